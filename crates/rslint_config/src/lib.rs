@@ -2,11 +2,13 @@
 
 mod de;
 use dirs_next::config_dir;
+use rslint_core::globals::{GlobalValue, Globals};
 use rslint_core::{get_group_rules_by_name, CstRule, CstRuleStore, Diagnostic, RuleLevel};
 use rslint_errors::file::{Files, SimpleFile};
 use serde::{Deserialize, Serialize};
 use std::{
     cell::RefCell,
+    collections::BTreeMap,
     env,
     fs::read_to_string,
     path::PathBuf,
@@ -25,6 +27,13 @@ pub struct ConfigRepr {
     rules: Option<RulesConfigRepr>,
     #[serde(default)]
     errors: ErrorsConfigRepr,
+    /// Environments such as `browser` or `node` whose globals are available to every file.
+    #[serde(default)]
+    env: BTreeMap<String, bool>,
+    /// Single globals available to every file, either `readonly`, `writable`, or `off`
+    /// to remove a global enabled by an environment.
+    #[serde(default)]
+    globals: BTreeMap<String, GlobalValue>,
 }
 
 impl Default for ConfigRepr {
@@ -32,6 +41,8 @@ impl Default for ConfigRepr {
         Self {
             rules: None,
             errors: Default::default(),
+            env: Default::default(),
+            globals: Default::default(),
         }
     }
 }
@@ -100,7 +111,12 @@ impl schemars::JsonSchema for RulesConfigRepr {
         });
 
         // TODO(RDambrosio016): dont hardcode it like this
-        let group_items = vec![string_schema!("errors"), string_schema!("style")];
+        let group_items = vec![
+            string_schema!("complexity"),
+            string_schema!("errors"),
+            string_schema!("regex"),
+            string_schema!("style"),
+        ];
 
         let groups_schema = Schema::Object(SchemaObject {
             array: Some(Box::new(ArrayValidation {
@@ -256,13 +272,30 @@ impl Config {
         }
     }
 
+    /// The globals enabled by the `env` and `globals` tables.
+    ///
+    /// This method may add warnings to the warning list of this `Config`.
+    pub fn globals(&self) -> Globals {
+        let mut globals = Globals::default();
+        for (env, _) in self.repr.env.iter().filter(|(_, enabled)| **enabled) {
+            if !globals.add_env(env) {
+                let d = Diagnostic::warning(1, "config", format!("unknown environment '{}'", env));
+                self.warnings.borrow_mut().push(d);
+            }
+        }
+        for (name, value) in &self.repr.globals {
+            globals.insert(name.as_str(), *value);
+        }
+        globals
+    }
+
     /// Collects all rules and creates a `CstRuleStore`.
     ///
     /// This method may add warnings to the warning list of this `Config`.
     pub fn rules_store(&self) -> CstRuleStore {
         let rule_cfg = match &self.repr.rules {
             Some(rules) => rules,
-            None => return CstRuleStore::new().builtins(),
+            None => return CstRuleStore::new().builtins().with_globals(self.globals()),
         };
 
         let rules = unique_rules(rule_cfg.errors.clone(), rule_cfg.warnings.clone());
@@ -279,7 +312,7 @@ impl Config {
             }
         }
 
-        let mut store = CstRuleStore::new();
+        let mut store = CstRuleStore::new().with_globals(self.globals());
         store.load_rules(rules);
         store
     }
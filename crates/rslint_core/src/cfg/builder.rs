@@ -0,0 +1,698 @@
+//! The tree walker which lowers statements and expressions into a [`ControlFlowGraph`].
+
+use super::*;
+use crate::util::simple_bool_coerce;
+use rslint_parser::{SmolStr, SyntaxNodeExt, T};
+
+/// A statement which can be the target of a jump.
+#[derive(Debug)]
+enum JumpCtx {
+    Loop {
+        node: SyntaxNode,
+        labels: Vec<SmolStr>,
+        brk: BlockId,
+        cont: BlockId,
+    },
+    Switch {
+        node: SyntaxNode,
+        labels: Vec<SmolStr>,
+        brk: BlockId,
+    },
+    Labelled {
+        node: SyntaxNode,
+        labels: Vec<SmolStr>,
+        brk: BlockId,
+    },
+    Try {
+        node: SyntaxNode,
+        catch: Option<BlockId>,
+        finally: Option<BlockId>,
+        in_catch: bool,
+        /// Jumps which were delayed until the `finally` block is done executing.
+        pending: Vec<Pending>,
+    },
+}
+
+#[derive(Debug)]
+struct Pending {
+    kind: EdgeKind,
+    label: Option<SmolStr>,
+    stmt: Option<SyntaxNode>,
+}
+
+pub(super) struct CfgBuilder {
+    blocks: Vec<BasicBlock>,
+    /// Whether a block is reachable from what has been built so far, this is used to avoid
+    /// `finally` blocks resuming jumps or normal flow which could never happen.
+    live: Vec<bool>,
+    cur: BlockId,
+    jumps: Vec<JumpCtx>,
+    labels: Vec<SmolStr>,
+    node_blocks: HashMap<SyntaxNode, BlockId>,
+    completions: HashMap<SyntaxNode, BlockId>,
+    jump_targets: HashMap<SyntaxNode, Option<SyntaxNode>>,
+}
+
+impl CfgBuilder {
+    pub(super) fn new() -> Self {
+        Self {
+            blocks: vec![],
+            live: vec![],
+            cur: 0,
+            jumps: vec![],
+            labels: vec![],
+            node_blocks: HashMap::new(),
+            completions: HashMap::new(),
+            jump_targets: HashMap::new(),
+        }
+    }
+
+    pub(super) fn build(mut self, node: &SyntaxNode) -> ControlFlowGraph {
+        let entry = self.new_block();
+        self.live[entry] = true;
+        let exit = self.new_block();
+        debug_assert_eq!(
+            (entry, exit),
+            (ControlFlowGraph::ENTRY, ControlFlowGraph::EXIT)
+        );
+
+        match node.kind() {
+            SCRIPT | MODULE => self.visit_stmts(node),
+            _ => {
+                if let Some(body) = node.child_with_kind(BLOCK_STMT) {
+                    self.visit_stmts(&body);
+                } else if let Some(expr) = node
+                    .children()
+                    .filter(|child| child.is::<ast::Expr>())
+                    .last()
+                {
+                    // arrow functions with an expression body implicitly return the expression
+                    self.record(&expr);
+                    self.visit_expr(&expr);
+                    self.edge(self.cur, exit, EdgeKind::Return, Some(expr));
+                    self.cur = self.new_block();
+                }
+            }
+        }
+        self.edge(self.cur, exit, EdgeKind::Normal, None);
+
+        let mut cfg = ControlFlowGraph {
+            node: node.to_owned(),
+            blocks: self.blocks,
+            reachable: vec![],
+            node_blocks: self.node_blocks,
+            completions: self.completions,
+            jump_targets: self.jump_targets,
+        };
+        cfg.compute_reachability();
+        cfg
+    }
+
+    fn new_block(&mut self) -> BlockId {
+        let id = self.blocks.len();
+        self.blocks.push(BasicBlock {
+            id,
+            ..Default::default()
+        });
+        self.live.push(false);
+        id
+    }
+
+    fn edge(&mut self, from: BlockId, to: BlockId, kind: EdgeKind, stmt: Option<SyntaxNode>) {
+        self.blocks[from].successors.push(Edge {
+            target: to,
+            kind,
+            stmt,
+        });
+        self.blocks[to].predecessors.push(from);
+        if self.live[from] {
+            self.live[to] = true;
+        }
+    }
+
+    /// Move to a new block which is only reachable from the current block.
+    fn next_block(&mut self, kind: EdgeKind) -> BlockId {
+        let next = self.new_block();
+        self.edge(self.cur, next, kind, None);
+        self.cur = next;
+        next
+    }
+
+    fn record(&mut self, node: &SyntaxNode) {
+        self.blocks[self.cur].nodes.push(node.to_owned());
+        self.node_blocks.insert(node.to_owned(), self.cur);
+    }
+
+    fn visit_stmts(&mut self, node: &SyntaxNode) {
+        for child in node.children() {
+            self.visit_stmt(&child);
+        }
+    }
+
+    fn visit_stmt(&mut self, stmt: &SyntaxNode) {
+        let labels = std::mem::take(&mut self.labels);
+        self.record(stmt);
+
+        match stmt.kind() {
+            BLOCK_STMT => self.visit_stmts(stmt),
+            IF_STMT => {
+                let if_stmt = stmt.to::<ast::IfStmt>();
+                if let Some(cond) = if_stmt.condition() {
+                    self.visit_expr(cond.syntax());
+                }
+                let test = self.cur;
+                let after = self.new_block();
+
+                self.next_block(EdgeKind::True);
+                if let Some(cons) = if_stmt.cons() {
+                    self.visit_stmt(cons.syntax());
+                }
+                self.edge(self.cur, after, EdgeKind::Normal, None);
+
+                if let Some(alt) = if_stmt.alt() {
+                    self.cur = test;
+                    self.next_block(EdgeKind::False);
+                    self.visit_stmt(alt.syntax());
+                    self.edge(self.cur, after, EdgeKind::Normal, None);
+                } else {
+                    self.edge(test, after, EdgeKind::False, None);
+                }
+                self.cur = after;
+            }
+            WHILE_STMT => {
+                let header = self.next_block(EdgeKind::Normal);
+                let cond = stmt.child_with_ast::<ast::Condition>();
+                if let Some(cond) = &cond {
+                    self.visit_expr(cond.syntax());
+                }
+                let test = self.cur;
+                let after = self.new_block();
+                self.next_block(EdgeKind::True);
+                if !always_truthy(cond.and_then(|c| c.condition())) {
+                    self.edge(test, after, EdgeKind::False, None);
+                }
+
+                self.visit_loop_body(stmt, labels, after, header);
+                self.edge(self.cur, header, EdgeKind::Normal, None);
+                self.cur = after;
+            }
+            DO_WHILE_STMT => {
+                let body = self.next_block(EdgeKind::Normal);
+                let test = self.new_block();
+                let after = self.new_block();
+
+                self.visit_loop_body(stmt, labels, after, test);
+                self.edge(self.cur, test, EdgeKind::Normal, None);
+                self.cur = test;
+                let cond = stmt.child_with_ast::<ast::Condition>();
+                if let Some(cond) = &cond {
+                    self.visit_expr(cond.syntax());
+                }
+                self.edge(self.cur, body, EdgeKind::True, None);
+                if !always_truthy(cond.and_then(|c| c.condition())) {
+                    self.edge(self.cur, after, EdgeKind::False, None);
+                }
+                self.cur = after;
+            }
+            FOR_STMT => {
+                let for_stmt = stmt.to::<ast::ForStmt>();
+                if let Some(init) = for_stmt.init() {
+                    self.visit_expr(init.syntax());
+                }
+                let header = self.next_block(EdgeKind::Normal);
+                let test = for_stmt.test();
+                if let Some(test) = &test {
+                    self.visit_expr(test.syntax());
+                }
+                let test_end = self.cur;
+                let after = self.new_block();
+                let update = self.new_block();
+                self.next_block(EdgeKind::True);
+                if test.is_some() && !always_truthy(test.and_then(|t| t.expr())) {
+                    self.edge(test_end, after, EdgeKind::False, None);
+                }
+
+                self.visit_loop_body(stmt, labels, after, update);
+                self.edge(self.cur, update, EdgeKind::Normal, None);
+                self.cur = update;
+                if let Some(update) = for_stmt.update() {
+                    self.visit_expr(update.syntax());
+                }
+                self.edge(self.cur, header, EdgeKind::Normal, None);
+                self.cur = after;
+            }
+            FOR_IN_STMT | FOR_OF_STMT => {
+                let left = stmt.child_with_kind(FOR_STMT_INIT);
+                for child in stmt.children() {
+                    if Some(&child) != left.as_ref() && child.is::<ast::Expr>() {
+                        self.visit_expr(&child);
+                        break;
+                    }
+                }
+                let header = self.next_block(EdgeKind::Normal);
+                let after = self.new_block();
+                self.edge(header, after, EdgeKind::False, None);
+                self.next_block(EdgeKind::True);
+                if let Some(left) = left {
+                    self.visit_expr(&left);
+                }
+
+                self.visit_loop_body(stmt, labels, after, header);
+                self.edge(self.cur, header, EdgeKind::Normal, None);
+                self.cur = after;
+            }
+            LABELLED_STMT => {
+                let mut labels = labels;
+                if let Some(label) = stmt.child_with_kind(NAME) {
+                    labels.push(label.trimmed_text().to_string().into());
+                }
+                let inner = match stmt.children().filter(|c| c.kind() != NAME).last() {
+                    Some(inner) => inner,
+                    None => return,
+                };
+                if inner.is_loop() || inner.kind() == SWITCH_STMT || inner.kind() == LABELLED_STMT {
+                    self.labels = labels;
+                    self.visit_stmt(&inner);
+                } else {
+                    let after = self.new_block();
+                    self.jumps.push(JumpCtx::Labelled {
+                        node: stmt.to_owned(),
+                        labels,
+                        brk: after,
+                    });
+                    self.visit_stmt(&inner);
+                    self.jumps.pop();
+                    self.edge(self.cur, after, EdgeKind::Normal, None);
+                    self.cur = after;
+                }
+            }
+            SWITCH_STMT => self.visit_switch(stmt, labels),
+            TRY_STMT => self.visit_try(stmt),
+            RETURN_STMT | THROW_STMT => {
+                for child in stmt.children() {
+                    self.visit_expr(&child);
+                }
+                let kind = if stmt.kind() == RETURN_STMT {
+                    EdgeKind::Return
+                } else {
+                    EdgeKind::Throw
+                };
+                self.jump(kind, None, Some(stmt.to_owned()));
+                self.cur = self.new_block();
+            }
+            BREAK_STMT | CONTINUE_STMT => {
+                let label = stmt
+                    .child_with_kind(NAME_REF)
+                    .map(|label| label.trimmed_text().to_string().into());
+                let kind = if stmt.kind() == BREAK_STMT {
+                    EdgeKind::Break
+                } else {
+                    EdgeKind::Continue
+                };
+                self.jump(kind, label, Some(stmt.to_owned()));
+                self.cur = self.new_block();
+            }
+            WITH_STMT => {
+                for child in stmt.children() {
+                    if child.kind() == CONDITION {
+                        self.visit_expr(&child);
+                    } else {
+                        self.visit_stmt(&child);
+                    }
+                }
+            }
+            EXPORT_DECL | EXPORT_DEFAULT_DECL => {
+                for child in stmt.children() {
+                    self.visit_stmt(&child);
+                }
+            }
+            // hoisted declarations and statements which do nothing at runtime
+            FN_DECL | CLASS_DECL | EMPTY_STMT | DEBUGGER_STMT | IMPORT_DECL | EXPORT_NAMED
+            | EXPORT_WILDCARD => {}
+            _ => self.visit_expr(stmt),
+        }
+
+        self.completions.insert(stmt.to_owned(), self.cur);
+    }
+
+    fn visit_loop_body(
+        &mut self,
+        stmt: &SyntaxNode,
+        labels: Vec<SmolStr>,
+        brk: BlockId,
+        cont: BlockId,
+    ) {
+        self.jumps.push(JumpCtx::Loop {
+            node: stmt.to_owned(),
+            labels,
+            brk,
+            cont,
+        });
+        if let Some(body) = stmt.children().filter(|c| c.is::<ast::Stmt>()).last() {
+            self.visit_stmt(&body);
+        }
+        self.jumps.pop();
+    }
+
+    fn visit_switch(&mut self, stmt: &SyntaxNode, labels: Vec<SmolStr>) {
+        if let Some(cond) = stmt.child_with_kind(CONDITION) {
+            self.visit_expr(&cond);
+        }
+        let after = self.new_block();
+        let clauses = stmt
+            .children()
+            .filter(|c| c.kind() == CASE_CLAUSE || c.kind() == DEFAULT_CLAUSE)
+            .collect::<Vec<_>>();
+        let entries = clauses.iter().map(|_| self.new_block()).collect::<Vec<_>>();
+
+        let mut default = None;
+        for (idx, clause) in clauses.iter().enumerate() {
+            if let Some(test) = clause.try_to::<ast::CaseClause>().and_then(|c| c.test()) {
+                self.visit_expr(test.syntax());
+                self.edge(self.cur, entries[idx], EdgeKind::True, None);
+                self.next_block(EdgeKind::False);
+            } else {
+                default = Some(entries[idx]);
+            }
+        }
+        self.edge(self.cur, default.unwrap_or(after), EdgeKind::False, None);
+
+        self.jumps.push(JumpCtx::Switch {
+            node: stmt.to_owned(),
+            labels,
+            brk: after,
+        });
+        for (idx, clause) in clauses.iter().enumerate() {
+            self.cur = entries[idx];
+            self.record(clause);
+            let colon = clause
+                .token_with_kind(T![:])
+                .map_or(clause.text_range().start(), |t| t.text_range().end());
+            for child in clause.children() {
+                if child.text_range().start() >= colon {
+                    self.visit_stmt(&child);
+                }
+            }
+            self.completions.insert(clause.to_owned(), self.cur);
+            // falling through to the next case
+            let next = entries.get(idx + 1).copied().unwrap_or(after);
+            self.edge(self.cur, next, EdgeKind::Normal, None);
+        }
+        self.jumps.pop();
+        self.cur = after;
+    }
+
+    fn visit_try(&mut self, stmt: &SyntaxNode) {
+        let try_stmt = stmt.to::<ast::TryStmt>();
+        let handler = try_stmt.handler();
+        let finalizer = try_stmt.finalizer();
+        let after = self.new_block();
+        let catch = handler.as_ref().map(|_| self.new_block());
+        let finally = finalizer.as_ref().map(|_| self.new_block());
+
+        let body = self.next_block(EdgeKind::Normal);
+        let mut pending = vec![];
+        if let Some(catch) = catch {
+            self.edge(body, catch, EdgeKind::Exception, None);
+        } else if let Some(finally) = finally {
+            self.edge(body, finally, EdgeKind::Exception, None);
+            if self.live[body] {
+                pending.push(Pending {
+                    kind: EdgeKind::Throw,
+                    label: None,
+                    stmt: None,
+                });
+            }
+        }
+        self.jumps.push(JumpCtx::Try {
+            node: stmt.to_owned(),
+            catch,
+            finally,
+            in_catch: false,
+            pending,
+        });
+
+        let mut finally_normally = false;
+        if let Some(test) = try_stmt.test() {
+            self.visit_stmt(test.syntax());
+        }
+        self.finish_try_part(finally, after, &mut finally_normally);
+
+        if let (Some(handler), Some(catch)) = (handler, catch) {
+            if let Some(JumpCtx::Try {
+                in_catch, pending, ..
+            }) = self.jumps.last_mut()
+            {
+                *in_catch = true;
+                if finally.is_some() && self.live[catch] {
+                    pending.push(Pending {
+                        kind: EdgeKind::Throw,
+                        label: None,
+                        stmt: None,
+                    });
+                }
+            }
+            if let Some(finally) = finally {
+                self.edge(catch, finally, EdgeKind::Exception, None);
+            }
+            self.cur = catch;
+            self.record(handler.syntax());
+            for child in handler.syntax().children() {
+                if child.kind() == BLOCK_STMT {
+                    self.visit_stmt(&child);
+                } else {
+                    self.visit_expr(&child);
+                }
+            }
+            self.completions
+                .insert(handler.syntax().to_owned(), self.cur);
+            self.finish_try_part(finally, after, &mut finally_normally);
+        }
+
+        let pending = match self.jumps.pop() {
+            Some(JumpCtx::Try { pending, .. }) => pending,
+            _ => unreachable!(),
+        };
+
+        if let (Some(finalizer), Some(finally)) = (finalizer, finally) {
+            self.cur = finally;
+            self.record(finalizer.syntax());
+            if let Some(body) = finalizer.cons() {
+                self.visit_stmt(body.syntax());
+            }
+            self.completions
+                .insert(finalizer.syntax().to_owned(), self.cur);
+
+            let end = self.cur;
+            if finally_normally {
+                self.edge(end, after, EdgeKind::Normal, None);
+            }
+            // resume any jumps which were paused by the finally block
+            for Pending { kind, label, stmt } in pending {
+                self.cur = end;
+                self.jump(kind, label, stmt);
+            }
+        }
+        self.cur = after;
+    }
+
+    fn finish_try_part(
+        &mut self,
+        finally: Option<BlockId>,
+        after: BlockId,
+        finally_normally: &mut bool,
+    ) {
+        match finally {
+            Some(finally) => {
+                if self.live[self.cur] {
+                    *finally_normally = true;
+                }
+                self.edge(self.cur, finally, EdgeKind::Normal, None);
+            }
+            None => self.edge(self.cur, after, EdgeKind::Normal, None),
+        }
+    }
+
+    /// Add the edges for a jump from the current block, jumps which leave a `try` or `catch` block
+    /// go to the `finally` block first, and are resumed once it is done executing.
+    fn jump(&mut self, kind: EdgeKind, label: Option<SmolStr>, stmt: Option<SyntaxNode>) {
+        let mut finally_ctx = None;
+        let mut target = None;
+
+        for (idx, ctx) in self.jumps.iter().enumerate().rev() {
+            let matches_label =
+                |labels: &[SmolStr]| label.as_ref().map_or(true, |label| labels.contains(label));
+            let found = match ctx {
+                JumpCtx::Try {
+                    node,
+                    catch,
+                    finally,
+                    in_catch,
+                    ..
+                } => {
+                    if kind == EdgeKind::Throw && !in_catch && catch.is_some() {
+                        Some((node, catch.unwrap()))
+                    } else {
+                        if finally.is_some() && finally_ctx.is_none() {
+                            finally_ctx = Some(idx);
+                        }
+                        None
+                    }
+                }
+                JumpCtx::Loop {
+                    node,
+                    labels,
+                    brk,
+                    cont,
+                } if matches_label(labels) => match kind {
+                    EdgeKind::Break => Some((node, *brk)),
+                    EdgeKind::Continue => Some((node, *cont)),
+                    _ => None,
+                },
+                JumpCtx::Switch { node, labels, brk }
+                    if kind == EdgeKind::Break && matches_label(labels) =>
+                {
+                    Some((node, *brk))
+                }
+                JumpCtx::Labelled { node, labels, brk }
+                    if kind == EdgeKind::Break && label.is_some() && matches_label(labels) =>
+                {
+                    Some((node, *brk))
+                }
+                _ => None,
+            };
+            if let Some((node, block)) = found {
+                target = Some((node.to_owned(), block));
+                break;
+            }
+        }
+
+        if let Some(stmt) = &stmt {
+            self.jump_targets
+                .entry(stmt.to_owned())
+                .or_insert_with(|| target.as_ref().map(|(node, _)| node.to_owned()));
+        }
+
+        let target = match target {
+            Some((_, block)) => Some(block),
+            None if matches!(kind, EdgeKind::Return | EdgeKind::Throw) => {
+                Some(ControlFlowGraph::EXIT)
+            }
+            None => None,
+        };
+
+        if let Some(idx) = finally_ctx {
+            if let JumpCtx::Try {
+                finally: Some(finally),
+                pending,
+                ..
+            } = &mut self.jumps[idx]
+            {
+                let finally = *finally;
+                if self.live[self.cur] {
+                    pending.push(Pending {
+                        kind,
+                        label,
+                        stmt: stmt.clone(),
+                    });
+                }
+                self.edge(self.cur, finally, kind, stmt);
+            }
+        } else if let Some(target) = target {
+            self.edge(self.cur, target, kind, stmt);
+        }
+    }
+
+    /// Visit an expression, splitting blocks for short circuiting expressions.
+    /// Nested functions and classes are not visited.
+    fn visit_expr(&mut self, node: &SyntaxNode) {
+        match node.kind() {
+            FN_EXPR | ARROW_EXPR | CLASS_EXPR | FN_DECL | CLASS_DECL => {}
+            BIN_EXPR => {
+                let kind = match node.to::<ast::BinExpr>().op_token().map(|t| t.kind()) {
+                    Some(T![&&]) => Some(EdgeKind::True),
+                    Some(T![||]) => Some(EdgeKind::False),
+                    Some(T![??]) => Some(EdgeKind::Normal),
+                    _ => None,
+                };
+                self.visit_short_circuit(node, kind);
+            }
+            ASSIGN_EXPR => {
+                let kind = match node.to::<ast::AssignExpr>().op_token().map(|t| t.kind()) {
+                    Some(T![&&=]) => Some(EdgeKind::True),
+                    Some(T![||=]) => Some(EdgeKind::False),
+                    Some(T![??=]) => Some(EdgeKind::Normal),
+                    _ => None,
+                };
+                self.visit_short_circuit(node, kind);
+            }
+            // `a?.b(c)` does not evaluate the rest of the chain if `a` is nullish
+            DOT_EXPR | CALL_EXPR | BRACKET_EXPR if node.token_with_kind(QUESTIONDOT).is_some() => {
+                self.visit_short_circuit(node, Some(EdgeKind::Normal));
+            }
+            COND_EXPR => {
+                let cond = node.to::<ast::CondExpr>();
+                if let Some(test) = cond.test() {
+                    self.visit_expr(test.syntax());
+                }
+                let test = self.cur;
+                let after = self.new_block();
+                for (branch, kind) in
+                    [(cond.cons(), EdgeKind::True), (cond.alt(), EdgeKind::False)].iter()
+                {
+                    self.cur = test;
+                    self.next_block(*kind);
+                    if let Some(branch) = branch {
+                        self.record(branch.syntax());
+                        self.visit_expr(branch.syntax());
+                    }
+                    self.edge(self.cur, after, EdgeKind::Normal, None);
+                }
+                self.cur = after;
+            }
+            _ => {
+                for child in node.children() {
+                    self.visit_expr(&child);
+                }
+            }
+        }
+    }
+
+    /// Visit an expression whose first child is always evaluated and whose other children are only
+    /// evaluated if the edge of `kind` is taken, if `kind` is `None` then all children are always evaluated.
+    fn visit_short_circuit(&mut self, node: &SyntaxNode, kind: Option<EdgeKind>) {
+        let mut children = node.children();
+        if let Some(first) = children.next() {
+            self.visit_expr(&first);
+        }
+        let kind = match kind {
+            Some(kind) => kind,
+            None => {
+                for child in children {
+                    self.visit_expr(&child);
+                }
+                return;
+            }
+        };
+        let skip = match kind {
+            EdgeKind::True => EdgeKind::False,
+            EdgeKind::False => EdgeKind::True,
+            kind => kind,
+        };
+
+        let test = self.cur;
+        let after = self.new_block();
+        self.edge(test, after, skip, None);
+        self.next_block(kind);
+        for child in children {
+            self.record(&child);
+            self.visit_expr(&child);
+        }
+        self.edge(self.cur, after, EdgeKind::Normal, None);
+        self.cur = after;
+    }
+}
+
+fn always_truthy(cond: Option<ast::Expr>) -> bool {
+    cond.and_then(simple_bool_coerce) == Some(true)
+}
@@ -0,0 +1,313 @@
+//! Control flow graphs for function, script, and module bodies.
+//!
+//! A [`ControlFlowGraph`] is a graph of [`BasicBlock`]s, each block holds the statements (and conditionally
+//! evaluated expressions) which are executed in sequence, and edges to the blocks control may flow to next.
+//! Every graph has a single entry block and a single exit block, returning, throwing out of the body,
+//! and completing the body normally are all edges into the exit block.
+//!
+//! Graphs are built for a single body, nested functions and classes are not included and have their own graphs.
+//! Rules should get graphs through [`RuleCtx::cfg`](crate::RuleCtx::cfg) which lazily builds and caches them.
+
+mod builder;
+
+use rslint_parser::{ast, AstNode, SyntaxKind, SyntaxKind::*, SyntaxNode};
+use std::collections::{HashMap, VecDeque};
+
+/// The index of a block inside of a [`ControlFlowGraph`].
+pub type BlockId = usize;
+
+/// The reason control flows from one block to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    /// Sequential flow, such as the end of a loop body to the loop's test.
+    Normal,
+    /// Taken when a condition (or the left hand side of a short circuiting expression) is truthy.
+    True,
+    /// Taken when a condition (or the left hand side of a short circuiting expression) is falsey.
+    False,
+    Break,
+    Continue,
+    Return,
+    Throw,
+    /// An implicit edge taken if any statement in a `try` or `catch` block throws.
+    Exception,
+}
+
+/// A single edge between two blocks.
+#[derive(Debug, Clone)]
+pub struct Edge {
+    pub target: BlockId,
+    pub kind: EdgeKind,
+    /// The statement which caused this jump, for `return`, `throw`, `break`, and `continue` edges.
+    /// This is also kept for jumps which have been delayed by a `finally` block.
+    /// For arrow functions with an expression body this is the expression.
+    pub stmt: Option<SyntaxNode>,
+}
+
+impl Edge {
+    /// Whether this is a `return` edge which returns a value, `return;` does not.
+    pub fn returns_value(&self) -> bool {
+        self.kind == EdgeKind::Return
+            && self.stmt.as_ref().map_or(false, |stmt| {
+                ast::ReturnStmt::cast(stmt.to_owned()).map_or(true, |ret| ret.value().is_some())
+            })
+    }
+}
+
+/// A sequence of statements and expressions which are always executed one after another.
+#[derive(Debug, Clone, Default)]
+pub struct BasicBlock {
+    pub id: BlockId,
+    pub nodes: Vec<SyntaxNode>,
+    pub successors: Vec<Edge>,
+    pub predecessors: Vec<BlockId>,
+}
+
+/// The control flow graph of a single function, script, or module body.
+#[derive(Debug, Clone)]
+pub struct ControlFlowGraph {
+    /// The function, script, or module this graph was built for.
+    pub node: SyntaxNode,
+    blocks: Vec<BasicBlock>,
+    reachable: Vec<bool>,
+    node_blocks: HashMap<SyntaxNode, BlockId>,
+    completions: HashMap<SyntaxNode, BlockId>,
+    jump_targets: HashMap<SyntaxNode, Option<SyntaxNode>>,
+}
+
+/// Whether a node of this kind has its own control flow graph.
+pub fn is_cfg_owner(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SCRIPT | MODULE | FN_DECL | FN_EXPR | ARROW_EXPR | METHOD | GETTER | SETTER | CONSTRUCTOR
+    )
+}
+
+/// Get the function, script, or module whose control flow graph contains this node.
+pub fn owner(node: &SyntaxNode) -> Option<SyntaxNode> {
+    node.ancestors().skip(1).find(|n| is_cfg_owner(n.kind()))
+}
+
+impl ControlFlowGraph {
+    /// Build the graph for a function, script, or module, returns `None` if the node is
+    /// not one of those, see [`is_cfg_owner`].
+    pub fn new(node: &SyntaxNode) -> Option<Self> {
+        if !is_cfg_owner(node.kind()) {
+            return None;
+        }
+        Some(builder::CfgBuilder::new().build(node))
+    }
+
+    pub const ENTRY: BlockId = 0;
+    pub const EXIT: BlockId = 1;
+
+    pub fn blocks(&self) -> &[BasicBlock] {
+        &self.blocks
+    }
+
+    pub fn block(&self, id: BlockId) -> &BasicBlock {
+        &self.blocks[id]
+    }
+
+    /// Whether a block can be reached from the entry of the body.
+    pub fn is_block_reachable(&self, id: BlockId) -> bool {
+        self.reachable[id]
+    }
+
+    /// The block a statement or conditionally evaluated expression starts in, if the node
+    /// was not recorded then the block of the closest recorded ancestor is returned.
+    pub fn block_of(&self, node: &SyntaxNode) -> Option<BlockId> {
+        node.ancestors()
+            .take_while(|n| n == node || !is_cfg_owner(n.kind()))
+            .find_map(|n| self.node_blocks.get(&n).copied())
+    }
+
+    /// Whether a node inside of this body can ever be executed.
+    pub fn is_reachable(&self, node: &SyntaxNode) -> bool {
+        self.block_of(node).map_or(false, |id| self.reachable[id])
+    }
+
+    /// Whether control can flow past the end of a statement, e.g. `return` and `if (a) { return } else { throw a }` never
+    /// complete normally. This is also valid for `CASE_CLAUSE`, `DEFAULT_CLAUSE`, `CATCH_CLAUSE`, and `FINALIZER` nodes.
+    pub fn completes_normally(&self, stmt: &SyntaxNode) -> bool {
+        self.completions
+            .get(stmt)
+            .map_or(false, |id| self.reachable[*id])
+    }
+
+    /// The statement a `break`, `continue`, or `throw` statement transfers control to.
+    /// This is the loop, switch, or labelled statement for `break` and `continue`, and the try statement
+    /// for a `throw` which is caught. `None` is returned for statements which leave the body or have no valid target.
+    pub fn jump_target(&self, stmt: &SyntaxNode) -> Option<&SyntaxNode> {
+        self.jump_targets.get(stmt)?.as_ref()
+    }
+
+    /// All reachable edges which leave the body, either by returning, throwing, or completing normally.
+    pub fn exits(&self) -> impl Iterator<Item = &Edge> {
+        self.blocks
+            .iter()
+            .filter(move |block| self.reachable[block.id])
+            .flat_map(|block| block.successors.iter())
+            .filter(|edge| edge.target == Self::EXIT)
+    }
+
+    /// Whether every path through the body either returns a value or throws.
+    /// `allow_empty_return` dictates whether `return;` is counted as returning a value.
+    pub fn always_returns_value(&self, allow_empty_return: bool) -> bool {
+        self.missing_returns(allow_empty_return).next().is_none()
+    }
+
+    /// All reachable exits which leave the body without returning a value or throwing, these are
+    /// `return;` statements (unless `allow_empty_return` is true) and completing the body normally.
+    pub fn missing_returns(&self, allow_empty_return: bool) -> impl Iterator<Item = &Edge> {
+        self.exits().filter(move |edge| match edge.kind {
+            EdgeKind::Throw => false,
+            EdgeKind::Return => !allow_empty_return && !edge.returns_value(),
+            _ => true,
+        })
+    }
+
+    /// All reachable `return` edges which return a value, including the implicit return
+    /// of an arrow function with an expression body.
+    pub fn value_returns(&self) -> impl Iterator<Item = &Edge> {
+        self.exits().filter(|edge| edge.returns_value())
+    }
+
+    fn compute_reachability(&mut self) {
+        let mut reachable = vec![false; self.blocks.len()];
+        let mut queue = VecDeque::new();
+        reachable[Self::ENTRY] = true;
+        queue.push_back(Self::ENTRY);
+        while let Some(id) = queue.pop_front() {
+            for edge in &self.blocks[id].successors {
+                if !reachable[edge.target] {
+                    reachable[edge.target] = true;
+                    queue.push_back(edge.target);
+                }
+            }
+        }
+        self.reachable = reachable;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rslint_parser::{parse_text, SyntaxNodeExt};
+
+    fn cfg(code: &str) -> (SyntaxNode, ControlFlowGraph) {
+        let root = parse_text(code, 0).syntax();
+        let cfg = ControlFlowGraph::new(&root).unwrap();
+        (root, cfg)
+    }
+
+    fn reachable(code: &str, stmt: &str) -> bool {
+        let (root, cfg) = cfg(code);
+        let node = root
+            .descendants()
+            .find(|n| n.kind() == EXPR_STMT && n.trimmed_text() == stmt)
+            .unwrap_or_else(|| panic!("no statement `{}`", stmt));
+        cfg.is_reachable(&node)
+    }
+
+    #[test]
+    fn returns_and_throws() {
+        assert!(!reachable("return; a;", "a;"));
+        assert!(!reachable("throw 5; a;", "a;"));
+        assert!(!reachable("if (b) { return } else { throw c } a;", "a;"));
+        assert!(reachable("if (b) { return } a;", "a;"));
+    }
+
+    #[test]
+    fn loops_and_labels() {
+        assert!(!reachable("while (true) {} a;", "a;"));
+        assert!(!reachable("for (;;) {} a;", "a;"));
+        assert!(reachable("while (true) { break; } a;", "a;"));
+        assert!(!reachable("while (b) { continue; c; }", "c;"));
+        assert!(reachable(
+            "outer: for (;;) { for (;;) { break outer; } } a;",
+            "a;"
+        ));
+        assert!(!reachable(
+            "outer: for (;;) { for (;;) { break; } } a;",
+            "a;"
+        ));
+        assert!(reachable("foo: { break foo; } a;", "a;"));
+    }
+
+    #[test]
+    fn try_catch_finally() {
+        assert!(reachable("try { throw b } catch (e) {} a;", "a;"));
+        assert!(reachable("try { b; } catch (e) { c; }", "c;"));
+        assert!(!reachable("try { return } finally { b; } a;", "a;"));
+        assert!(reachable("try { return } finally { b; }", "b;"));
+        assert!(!reachable("try { b; } finally { return } a;", "a;"));
+        assert!(reachable(
+            "for (;;) { try { break; } finally { b; } } a;",
+            "a;"
+        ));
+    }
+
+    #[test]
+    fn switch_fallthrough() {
+        let (root, cfg) = cfg("switch (a) { case 1: b; case 2: c; break; default: d; }");
+        let clauses = root
+            .descendants()
+            .filter(|n| n.kind() == CASE_CLAUSE || n.kind() == DEFAULT_CLAUSE)
+            .collect::<Vec<_>>();
+        assert!(cfg.completes_normally(&clauses[0]));
+        assert!(!cfg.completes_normally(&clauses[1]));
+        assert!(cfg.completes_normally(&clauses[2]));
+    }
+
+    #[test]
+    fn short_circuit_expressions() {
+        let (root, cfg) = cfg("a && b; c ?? d; e ? f : g;");
+        let block = |text: &str| {
+            let node = root
+                .descendants()
+                .find(|n| n.kind() == NAME_REF && n.text() == text)
+                .unwrap();
+            cfg.block_of(&node).unwrap()
+        };
+        assert_ne!(block("a"), block("b"));
+        assert_ne!(block("c"), block("d"));
+        assert_ne!(block("f"), block("g"));
+        assert!(cfg
+            .block(block("a"))
+            .successors
+            .iter()
+            .any(|e| e.kind == EdgeKind::True));
+    }
+
+    #[test]
+    fn returns_value() {
+        let fn_cfg = |code: &str| {
+            let root = parse_text(code, 0).syntax();
+            let func = root.descendants().find(|n| n.kind() == FN_DECL).unwrap();
+            ControlFlowGraph::new(&func).unwrap()
+        };
+        assert!(
+            fn_cfg("function f() { if (a) { return 1 } else { throw a } }")
+                .always_returns_value(false)
+        );
+        assert!(!fn_cfg("function f() { if (a) { return 1 } }").always_returns_value(false));
+        assert!(!fn_cfg("function f() { return; }").always_returns_value(false));
+        assert!(fn_cfg("function f() { return; }").always_returns_value(true));
+        assert!(
+            fn_cfg("function f() { try { return 1 } finally { a } }").always_returns_value(false)
+        );
+        assert_eq!(
+            fn_cfg("function f() { if (a) { return; } if (b) { return 1 } }")
+                .missing_returns(false)
+                .count(),
+            2
+        );
+        assert_eq!(
+            fn_cfg("function f() { if (a) { return; } return 1; }")
+                .value_returns()
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,835 @@
+//! The bundled lists of globals for every environment.
+
+pub const ES5: &[&str] = &[
+    "Array",
+    "Boolean",
+    "constructor",
+    "Date",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "Error",
+    "escape",
+    "eval",
+    "EvalError",
+    "Function",
+    "hasOwnProperty",
+    "Infinity",
+    "isFinite",
+    "isNaN",
+    "isPrototypeOf",
+    "JSON",
+    "Math",
+    "NaN",
+    "Number",
+    "Object",
+    "parseFloat",
+    "parseInt",
+    "propertyIsEnumerable",
+    "RangeError",
+    "ReferenceError",
+    "RegExp",
+    "String",
+    "SyntaxError",
+    "toLocaleString",
+    "toString",
+    "TypeError",
+    "undefined",
+    "unescape",
+    "URIError",
+    "valueOf",
+];
+
+pub const ES2015: &[&str] = &[
+    "Array",
+    "ArrayBuffer",
+    "Boolean",
+    "constructor",
+    "DataView",
+    "Date",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "Error",
+    "escape",
+    "eval",
+    "EvalError",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "hasOwnProperty",
+    "Infinity",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "isFinite",
+    "isNaN",
+    "isPrototypeOf",
+    "JSON",
+    "Map",
+    "Math",
+    "NaN",
+    "Number",
+    "Object",
+    "parseFloat",
+    "parseInt",
+    "Promise",
+    "propertyIsEnumerable",
+    "Proxy",
+    "RangeError",
+    "ReferenceError",
+    "Reflect",
+    "RegExp",
+    "Set",
+    "String",
+    "Symbol",
+    "SyntaxError",
+    "toLocaleString",
+    "toString",
+    "TypeError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "undefined",
+    "unescape",
+    "URIError",
+    "valueOf",
+    "WeakMap",
+    "WeakSet",
+];
+
+pub const ES2017: &[&str] = &[
+    "Array",
+    "ArrayBuffer",
+    "Atomics",
+    "Boolean",
+    "constructor",
+    "DataView",
+    "Date",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "Error",
+    "escape",
+    "eval",
+    "EvalError",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "hasOwnProperty",
+    "Infinity",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "isFinite",
+    "isNaN",
+    "isPrototypeOf",
+    "JSON",
+    "Map",
+    "Math",
+    "NaN",
+    "Number",
+    "Object",
+    "parseFloat",
+    "parseInt",
+    "Promise",
+    "propertyIsEnumerable",
+    "Proxy",
+    "RangeError",
+    "ReferenceError",
+    "Reflect",
+    "RegExp",
+    "Set",
+    "SharedArrayBuffer",
+    "String",
+    "Symbol",
+    "SyntaxError",
+    "toLocaleString",
+    "toString",
+    "TypeError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "undefined",
+    "unescape",
+    "URIError",
+    "valueOf",
+    "WeakMap",
+    "WeakSet",
+];
+
+pub const ES2020: &[&str] = &[
+    "Array",
+    "ArrayBuffer",
+    "Atomics",
+    "BigInt",
+    "BigInt64Array",
+    "BigUint64Array",
+    "Boolean",
+    "constructor",
+    "DataView",
+    "Date",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "Error",
+    "escape",
+    "eval",
+    "EvalError",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "globalThis",
+    "hasOwnProperty",
+    "Infinity",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "isFinite",
+    "isNaN",
+    "isPrototypeOf",
+    "JSON",
+    "Map",
+    "Math",
+    "NaN",
+    "Number",
+    "Object",
+    "parseFloat",
+    "parseInt",
+    "Promise",
+    "propertyIsEnumerable",
+    "Proxy",
+    "RangeError",
+    "ReferenceError",
+    "Reflect",
+    "RegExp",
+    "Set",
+    "SharedArrayBuffer",
+    "String",
+    "Symbol",
+    "SyntaxError",
+    "toLocaleString",
+    "toString",
+    "TypeError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "undefined",
+    "unescape",
+    "URIError",
+    "valueOf",
+    "WeakMap",
+    "WeakSet",
+];
+
+pub const ES2021: &[&str] = &[
+    "AggregateError",
+    "Array",
+    "ArrayBuffer",
+    "Atomics",
+    "BigInt",
+    "BigInt64Array",
+    "BigUint64Array",
+    "Boolean",
+    "constructor",
+    "DataView",
+    "Date",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "Error",
+    "escape",
+    "eval",
+    "EvalError",
+    "FinalizationRegistry",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "globalThis",
+    "hasOwnProperty",
+    "Infinity",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "isFinite",
+    "isNaN",
+    "isPrototypeOf",
+    "JSON",
+    "Map",
+    "Math",
+    "NaN",
+    "Number",
+    "Object",
+    "parseFloat",
+    "parseInt",
+    "Promise",
+    "propertyIsEnumerable",
+    "Proxy",
+    "RangeError",
+    "ReferenceError",
+    "Reflect",
+    "RegExp",
+    "Set",
+    "SharedArrayBuffer",
+    "String",
+    "Symbol",
+    "SyntaxError",
+    "toLocaleString",
+    "toString",
+    "TypeError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "undefined",
+    "unescape",
+    "URIError",
+    "valueOf",
+    "WeakMap",
+    "WeakRef",
+    "WeakSet",
+];
+
+/// The globals which are always available, the builtins of the latest ECMAScript version.
+pub const BUILTIN: &[&str] = ES2021;
+
+pub const BROWSER: &[&str] = &[
+    "AbortController",
+    "AbortSignal",
+    "addEventListener",
+    "alert",
+    "AnalyserNode",
+    "Animation",
+    "AnimationEvent",
+    "atob",
+    "Attr",
+    "Audio",
+    "AudioBuffer",
+    "AudioBufferSourceNode",
+    "AudioContext",
+    "AudioDestinationNode",
+    "AudioNode",
+    "AudioParam",
+    "BeforeUnloadEvent",
+    "BiquadFilterNode",
+    "Blob",
+    "blur",
+    "BroadcastChannel",
+    "btoa",
+    "caches",
+    "CanvasGradient",
+    "CanvasPattern",
+    "CanvasRenderingContext2D",
+    "CDATASection",
+    "ChannelMergerNode",
+    "ChannelSplitterNode",
+    "CharacterData",
+    "clearInterval",
+    "clearTimeout",
+    "clientInformation",
+    "Clipboard",
+    "ClipboardEvent",
+    "close",
+    "closed",
+    "CloseEvent",
+    "Comment",
+    "CompositionEvent",
+    "confirm",
+    "console",
+    "ConvolverNode",
+    "createImageBitmap",
+    "crypto",
+    "Crypto",
+    "CryptoKey",
+    "CSS",
+    "CSSRule",
+    "CSSStyleDeclaration",
+    "CSSStyleSheet",
+    "customElements",
+    "CustomElementRegistry",
+    "CustomEvent",
+    "DataTransfer",
+    "DelayNode",
+    "devicePixelRatio",
+    "dispatchEvent",
+    "document",
+    "Document",
+    "DocumentFragment",
+    "DocumentType",
+    "DOMException",
+    "DOMImplementation",
+    "DOMMatrix",
+    "DOMParser",
+    "DOMPoint",
+    "DOMRect",
+    "DOMTokenList",
+    "DragEvent",
+    "DynamicsCompressorNode",
+    "Element",
+    "ErrorEvent",
+    "event",
+    "Event",
+    "EventSource",
+    "EventTarget",
+    "external",
+    "fetch",
+    "File",
+    "FileList",
+    "FileReader",
+    "find",
+    "focus",
+    "FocusEvent",
+    "FontFace",
+    "FormData",
+    "frameElement",
+    "frames",
+    "GainNode",
+    "Gamepad",
+    "GamepadEvent",
+    "getComputedStyle",
+    "getSelection",
+    "HashChangeEvent",
+    "Headers",
+    "history",
+    "History",
+    "HTMLAnchorElement",
+    "HTMLAreaElement",
+    "HTMLAudioElement",
+    "HTMLBodyElement",
+    "HTMLBRElement",
+    "HTMLButtonElement",
+    "HTMLCanvasElement",
+    "HTMLCollection",
+    "HTMLDataListElement",
+    "HTMLDialogElement",
+    "HTMLDivElement",
+    "HTMLDocument",
+    "HTMLElement",
+    "HTMLEmbedElement",
+    "HTMLFieldSetElement",
+    "HTMLFormControlsCollection",
+    "HTMLFormElement",
+    "HTMLHeadElement",
+    "HTMLHeadingElement",
+    "HTMLHRElement",
+    "HTMLHtmlElement",
+    "HTMLIFrameElement",
+    "HTMLImageElement",
+    "HTMLInputElement",
+    "HTMLLabelElement",
+    "HTMLLegendElement",
+    "HTMLLIElement",
+    "HTMLLinkElement",
+    "HTMLMediaElement",
+    "HTMLMetaElement",
+    "HTMLMeterElement",
+    "HTMLObjectElement",
+    "HTMLOListElement",
+    "HTMLOptGroupElement",
+    "HTMLOptionElement",
+    "HTMLOutputElement",
+    "HTMLParagraphElement",
+    "HTMLPictureElement",
+    "HTMLPreElement",
+    "HTMLProgressElement",
+    "HTMLQuoteElement",
+    "HTMLScriptElement",
+    "HTMLSelectElement",
+    "HTMLSlotElement",
+    "HTMLSourceElement",
+    "HTMLSpanElement",
+    "HTMLStyleElement",
+    "HTMLTableCellElement",
+    "HTMLTableElement",
+    "HTMLTableRowElement",
+    "HTMLTableSectionElement",
+    "HTMLTemplateElement",
+    "HTMLTextAreaElement",
+    "HTMLTimeElement",
+    "HTMLTitleElement",
+    "HTMLTrackElement",
+    "HTMLUListElement",
+    "HTMLUnknownElement",
+    "HTMLVideoElement",
+    "IDBCursor",
+    "IDBDatabase",
+    "IDBFactory",
+    "IDBIndex",
+    "IDBKeyRange",
+    "IDBObjectStore",
+    "IDBRequest",
+    "IDBTransaction",
+    "Image",
+    "ImageBitmap",
+    "ImageData",
+    "indexedDB",
+    "innerHeight",
+    "innerWidth",
+    "InputEvent",
+    "IntersectionObserver",
+    "IntersectionObserverEntry",
+    "Intl",
+    "isSecureContext",
+    "KeyboardEvent",
+    "length",
+    "localStorage",
+    "location",
+    "Location",
+    "locationbar",
+    "matchMedia",
+    "MediaDevices",
+    "MediaQueryList",
+    "MediaQueryListEvent",
+    "MediaRecorder",
+    "MediaSource",
+    "MediaStream",
+    "menubar",
+    "MessageChannel",
+    "MessageEvent",
+    "MessagePort",
+    "MouseEvent",
+    "moveBy",
+    "moveTo",
+    "MutationObserver",
+    "MutationRecord",
+    "name",
+    "NamedNodeMap",
+    "navigator",
+    "Navigator",
+    "Node",
+    "NodeFilter",
+    "NodeIterator",
+    "NodeList",
+    "Notification",
+    "OfflineAudioContext",
+    "onbeforeunload",
+    "onblur",
+    "onchange",
+    "onclick",
+    "onerror",
+    "onfocus",
+    "onhashchange",
+    "oninput",
+    "onkeydown",
+    "onkeypress",
+    "onkeyup",
+    "onload",
+    "onmessage",
+    "onmousedown",
+    "onmousemove",
+    "onmouseout",
+    "onmouseover",
+    "onmouseup",
+    "onpopstate",
+    "onresize",
+    "onscroll",
+    "onsubmit",
+    "onunload",
+    "open",
+    "opener",
+    "Option",
+    "origin",
+    "OscillatorNode",
+    "outerHeight",
+    "outerWidth",
+    "PageTransitionEvent",
+    "pageXOffset",
+    "pageYOffset",
+    "parent",
+    "Path2D",
+    "performance",
+    "Performance",
+    "PerformanceEntry",
+    "PerformanceObserver",
+    "personalbar",
+    "PointerEvent",
+    "PopStateEvent",
+    "postMessage",
+    "print",
+    "ProgressEvent",
+    "prompt",
+    "PromiseRejectionEvent",
+    "queueMicrotask",
+    "Range",
+    "ReadableStream",
+    "removeEventListener",
+    "Request",
+    "requestAnimationFrame",
+    "cancelAnimationFrame",
+    "requestIdleCallback",
+    "cancelIdleCallback",
+    "resizeBy",
+    "ResizeObserver",
+    "resizeTo",
+    "Response",
+    "screen",
+    "Screen",
+    "screenLeft",
+    "screenTop",
+    "screenX",
+    "screenY",
+    "scroll",
+    "scrollbars",
+    "scrollBy",
+    "scrollTo",
+    "scrollX",
+    "scrollY",
+    "Selection",
+    "self",
+    "ServiceWorker",
+    "ServiceWorkerContainer",
+    "ServiceWorkerRegistration",
+    "sessionStorage",
+    "setInterval",
+    "setTimeout",
+    "ShadowRoot",
+    "SharedWorker",
+    "speechSynthesis",
+    "status",
+    "statusbar",
+    "stop",
+    "Storage",
+    "StorageEvent",
+    "structuredClone",
+    "StyleSheet",
+    "SubmitEvent",
+    "SVGElement",
+    "SVGGraphicsElement",
+    "SVGSVGElement",
+    "Text",
+    "TextDecoder",
+    "TextEncoder",
+    "TextMetrics",
+    "toolbar",
+    "top",
+    "Touch",
+    "TouchEvent",
+    "TouchList",
+    "TrackEvent",
+    "TransitionEvent",
+    "TreeWalker",
+    "UIEvent",
+    "URL",
+    "URLSearchParams",
+    "ValidityState",
+    "visualViewport",
+    "WebAssembly",
+    "WebGL2RenderingContext",
+    "WebGLRenderingContext",
+    "WebSocket",
+    "WheelEvent",
+    "window",
+    "Window",
+    "Worker",
+    "WritableStream",
+    "XMLDocument",
+    "XMLHttpRequest",
+    "XMLHttpRequestEventTarget",
+    "XMLHttpRequestUpload",
+    "XMLSerializer",
+    "XPathEvaluator",
+    "XPathResult",
+    "XSLTProcessor",
+];
+
+pub const BROWSER_WRITABLE: &[&str] = &[
+    "location",
+    "name",
+    "onbeforeunload",
+    "onblur",
+    "onchange",
+    "onclick",
+    "onerror",
+    "onfocus",
+    "onhashchange",
+    "oninput",
+    "onkeydown",
+    "onkeypress",
+    "onkeyup",
+    "onload",
+    "onmessage",
+    "onmousedown",
+    "onmousemove",
+    "onmouseout",
+    "onmouseover",
+    "onmouseup",
+    "onpopstate",
+    "onresize",
+    "onscroll",
+    "onsubmit",
+    "onunload",
+    "opener",
+    "status",
+];
+
+pub const NODE: &[&str] = &[
+    "__dirname",
+    "__filename",
+    "AbortController",
+    "AbortSignal",
+    "Buffer",
+    "clearImmediate",
+    "clearInterval",
+    "clearTimeout",
+    "console",
+    "Event",
+    "EventTarget",
+    "exports",
+    "fetch",
+    "global",
+    "Intl",
+    "MessageChannel",
+    "MessageEvent",
+    "MessagePort",
+    "module",
+    "performance",
+    "process",
+    "queueMicrotask",
+    "require",
+    "setImmediate",
+    "setInterval",
+    "setTimeout",
+    "structuredClone",
+    "TextDecoder",
+    "TextEncoder",
+    "URL",
+    "URLSearchParams",
+    "WebAssembly",
+];
+
+pub const NODE_WRITABLE: &[&str] = &["exports"];
+
+pub const COMMONJS: &[&str] = &["exports", "global", "module", "require"];
+
+pub const SHARED_NODE_BROWSER: &[&str] = &[
+    "clearInterval",
+    "clearTimeout",
+    "console",
+    "Intl",
+    "queueMicrotask",
+    "setInterval",
+    "setTimeout",
+    "TextDecoder",
+    "TextEncoder",
+    "URL",
+    "URLSearchParams",
+    "WebAssembly",
+];
+
+pub const WORKER: &[&str] = &[
+    "AbortController",
+    "AbortSignal",
+    "addEventListener",
+    "atob",
+    "Blob",
+    "BroadcastChannel",
+    "btoa",
+    "caches",
+    "clearInterval",
+    "clearTimeout",
+    "close",
+    "console",
+    "createImageBitmap",
+    "crypto",
+    "CustomEvent",
+    "dispatchEvent",
+    "ErrorEvent",
+    "Event",
+    "EventSource",
+    "EventTarget",
+    "fetch",
+    "File",
+    "FileReader",
+    "FileReaderSync",
+    "FormData",
+    "Headers",
+    "IDBFactory",
+    "ImageData",
+    "importScripts",
+    "indexedDB",
+    "Intl",
+    "location",
+    "MessageChannel",
+    "MessageEvent",
+    "MessagePort",
+    "name",
+    "navigator",
+    "Notification",
+    "onerror",
+    "onmessage",
+    "onmessageerror",
+    "performance",
+    "postMessage",
+    "ProgressEvent",
+    "queueMicrotask",
+    "ReadableStream",
+    "removeEventListener",
+    "Request",
+    "Response",
+    "self",
+    "setInterval",
+    "setTimeout",
+    "structuredClone",
+    "TextDecoder",
+    "TextEncoder",
+    "URL",
+    "URLSearchParams",
+    "WebAssembly",
+    "WebSocket",
+    "Worker",
+    "WorkerGlobalScope",
+    "WritableStream",
+    "XMLHttpRequest",
+];
+
+pub const WORKER_WRITABLE: &[&str] = &["onerror", "onmessage", "onmessageerror"];
+
+pub const JEST: &[&str] = &[
+    "afterAll",
+    "afterEach",
+    "beforeAll",
+    "beforeEach",
+    "describe",
+    "expect",
+    "fdescribe",
+    "fit",
+    "it",
+    "jest",
+    "pit",
+    "require",
+    "test",
+    "xdescribe",
+    "xit",
+    "xtest",
+];
+
+pub const MOCHA: &[&str] = &[
+    "after",
+    "afterEach",
+    "before",
+    "beforeEach",
+    "context",
+    "describe",
+    "it",
+    "mocha",
+    "run",
+    "setup",
+    "specify",
+    "suite",
+    "suiteSetup",
+    "suiteTeardown",
+    "teardown",
+    "test",
+    "xcontext",
+    "xdescribe",
+    "xit",
+    "xspecify",
+];
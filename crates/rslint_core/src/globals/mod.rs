@@ -0,0 +1,198 @@
+//! Global variables provided by JavaScript environments.
+//!
+//! The lists of globals for every environment are bundled inside of the crate, an environment
+//! such as `browser` or `node` can be enabled by name, and single globals can be added or removed on top of that.
+//! Files can also declare their own globals with `/* global foo, bar: writable */` comments, those are
+//! collected by the [`SemanticModel`](crate::SemanticModel).
+
+mod list;
+
+use rslint_parser::SmolStr;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Whether a global can be assigned to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum GlobalValue {
+    #[serde(alias = "readable")]
+    Readonly,
+    #[serde(alias = "writeable")]
+    Writable,
+    /// Remove a global which was enabled by an environment.
+    Off,
+}
+
+/// A named set of globals such as `browser` or `jest`.
+#[derive(Debug, Clone, Copy)]
+pub struct Env {
+    pub name: &'static str,
+    pub globals: &'static [&'static str],
+    /// Globals of this environment which may be assigned to, every other global is readonly.
+    pub writable: &'static [&'static str],
+}
+
+/// All of the environments which can be enabled.
+pub const ENVIRONMENTS: &[Env] = &[
+    Env {
+        name: "builtin",
+        globals: list::BUILTIN,
+        writable: &[],
+    },
+    Env {
+        name: "es5",
+        globals: list::ES5,
+        writable: &[],
+    },
+    Env {
+        name: "es6",
+        globals: list::ES2015,
+        writable: &[],
+    },
+    Env {
+        name: "es2015",
+        globals: list::ES2015,
+        writable: &[],
+    },
+    Env {
+        name: "es2017",
+        globals: list::ES2017,
+        writable: &[],
+    },
+    Env {
+        name: "es2020",
+        globals: list::ES2020,
+        writable: &[],
+    },
+    Env {
+        name: "es2021",
+        globals: list::ES2021,
+        writable: &[],
+    },
+    Env {
+        name: "browser",
+        globals: list::BROWSER,
+        writable: list::BROWSER_WRITABLE,
+    },
+    Env {
+        name: "node",
+        globals: list::NODE,
+        writable: list::NODE_WRITABLE,
+    },
+    Env {
+        name: "commonjs",
+        globals: list::COMMONJS,
+        writable: list::NODE_WRITABLE,
+    },
+    Env {
+        name: "shared-node-browser",
+        globals: list::SHARED_NODE_BROWSER,
+        writable: &[],
+    },
+    Env {
+        name: "worker",
+        globals: list::WORKER,
+        writable: list::WORKER_WRITABLE,
+    },
+    Env {
+        name: "jest",
+        globals: list::JEST,
+        writable: &[],
+    },
+    Env {
+        name: "mocha",
+        globals: list::MOCHA,
+        writable: &[],
+    },
+];
+
+/// Get an environment by its name.
+pub fn env(name: &str) -> Option<&'static Env> {
+    ENVIRONMENTS.iter().find(|env| env.name == name)
+}
+
+/// A set of globals which are available to a file.
+///
+/// The default set contains every ECMAScript builtin (the `builtin` environment).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Globals {
+    names: HashMap<SmolStr, GlobalValue>,
+}
+
+impl Default for Globals {
+    fn default() -> Self {
+        let mut globals = Self::empty();
+        globals.add_env("builtin");
+        globals
+    }
+}
+
+impl Globals {
+    /// A set with no globals at all, not even ECMAScript builtins.
+    pub fn empty() -> Self {
+        Self {
+            names: HashMap::new(),
+        }
+    }
+
+    /// Add every global of an environment, returns `false` if there is no environment with this name.
+    pub fn add_env(&mut self, name: &str) -> bool {
+        let env = match env(name) {
+            Some(env) => env,
+            None => return false,
+        };
+        for global in env.globals {
+            let value = if env.writable.contains(global) {
+                GlobalValue::Writable
+            } else {
+                GlobalValue::Readonly
+            };
+            self.insert(*global, value);
+        }
+        true
+    }
+
+    /// Add a single global, or remove it if the value is [`GlobalValue::Off`].
+    pub fn insert(&mut self, name: impl Into<SmolStr>, value: GlobalValue) {
+        if value == GlobalValue::Off {
+            self.names.remove(&name.into());
+        } else {
+            self.names.insert(name.into(), value);
+        }
+    }
+
+    /// Whether a global with this name exists and whether it can be assigned to.
+    pub fn get(&self, name: &str) -> Option<GlobalValue> {
+        self.names.get(name).copied()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains_key(name)
+    }
+
+    /// The names of all of the globals in this set.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.keys().map(|name| name.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn envs() {
+        let mut globals = Globals::default();
+        assert_eq!(globals.get("Promise"), Some(GlobalValue::Readonly));
+        assert!(!globals.contains("window"));
+        assert!(globals.add_env("browser"));
+        assert!(globals.contains("window"));
+        assert!(!globals.add_env("not-an-env"));
+
+        globals.insert("window", GlobalValue::Off);
+        assert!(!globals.contains("window"));
+        globals.insert("foo", GlobalValue::Writable);
+        assert_eq!(globals.get("foo"), Some(GlobalValue::Writable));
+    }
+}
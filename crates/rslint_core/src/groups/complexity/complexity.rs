@@ -0,0 +1,177 @@
+use super::INFERRED_PERCENTILE;
+use crate::rule_prelude::*;
+use crate::Inferable;
+use ast::{AssignExpr, AssignOp, BinExpr, BinOp};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce a maximum cyclomatic complexity for functions.
+
+    Cyclomatic complexity is the number of independent paths through a function. Every function starts with
+    a complexity of one, and every branch adds one to it. Branches are `if` statements, conditional
+    expressions, loops, `case` clauses, `catch` clauses, logical operators (`&&`, `||`, and `??`),
+    and logical assignments (`&&=`, `||=`, and `??=`).
+
+    Functions with a high complexity are hard to read, hard to test, and are usually better split into
+    multiple smaller functions. Nested functions do not count toward the complexity of the function they are in.
+
+    ## Incorrect Code Examples
+
+    With the default maximum of `20`, or a maximum of `2`:
+
+    ```js
+    function foo(a, b) {
+        if (a) {
+            return 1;
+        } else if (b) {
+            return 2;
+        }
+        return 3;
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function foo(a) {
+        if (a) {
+            return 1;
+        }
+        return 2;
+    }
+    ```
+    */
+    #[serde(default)]
+    Complexity,
+    complexity,
+    "complexity",
+    /// The maximum complexity allowed for a function (20 by default).
+    pub max: usize
+}
+
+impl Default for Complexity {
+    fn default() -> Self {
+        Self { max: 20 }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for Complexity {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !util::is_function(node) {
+            return None;
+        }
+        let complexity = complexity(node);
+        if complexity <= self.max {
+            return None;
+        }
+
+        let (name, range) = util::function_name(node)?;
+        let err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "{} has a complexity of {}, but the maximum allowed is {}",
+                    name, complexity, self.max
+                ),
+            )
+            .primary(
+                range,
+                format!("this function has a complexity of {}", complexity),
+            );
+        ctx.add_err(err);
+        None
+    }
+}
+
+#[typetag::serde]
+impl Inferable for Complexity {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let values = nodes
+            .iter()
+            .filter(|node| util::is_function(*node))
+            .map(complexity)
+            .collect();
+        if let Some(max) = util::percentile(values, INFERRED_PERCENTILE) {
+            self.max = max;
+        }
+    }
+}
+
+/// The cyclomatic complexity of a function.
+fn complexity(function: &SyntaxNode) -> usize {
+    1 + branches(function)
+}
+
+/// The number of branches inside of a node, not including branches inside of nested functions.
+fn branches(node: &SyntaxNode) -> usize {
+    node.children()
+        .filter(|child| !util::is_function(child))
+        .map(|child| {
+            let is_branch = match child.kind() {
+                IF_STMT | COND_EXPR | FOR_STMT | FOR_IN_STMT | FOR_OF_STMT | WHILE_STMT
+                | DO_WHILE_STMT | CASE_CLAUSE | CATCH_CLAUSE => true,
+                BIN_EXPR => matches!(
+                    child.to::<BinExpr>().op(),
+                    Some(BinOp::LogicalAnd)
+                        | Some(BinOp::LogicalOr)
+                        | Some(BinOp::NullishCoalescing)
+                ),
+                ASSIGN_EXPR => matches!(
+                    child.to::<AssignExpr>().op(),
+                    Some(AssignOp::LogicalAndAssign)
+                        | Some(AssignOp::LogicalOrAssign)
+                        | Some(AssignOp::NullishCoalescingAssign)
+                ),
+                _ => false,
+            };
+            is_branch as usize + branches(&child)
+        })
+        .sum()
+}
+
+rule_tests! {
+    Complexity { max: 2 },
+    err: {
+        "function foo(a, b) { if (a) {} else if (b) {} }",
+        "function foo(a, b) { return a && b || c; }",
+        "const foo = (a) => { while (a) {} for (;;) {} };",
+        "function foo(a) { switch (a) { case 1: break; case 2: break; } }",
+        "function foo(a) { try {} catch (e) { a ? b : c; } if (a) {} }",
+        "function foo(a) { a ??= 1; a ||= 2; }",
+        "class A { foo(a) { for (const b of a) {} for (const b in a) {} } }",
+        "function foo(a) { if (a) {} function bar(b) { if (b) {} if (b) {} } }",
+        "function foo(a = b || c, d = e ?? f) {}"
+    },
+    ok: {
+        "function foo(a) { if (a) {} }",
+        "function foo(a) { switch (a) { case 1: break; default: break; } }",
+        "function foo(a) { a += 1; a = a + 1; do {} while (a); }",
+        "function foo(a) { function bar() { if (a) {} } function baz() { if (a) {} } }",
+        "function foo(a) { try {} finally {} return a | b & c; }",
+        "if (a) {} if (b) {} if (c) {}"
+    }
+}
+
+rule_tests! {
+    default_valid,
+    default_invalid,
+    Complexity::default(),
+    err: {
+        "function foo(a) {
+            if (a == 1) {} if (a == 2) {} if (a == 3) {} if (a == 4) {} if (a == 5) {}
+            if (a == 6) {} if (a == 7) {} if (a == 8) {} if (a == 9) {} if (a == 10) {}
+            if (a == 11) {} if (a == 12) {} if (a == 13) {} if (a == 14) {} if (a == 15) {}
+            if (a == 16) {} if (a == 17) {} if (a == 18) {} if (a == 19) {} if (a == 20) {}
+        }"
+    },
+    ok: {
+        "function foo(a) {
+            if (a == 1) {} if (a == 2) {} if (a == 3) {} if (a == 4) {} if (a == 5) {}
+            if (a == 6) {} if (a == 7) {} if (a == 8) {} if (a == 9) {} if (a == 10) {}
+            if (a == 11) {} if (a == 12) {} if (a == 13) {} if (a == 14) {} if (a == 15) {}
+            if (a == 16) {} if (a == 17) {} if (a == 18) {} if (a == 19) {}
+        }"
+    }
+}
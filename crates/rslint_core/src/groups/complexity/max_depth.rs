@@ -0,0 +1,203 @@
+use super::INFERRED_PERCENTILE;
+use crate::rule_prelude::*;
+use crate::Inferable;
+use ast::IfStmt;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce a maximum depth that blocks can be nested.
+
+    Deeply nested blocks are hard to read and to follow, they can usually be flattened with early returns,
+    or by moving parts of the code into separate functions.
+
+    `if`, `switch`, `try`, `with` statements and loops add a level of nesting, `else if` does not add a level
+    of nesting on top of the `if` it belongs to. Every function starts at a depth of zero.
+
+    ## Incorrect Code Examples
+
+    With the default maximum of `4`:
+
+    ```js
+    function foo() {
+        for (;;) {
+            while (true) {
+                if (a) {
+                    if (b) {
+                        if (c) {}
+                    }
+                }
+            }
+        }
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function foo() {
+        for (;;) {
+            while (true) {
+                if (a) {
+                    if (b) {}
+                }
+            }
+        }
+    }
+    ```
+
+    ```js
+    function foo() {
+        if (a) {
+        } else if (b) {
+        } else if (c) {
+        } else if (d) {
+        } else if (e) {
+        }
+    }
+    ```
+    */
+    #[serde(default)]
+    MaxDepth,
+    complexity,
+    "max-depth",
+    /// The maximum depth that blocks can be nested (4 by default).
+    pub max: usize
+}
+
+impl Default for MaxDepth {
+    fn default() -> Self {
+        Self { max: 4 }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for MaxDepth {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if util::is_function(node) {
+            self.check(node, ctx);
+        }
+        None
+    }
+
+    // statements at the top level which are not inside of any function
+    fn check_root(&self, root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        self.check(root, ctx)
+    }
+}
+
+impl MaxDepth {
+    fn check(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let (depth, deepest) = deepest(node, 0);
+        if depth <= self.max {
+            return None;
+        }
+        let deepest = deepest?.first_token()?;
+
+        let err = match util::function_name(node) {
+            Some((name, range)) => ctx
+                .err(
+                    self.name(),
+                    format!(
+                        "blocks in {} are nested {} levels deep, but the maximum allowed is {}",
+                        name, depth, self.max
+                    ),
+                )
+                .primary(
+                    range,
+                    format!("blocks in this function are nested {} levels deep", depth),
+                )
+                .secondary(
+                    deepest.text_range(),
+                    format!("this is nested {} levels deep", depth),
+                ),
+            None => ctx
+                .err(
+                    self.name(),
+                    format!(
+                        "blocks are nested {} levels deep, but the maximum allowed is {}",
+                        depth, self.max
+                    ),
+                )
+                .primary(
+                    deepest.text_range(),
+                    format!("this is nested {} levels deep", depth),
+                ),
+        };
+        ctx.add_err(err);
+        None
+    }
+}
+
+#[typetag::serde]
+impl Inferable for MaxDepth {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let values = nodes
+            .iter()
+            .filter(|node| util::is_function(*node) || matches!(node.kind(), SCRIPT | MODULE))
+            .map(|node| deepest(node, 0).0)
+            .collect();
+        if let Some(max) = util::percentile(values, INFERRED_PERCENTILE) {
+            self.max = max;
+        }
+    }
+}
+
+/// The depth of the most deeply nested statement inside of a node and the statement itself,
+/// not including statements inside of nested functions.
+fn deepest(node: &SyntaxNode, depth: usize) -> (usize, Option<SyntaxNode>) {
+    let mut result = (depth, None);
+    for child in node.children().filter(|child| !util::is_function(child)) {
+        let nests = matches!(
+            child.kind(),
+            IF_STMT
+                | SWITCH_STMT
+                | TRY_STMT
+                | WITH_STMT
+                | DO_WHILE_STMT
+                | WHILE_STMT
+                | FOR_STMT
+                | FOR_IN_STMT
+                | FOR_OF_STMT
+        ) && !is_else_if(&child);
+
+        let child_result = if nests {
+            let (child_depth, child_deepest) = deepest(&child, depth + 1);
+            (child_depth, child_deepest.or(Some(child)))
+        } else {
+            deepest(&child, depth)
+        };
+        if child_result.0 > result.0 {
+            result = child_result;
+        }
+    }
+    result
+}
+
+fn is_else_if(node: &SyntaxNode) -> bool {
+    node.kind() == IF_STMT
+        && node
+            .parent()
+            .and_then(|parent| parent.try_to::<IfStmt>()?.alt())
+            .map_or(false, |alt| alt.syntax() == node)
+}
+
+rule_tests! {
+    MaxDepth { max: 2 },
+    err: {
+        "function foo() { if (a) { if (b) { if (c) {} } } }",
+        "function foo() { for (;;) { while (a) { do {} while (b); } } }",
+        "function foo() { try { switch (a) { case 1: while (b) {} } } catch (e) {} }",
+        "const foo = () => { for (const a of b) { for (const c in a) { if (c) {} } } };",
+        "if (a) { if (b) { if (c) {} } }",
+        "function foo() { if (a) {} else { if (b) { if (c) {} } } }",
+        "function foo() { if (a) { function bar() { if (b) { if (c) { if (d) {} } } } } }"
+    },
+    ok: {
+        "function foo() { if (a) { if (b) {} } }",
+        "function foo() { if (a) {} else if (b) {} else if (c) {} else if (d) {} }",
+        "function foo() { if (a) { if (b) { function bar() { if (c) { if (d) {} } } } } }",
+        "function foo() { if (a) { if (b) { const bar = () => { if (c) {} }; } } }",
+        "if (a) { if (b) { { { foo(); } } } }"
+    }
+}
@@ -0,0 +1,139 @@
+use super::INFERRED_PERCENTILE;
+use crate::rule_prelude::*;
+use crate::Inferable;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce a maximum depth that callbacks can be nested.
+
+    Deeply nested callbacks, often called "callback hell", are hard to read and to follow. They can usually
+    be flattened by using promises and `async` functions, or by moving the callbacks into named functions.
+
+    A callback is a function or an arrow function which is passed directly as an argument to a call.
+    Functions which are not callbacks do not add a level of nesting.
+
+    ## Incorrect Code Examples
+
+    With a maximum of `3`:
+
+    ```js
+    foo(function () {
+        bar(function () {
+            baz(function () {
+                qux(function () {});
+            });
+        });
+    });
+    ```
+
+    ## Correct Code Examples
+
+    With a maximum of `3`:
+
+    ```js
+    foo(function () {
+        bar(function () {
+            baz(handleBaz);
+        });
+    });
+
+    function handleBaz() {
+        qux(function () {});
+    }
+    ```
+    */
+    #[serde(default)]
+    MaxNestedCallbacks,
+    complexity,
+    "max-nested-callbacks",
+    /// The maximum depth that callbacks can be nested (10 by default).
+    pub max: usize
+}
+
+impl Default for MaxNestedCallbacks {
+    fn default() -> Self {
+        Self { max: 10 }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for MaxNestedCallbacks {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !is_callback(node) {
+            return None;
+        }
+        let depth = depth(node);
+        if depth <= self.max {
+            return None;
+        }
+
+        let (_, range) = util::function_name(node)?;
+        let err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "callbacks are nested {} levels deep, but the maximum allowed is {}",
+                    depth, self.max
+                ),
+            )
+            .primary(
+                range,
+                format!("this callback is nested {} levels deep", depth),
+            );
+        ctx.add_err(err);
+        None
+    }
+}
+
+#[typetag::serde]
+impl Inferable for MaxNestedCallbacks {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let values = nodes
+            .iter()
+            .filter(|node| is_callback(node))
+            .map(depth)
+            .collect();
+        if let Some(max) = util::percentile(values, INFERRED_PERCENTILE) {
+            self.max = max;
+        }
+    }
+}
+
+/// Whether a node is a function passed directly as an argument to a call, e.g. `foo(() => {})`.
+fn is_callback(node: &SyntaxNode) -> bool {
+    if !matches!(node.kind(), FN_EXPR | ARROW_EXPR) {
+        return false;
+    }
+    node.ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.kind() != GROUPING_EXPR)
+        .filter(|parent| parent.kind() == ARG_LIST)
+        .and_then(|args| args.parent())
+        .map_or(false, |call| call.kind() == CALL_EXPR)
+}
+
+/// The number of callbacks a callback is nested in, including the callback itself.
+fn depth(callback: &SyntaxNode) -> usize {
+    callback.ancestors().filter(is_callback).count()
+}
+
+rule_tests! {
+    MaxNestedCallbacks { max: 2 },
+    err: {
+        "foo(function () { bar(function () { baz(function () {}); }); });",
+        "foo(() => { bar(() => { baz(() => {}); }); });",
+        "foo(() => bar(() => baz(() => {})));",
+        "foo(() => { bar(() => { function qux() { baz(() => {}); } }); });",
+        "foo(() => { bar(() => { baz((() => {})); }); });",
+        "foo.then(() => { bar.then(() => { baz.then(() => {}); }); });"
+    },
+    ok: {
+        "foo(function () { bar(function () {}); });",
+        "foo(() => { bar(() => { baz(qux); }); });",
+        "function foo() { function bar() { function baz() { qux(() => {}); } } }",
+        "foo(() => { const a = () => { const b = () => {}; }; });",
+        "foo(() => { new Bar(() => { new Baz(() => {}); }); });",
+        "foo(() => { bar(() => {}); baz(() => {}); });"
+    }
+}
@@ -0,0 +1,130 @@
+use super::INFERRED_PERCENTILE;
+use crate::rule_prelude::*;
+use crate::Inferable;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce a maximum number of parameters for functions.
+
+    Functions with many parameters are hard to call correctly, since the order of the arguments has to be
+    remembered. They are often a sign that a function does too much. Passing an object with named
+    properties instead is usually easier to read and to extend.
+
+    Rest parameters and parameters with default values also count as parameters.
+
+    ## Incorrect Code Examples
+
+    With the default maximum of `3`:
+
+    ```js
+    function foo(a, b, c, d) {}
+    ```
+
+    ```js
+    const foo = (a, b, c, ...d) => {};
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function foo(a, b, c) {}
+    ```
+
+    ```js
+    function foo({ a, b, c, d }) {}
+    ```
+    */
+    #[serde(default)]
+    MaxParams,
+    complexity,
+    "max-params",
+    /// The maximum number of parameters allowed for a function (3 by default).
+    pub max: usize
+}
+
+impl Default for MaxParams {
+    fn default() -> Self {
+        Self { max: 3 }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for MaxParams {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !util::is_function(node) {
+            return None;
+        }
+        let params = params(node);
+        if params <= self.max {
+            return None;
+        }
+
+        let (name, range) = util::function_name(node)?;
+        let mut err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "{} has {} parameters, but the maximum allowed is {}",
+                    name, params, self.max
+                ),
+            )
+            .primary(range, format!("this function has {} parameters", params));
+        // the parameters are already labeled if the function has no name
+        if let Some(list) = node
+            .child_with_kind(PARAMETER_LIST)
+            .filter(|list| !range.contains_range(list.trimmed_range()))
+        {
+            err = err.secondary(list.trimmed_range(), "parameters declared here");
+        }
+        ctx.add_err(err);
+        None
+    }
+}
+
+#[typetag::serde]
+impl Inferable for MaxParams {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let values = nodes
+            .iter()
+            .filter(|node| util::is_function(*node))
+            .map(params)
+            .collect();
+        if let Some(max) = util::percentile(values, INFERRED_PERCENTILE) {
+            self.max = max;
+        }
+    }
+}
+
+/// The number of parameters of a function.
+fn params(function: &SyntaxNode) -> usize {
+    match function.child_with_kind(PARAMETER_LIST) {
+        Some(list) => list.children().count(),
+        // arrow functions with a single parameter and no parentheses, e.g. `a => a`
+        None if function.kind() == ARROW_EXPR => function.child_with_kind(NAME).is_some() as usize,
+        None => 0,
+    }
+}
+
+rule_tests! {
+    MaxParams { max: 2 },
+    err: {
+        "function foo(a, b, c) {}",
+        "const foo = function (a, b, c) {};",
+        "(a, b, c) => {};",
+        "function foo(a, b, ...c) {}",
+        "function foo(a, b = 1, { c }) {}",
+        "class A { foo(a, b, c) {} }",
+        "class A { constructor(a, b, c) {} }",
+        "({ foo(a, b, [c]) {} });"
+    },
+    ok: {
+        "function foo() {}",
+        "function foo(a, b) {}",
+        "a => a;",
+        "(a, b) => {};",
+        "function foo({ a, b, c }) {}",
+        "function foo(a, [b, c, d]) {}",
+        "class A { set foo(value) {} }"
+    }
+}
@@ -0,0 +1,142 @@
+use super::INFERRED_PERCENTILE;
+use crate::rule_prelude::*;
+use crate::Inferable;
+use ast::Stmt;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce a maximum number of statements in functions.
+
+    Functions with many statements usually do too many things at once, which makes them hard to read
+    and to test. Splitting them up into smaller functions which each do one thing makes code easier to follow.
+
+    Statements inside of nested blocks are also counted, statements inside of nested functions are not counted
+    toward the function they are in.
+
+    ## Incorrect Code Examples
+
+    With a maximum of `2`:
+
+    ```js
+    function foo() {
+        let a = 1;
+        let b = 2;
+        return a + b;
+    }
+    ```
+
+    ```js
+    function foo() {
+        if (a) {
+            bar();
+            baz();
+        }
+    }
+    ```
+
+    ## Correct Code Examples
+
+    With a maximum of `2`:
+
+    ```js
+    function foo() {
+        let a = 1;
+        return function () {
+            let b = 2;
+            return a + b;
+        };
+    }
+    ```
+    */
+    #[serde(default)]
+    MaxStatements,
+    complexity,
+    "max-statements",
+    /// The maximum number of statements allowed in a function (10 by default).
+    pub max: usize
+}
+
+impl Default for MaxStatements {
+    fn default() -> Self {
+        Self { max: 10 }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for MaxStatements {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !util::is_function(node) {
+            return None;
+        }
+        let statements = statements(node);
+        if statements <= self.max {
+            return None;
+        }
+
+        let (name, range) = util::function_name(node)?;
+        let err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "{} has {} statements, but the maximum allowed is {}",
+                    name, statements, self.max
+                ),
+            )
+            .primary(
+                range,
+                format!("this function has {} statements", statements),
+            );
+        ctx.add_err(err);
+        None
+    }
+}
+
+#[typetag::serde]
+impl Inferable for MaxStatements {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let values = nodes
+            .iter()
+            .filter(|node| util::is_function(*node))
+            .map(statements)
+            .collect();
+        if let Some(max) = util::percentile(values, INFERRED_PERCENTILE) {
+            self.max = max;
+        }
+    }
+}
+
+/// The number of statements in blocks inside of a node, not including statements inside of nested functions.
+fn statements(node: &SyntaxNode) -> usize {
+    node.children()
+        .map(|child| {
+            let counted = (node.kind() == BLOCK_STMT && Stmt::can_cast(child.kind())) as usize;
+            if util::is_function(&child) {
+                counted
+            } else {
+                counted + statements(&child)
+            }
+        })
+        .sum()
+}
+
+rule_tests! {
+    MaxStatements { max: 2 },
+    err: {
+        "function foo() { let a = 1; let b = 2; return a + b; }",
+        "function foo() { if (a) { bar(); baz(); } }",
+        "const foo = () => { a(); b(); c(); };",
+        "class A { foo() { a(); b(); c(); } }",
+        "function foo() { try { a(); } catch (e) { b(); } }",
+        "function foo() { a(); b(); function bar() {} }",
+        "function foo() { a(); b(); function bar() { c(); d(); e(); } }"
+    },
+    ok: {
+        "function foo() {}",
+        "function foo() { let a = 1; return a; }",
+        "function foo() { a(); return function () { b(); c(); }; }",
+        "const foo = () => a && b && c;",
+        "function foo() { switch (a) { case 1: b(); c(); d(); } }",
+        "a(); b(); c();"
+    }
+}
@@ -0,0 +1,18 @@
+//! Rules which limit the complexity of code to keep it maintainable.
+
+use crate::group;
+
+group! {
+    /// Rules which limit the complexity of code, such as the number of paths through a function
+    /// or how deeply blocks are nested, to keep it readable and maintainable.
+    complexity,
+    complexity::Complexity,
+    max_depth::MaxDepth,
+    max_params::MaxParams,
+    max_nested_callbacks::MaxNestedCallbacks,
+    max_statements::MaxStatements,
+}
+
+/// The percentile of the values measured in existing code which rules in this group use as the inferred maximum.
+/// Using a high percentile instead of the maximum means a few outliers do not make the limit useless.
+const INFERRED_PERCENTILE: f64 = 95.0;
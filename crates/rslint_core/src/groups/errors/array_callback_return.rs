@@ -0,0 +1,272 @@
+use crate::rule_prelude::*;
+use ast::{CallExpr, Expr};
+use rslint_parser::TextRange;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce return statements in callbacks of array methods.
+
+    Methods such as `map`, `filter`, and `reduce` use the value returned by their callback, forgetting to
+    return a value from the callback is almost always a mistake:
+
+    ```js
+    const doubled = numbers.map(num => {
+        num * 2;
+    });
+    // doubled is [undefined, undefined, ...]
+    ```
+
+    This rule checks the callbacks of `Array.from`, `every`, `filter`, `find`, `findIndex`, `findLast`,
+    `findLastIndex`, `flatMap`, `map`, `reduce`, `reduceRight`, `some`, `sort`, and `toSorted`, and reports
+    callbacks which do not return a value on every path. Async functions and generators are not checked since
+    they always return a value.
+
+    The `checkForEach` option also reports callbacks of `forEach` which return a value, since `forEach`
+    ignores it.
+
+    ## Incorrect Code Examples
+
+    ```js
+    const indexMap = myArray.reduce(function(memo, item, index) {
+        memo[item] = index;
+    }, {});
+    ```
+
+    ```js
+    const foo = Array.from(nodes, function(node) {
+        if (node.tagName === "DIV") {
+            return true;
+        }
+    });
+    ```
+
+    ```js
+    const bar = foo.filter(function(x) {
+        if (x) {
+            return true;
+        } else {
+            return;
+        }
+    });
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const indexMap = myArray.reduce(function(memo, item, index) {
+        memo[item] = index;
+        return memo;
+    }, {});
+    ```
+
+    ```js
+    const foo = Array.from(nodes, node => node.tagName === "DIV");
+    ```
+    */
+    #[serde(default)]
+    #[derive(Default)]
+    ArrayCallbackReturn,
+    errors,
+    "array-callback-return",
+    /// Whether to allow implicitly returning `undefined` with `return;`.
+    /// `false` by default.
+    pub allow_implicit: bool,
+    /// Whether to report callbacks of `forEach` which return a value.
+    /// `false` by default.
+    pub check_for_each: bool
+}
+
+const CHECKED_METHODS: [&str; 14] = [
+    "every",
+    "filter",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "flatMap",
+    "map",
+    "reduce",
+    "reduceRight",
+    "some",
+    "sort",
+    "toSorted",
+    "forEach",
+];
+
+#[typetag::serde]
+impl CstRule for ArrayCallbackReturn {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let call = node.try_to::<CallExpr>()?;
+        let callee = call.callee()?.syntax().try_to::<ast::DotExpr>()?;
+        let prop = callee.prop()?;
+        let name = prop.text();
+
+        // `Array.from(items, callback)` takes the callback as its second argument
+        let (method, index) = if name == "from"
+            && callee
+                .object()
+                .map_or(false, |object| object.syntax().text() == "Array")
+        {
+            ("Array.from".to_string(), 1)
+        } else if CHECKED_METHODS.contains(&name.as_str()) {
+            (format!("Array.prototype.{}", name), 0)
+        } else {
+            return None;
+        };
+
+        let callback = callback(call.arguments()?.args().nth(index)?)?;
+        if is_async_or_generator(&callback) {
+            return None;
+        }
+        let cfg = ctx.cfg(&callback)?;
+
+        if name == "forEach" {
+            if !self.check_for_each {
+                return None;
+            }
+            let mut returns = cfg.value_returns().filter_map(|edge| edge.stmt.as_ref());
+            let first = returns.next()?;
+            let mut err = ctx
+                .err(
+                    self.name(),
+                    format!("`{}` ignores the value returned by its callback", method),
+                )
+                .secondary(
+                    prop.syntax().trimmed_range(),
+                    "`forEach` does not use the returned value...",
+                )
+                .primary(first, "...so returning this value is useless");
+            for stmt in returns {
+                err = err.secondary(stmt, "this value is also ignored");
+            }
+            ctx.add_err(err);
+            return None;
+        }
+
+        let mut labels = cfg
+            .missing_returns(self.allow_implicit)
+            .map(|edge| match &edge.stmt {
+                Some(stmt) => (stmt.trimmed_range(), "this returns `undefined`"),
+                None => (
+                    end_of(&callback),
+                    "`undefined` is returned if the callback reaches its end",
+                ),
+            })
+            .collect::<Vec<_>>();
+        if labels.is_empty() {
+            return None;
+        }
+        labels.sort_by_key(|(range, _)| range.start());
+
+        let (first_range, first_msg) = labels.remove(0);
+        let mut err = ctx
+            .err(
+                self.name(),
+                format!("`{}` expects a return value from its callback", method),
+            )
+            .secondary(
+                prop.syntax().trimmed_range(),
+                format!("`{}` uses the value returned by the callback...", name),
+            )
+            .primary(
+                first_range,
+                format!(
+                    "...but the callback does not always return a value, {}",
+                    first_msg
+                ),
+            );
+        for (range, msg) in labels {
+            err = err.secondary(range, msg);
+        }
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// The function passed as a callback, unwrapping parentheses and `.bind()` calls.
+fn callback(expr: Expr) -> Option<SyntaxNode> {
+    match expr {
+        Expr::GroupingExpr(group) => callback(group.inner()?),
+        Expr::FnExpr(_) | Expr::ArrowExpr(_) => Some(expr.syntax().clone()),
+        Expr::CallExpr(call) => {
+            let callee = call.callee()?.syntax().try_to::<ast::DotExpr>()?;
+            if callee.prop()?.text() == "bind" {
+                callback(callee.object()?)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_async_or_generator(callback: &SyntaxNode) -> bool {
+    match callback.kind() {
+        FN_EXPR => {
+            let func = callback.to::<ast::FnExpr>();
+            func.async_token().is_some() || func.star_token().is_some()
+        }
+        ARROW_EXPR => callback.to::<ast::ArrowExpr>().async_token().is_some(),
+        _ => false,
+    }
+}
+
+/// The range of the closing brace of the callback's body.
+fn end_of(callback: &SyntaxNode) -> TextRange {
+    callback
+        .child_with_kind(BLOCK_STMT)
+        .and_then(|body| body.token_with_kind(R_CURLY))
+        .map_or_else(|| callback.trimmed_range(), |token| token.text_range())
+}
+
+rule_tests! {
+    ArrayCallbackReturn::default(),
+    err: {
+        "foo.map(function(x) { x * 2; });",
+        "foo.map(x => { x * 2; });",
+        "foo.filter(function(x) { if (x) { return true; } });",
+        "foo.filter(function(x) { if (x) { return true; } else { return; } });",
+        "foo.reduce(function(memo, item) { memo[item] = 1; }, {});",
+        "foo.every(x => { if (x) return true; });",
+        "foo.some((function(x) { x; }));",
+        "foo.sort(function(a, b) { a - b; }.bind(this));",
+        "Array.from(nodes, function(node) { node.tagName; });",
+        "foo?.find(x => { x; });",
+        "foo.flatMap(function(x) { switch (x) { case 1: return [1]; } });"
+    },
+    ok: {
+        "foo.map(function(x) { return x * 2; });",
+        "foo.map(x => x * 2);",
+        "foo.filter(function(x) { if (x) { return true; } else { throw x; } });",
+        "foo.reduce(function(memo, item) { memo[item] = 1; return memo; }, {});",
+        "foo.forEach(function(x) { return x; });",
+        "foo.map(async function(x) { x; });",
+        "foo.map(function* (x) { x; });",
+        "foo.bar(function(x) { x; });",
+        "from(nodes, function(node) { node; });",
+        "Array.from(nodes);",
+        "foo.map(callback);",
+        "foo.map(function(x) { function inner() {} return inner; });"
+    }
+}
+
+rule_tests! {
+    check_for_each_valid,
+    check_for_each_invalid,
+    ArrayCallbackReturn {
+        allow_implicit: true,
+        check_for_each: true,
+    },
+    err: {
+        "foo.forEach(function(x) { return x; });",
+        "foo.forEach(x => x);",
+        "foo.forEach(x => { if (x) { return 5; } });",
+        "foo.map(x => { x; });"
+    },
+    ok: {
+        "foo.forEach(function(x) { bar(x); });",
+        "foo.forEach(x => { if (x) { return; } bar(x); });",
+        "foo.filter(function(x) { if (x) { return true; } return; });"
+    }
+}
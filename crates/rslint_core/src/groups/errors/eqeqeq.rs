@@ -0,0 +1,240 @@
+use crate::rule_prelude::*;
+use ast::{BinExpr, BinOp, Expr, UnaryOp};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Require the use of `===` and `!==` instead of `==` and `!=`.
+
+    `==` and `!=` convert their operands to the same type before comparing them, following rules which
+    are hard to remember, such as `"" == 0` and `[0] == false` both being true. `===` and `!==` never
+    convert their operands, two values of different types are never equal.
+
+    The `style` option can be set to "smart" to allow `==` and `!=` where the conversion cannot
+    change the result, which is when comparing two literals, comparing the result of `typeof`, or comparing
+    against `null`. The `null` option can be set to "ignore" to allow comparing against `null` with `==`,
+    since `x == null` is a common way of checking for both `null` and `undefined`.
+
+    The operators are only automatically fixed when both operands are known to be of the same type,
+    such as a `typeof` compared to a string or two string literals, in every other case changing the
+    operator may change the behavior of the code.
+
+    ## Incorrect Code Examples
+
+    ```js
+    if (x == 42) {}
+    ```
+
+    ```js
+    if ("" == text) {}
+    ```
+
+    ```js
+    if (obj.getStuff() != undefined) {}
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    if (x === 42) {}
+    ```
+
+    ```js
+    if (typeof foo === "undefined") {}
+    ```
+    */
+    #[serde(default)]
+    Eqeqeq,
+    errors,
+    "eqeqeq",
+    /// When to report `==` and `!=`, either "always" (default) to report every use, or "smart"
+    /// to allow comparing two literals, comparing the result of `typeof`, and comparing against `null`.
+    pub style: String,
+    /// How to treat comparisons against `null` with the "always" style, either "always" (default)
+    /// to report them, or "ignore" to allow them.
+    pub null: String
+}
+
+impl Default for Eqeqeq {
+    fn default() -> Self {
+        Self {
+            style: "always".to_string(),
+            null: "always".to_string(),
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for Eqeqeq {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let bin = node.try_to::<BinExpr>()?;
+        let (op_token, op) = bin.op_details()?;
+        let strict = match op {
+            BinOp::Equality => "===",
+            BinOp::Inequality => "!==",
+            _ => return None,
+        };
+        let (lhs, rhs) = (bin.lhs()?, bin.rhs()?);
+
+        let is_null_comparison = is_null(&lhs) || is_null(&rhs);
+        let allowed = if self.style == "smart" {
+            is_null_comparison
+                || is_typeof(&lhs)
+                || is_typeof(&rhs)
+                || (literal_type(&lhs).is_some() && literal_type(&rhs).is_some())
+        } else {
+            is_null_comparison && self.null == "ignore"
+        };
+        if allowed {
+            return None;
+        }
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!("expected `{}` but found `{}`", strict, op_token.text()),
+            )
+            .primary(
+                op_token.text_range(),
+                format!(
+                    "`{}` converts its operands to the same type before comparing them",
+                    op_token.text()
+                ),
+            );
+
+        let err = if same_type(&lhs, &rhs) {
+            ctx.extend_fix().replace(op_token.text_range(), strict);
+            err.suggestion(
+                op_token.text_range(),
+                "both operands are of the same type, so this comparison can be strict",
+                strict,
+                Applicability::Always,
+            )
+        } else {
+            err.suggestion(
+                op_token.text_range(),
+                &format!(
+                    "use `{}` if the operands do not need to be converted",
+                    strict
+                ),
+                strict,
+                Applicability::MaybeIncorrect,
+            )
+        };
+        ctx.add_err(err);
+        None
+    }
+}
+
+fn is_null(expr: &Expr) -> bool {
+    matches!(expr, Expr::Literal(literal) if literal.is_null())
+}
+
+fn is_typeof(expr: &Expr) -> bool {
+    matches!(expr, Expr::UnaryExpr(unary) if unary.op() == Some(UnaryOp::Typeof))
+}
+
+/// The type of a literal, `None` if the expression is not a literal.
+fn literal_type(expr: &Expr) -> Option<&'static str> {
+    match expr {
+        Expr::Literal(literal) => {
+            let token = literal.token();
+            Some(match token.kind() {
+                NULL_KW => "null",
+                NUMBER if token.text().ends_with('n') => "bigint",
+                NUMBER => "number",
+                STRING => "string",
+                TRUE_KW | FALSE_KW => "boolean",
+                _ => "object",
+            })
+        }
+        Expr::Template(template)
+            if template.tag().is_none() && template.elements().next().is_none() =>
+        {
+            Some("string")
+        }
+        Expr::UnaryExpr(unary) if unary.op() == Some(UnaryOp::Typeof) => Some("string"),
+        _ => None,
+    }
+}
+
+/// Whether both operands are provably of the same type, in which case `==` behaves exactly like `===`.
+fn same_type(lhs: &Expr, rhs: &Expr) -> bool {
+    match (literal_type(lhs), literal_type(rhs)) {
+        (Some(lhs), Some(rhs)) => lhs == rhs,
+        _ => false,
+    }
+}
+
+rule_tests! {
+    Eqeqeq::default(),
+    err: {
+        "a == b",
+        "foo != true",
+        "x == null",
+        "null != x",
+        "typeof foo == 'undefined'",
+        "'hello' != 'world'",
+        "0 == 0",
+        "true == true",
+        "foo == undefined",
+        "a == b == c"
+    },
+    ok: {
+        "a === b",
+        "foo !== true",
+        "typeof foo === 'undefined'",
+        "x === null",
+        "a < b",
+        "a = b"
+    }
+}
+
+rule_tests! {
+    smart_valid,
+    smart_invalid,
+    Eqeqeq {
+        style: "smart".to_string(),
+        null: "always".to_string(),
+    },
+    err: {
+        "a == b",
+        "foo == true && bar",
+        "x != undefined"
+    },
+    ok: {
+        "typeof foo == 'undefined'",
+        "'hello' != 'world'",
+        "0 == 0",
+        "true == true",
+        "foo == null",
+        "null != foo"
+    }
+}
+
+rule_tests! {
+    null_ignore_valid,
+    null_ignore_invalid,
+    Eqeqeq {
+        style: "always".to_string(),
+        null: "ignore".to_string(),
+    },
+    err: {
+        "a == b",
+        "typeof foo == 'undefined'"
+    },
+    ok: {
+        "foo == null",
+        "null != foo"
+    }
+}
+
+fix_tests! {
+    Eqeqeq::default(),
+    "'hello' != 'world'" => "'hello' !== 'world'",
+    "typeof foo == 'undefined'" => "typeof foo === 'undefined'",
+    "0 == 0 && true != false" => "0 === 0 && true !== false",
+    "a == b" => "a == b",
+    "foo == null" => "foo == null",
+    "1 == '1'" => "1 == '1'"
+}
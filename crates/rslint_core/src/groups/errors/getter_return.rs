@@ -88,26 +88,9 @@ impl CstRule for GetterReturn {
                                 if literal_prop.key()?.text() != "get" {
                                     continue;
                                 }
-                                match literal_prop.value()? {
-                                    Expr::FnExpr(decl) => {
-                                        self.check_stmts(
-                                            args[1].syntax(),
-                                            decl.body()?.syntax(),
-                                            decl.body()?.stmts(),
-                                            ctx,
-                                        );
-                                    }
-                                    Expr::ArrowExpr(arrow) => {
-                                        if let ExprOrBlock::Block(block) = arrow.body()? {
-                                            self.check_stmts(
-                                                args[1].syntax(),
-                                                block.syntax(),
-                                                block.stmts(),
-                                                ctx,
-                                            );
-                                        }
-                                    }
-                                    _ => {}
+                                let value = literal_prop.value()?;
+                                if matches!(value, Expr::FnExpr(_) | Expr::ArrowExpr(_)) {
+                                    self.check_fn(args[1].syntax(), value.syntax(), ctx);
                                 }
                             }
                         }
@@ -116,10 +99,8 @@ impl CstRule for GetterReturn {
             }
             GETTER => {
                 let getter = node.to::<Getter>();
-                if let Some(body) = getter.body() {
-                    if let Some(key) = getter.key() {
-                        self.check_stmts(key.syntax(), body.syntax(), body.stmts(), ctx);
-                    }
+                if let Some(key) = getter.key() {
+                    self.check_fn(key.syntax(), node, ctx);
                 }
             }
             _ => {}
@@ -129,14 +110,10 @@ impl CstRule for GetterReturn {
 }
 
 impl GetterReturn {
-    fn check_stmts(
-        &self,
-        key: &SyntaxNode,
-        body: &SyntaxNode,
-        mut stmts: impl Iterator<Item = Stmt>,
-        ctx: &mut RuleCtx,
-    ) {
-        if !stmts.any(|stmt| self.check_stmt(&stmt)) {
+    fn check_fn(&self, key: &SyntaxNode, func: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let body = func.child_with_kind(BLOCK_STMT)?;
+        let cfg = ctx.cfg(func)?;
+        if !cfg.always_returns_value(self.allow_implicit) {
             let err = ctx
                 .err(
                     self.name(),
@@ -153,34 +130,7 @@ impl GetterReturn {
 
             ctx.add_err(err);
         }
-    }
-
-    fn check_stmt(&self, stmt: &Stmt) -> bool {
-        match stmt {
-            Stmt::IfStmt(if_stmt) => self.check_if(if_stmt),
-            Stmt::BlockStmt(block) => block.stmts().any(|stmt| self.check_stmt(&stmt)),
-            Stmt::ReturnStmt(stmt) => stmt.value().is_some() || self.allow_implicit,
-            Stmt::SwitchStmt(switch) => switch.cases().any(|case| match case {
-                SwitchCase::CaseClause(clause) => clause.cons().any(|s| self.check_stmt(&s)),
-                SwitchCase::DefaultClause(clause) => clause.cons().any(|s| self.check_stmt(&s)),
-            }),
-            _ => false,
-        }
-    }
-
-    /// Check if an if statement unconditionally returns from the statement.
-    fn check_if(&self, stmt: &IfStmt) -> bool {
-        if stmt.alt().is_none() {
-            return false;
-        }
-
-        if let Some(cons) = stmt.cons() {
-            if !self.check_stmt(&cons) {
-                return false;
-            }
-            return self.check_stmt(&stmt.alt().unwrap());
-        }
-        false
+        None
     }
 }
 
@@ -250,6 +200,16 @@ rule_tests! {
                 }
             }
         }
+        ",
+        "
+        let bar = {
+            get foo() {
+                if (bar) {
+                    return foo;
+                }
+                throw new Error(\"no foo\");
+            }
+        }
         "
     }
 }
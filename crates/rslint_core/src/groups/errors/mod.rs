@@ -32,4 +32,25 @@ group! {
     valid_typeof::ValidTypeof,
     no_extra_boolean_cast::NoExtraBooleanCast,
     no_confusing_arrow::NoConfusingArrow,
+    no_unreachable::NoUnreachable,
+    no_unused_vars::NoUnusedVars,
+    no_undef::NoUndef,
+    no_shadow::NoShadow,
+    no_shadow_restricted_names::NoShadowRestrictedNames,
+    no_const_assign::NoConstAssign,
+    no_func_assign::NoFuncAssign,
+    no_class_assign::NoClassAssign,
+    no_import_assign::NoImportAssign,
+    no_fallthrough::NoFallthrough,
+    no_loss_of_precision::NoLossOfPrecision,
+    eqeqeq::Eqeqeq,
+    no_unsafe_optional_chaining::NoUnsafeOptionalChaining,
+    no_useless_optional_chaining::NoUselessOptionalChaining,
+    array_callback_return::ArrayCallbackReturn,
+    no_template_curly_in_string::NoTemplateCurlyInString,
+    no_dupe_class_members::NoDupeClassMembers,
+    no_dupe_else_if::NoDupeElseIf,
+    no_self_assign::NoSelfAssign,
+    no_self_compare::NoSelfCompare,
+    no_restricted_syntax::NoRestrictedSyntax,
 }
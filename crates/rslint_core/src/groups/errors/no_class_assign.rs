@@ -0,0 +1,150 @@
+use crate::rule_prelude::*;
+use crate::semantic::BindingKind;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow reassigning class declarations.
+
+    A class declaration creates a binding which can be modified like a `let` variable, however, overwriting
+    it is almost always a mistake. The name of a class is also constant inside of the class itself,
+    assigning to it there throws a `TypeError` at runtime, the same goes for the name of a class expression.
+
+    ## Incorrect Code Examples
+
+    ```js
+    class A {}
+    A = 0;
+    ```
+
+    ```js
+    class A {
+        foo() {
+            A = 0;
+        }
+    }
+    ```
+
+    ```js
+    let Foo = class A {
+        foo() {
+            A = 0;
+        }
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let A = class {}
+    A = 0;
+    ```
+
+    ```js
+    class A {
+        foo(A) {
+            A = 0;
+        }
+    }
+    ```
+    */
+    #[derive(Default)]
+    NoClassAssign,
+    errors,
+    "no-class-assign"
+}
+
+#[typetag::serde]
+impl CstRule for NoClassAssign {
+    fn check_root(&self, _root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let model = ctx.semantic.clone();
+        for binding in model.bindings() {
+            if binding.kind != BindingKind::Class {
+                continue;
+            }
+            for write in model.writes(binding.id).filter(|r| !r.init) {
+                let inside_class = binding.decl.kind() == CLASS_EXPR
+                    || binding
+                        .decl
+                        .text_range()
+                        .contains_range(write.node.text_range());
+                let note = if inside_class {
+                    "the name of a class is constant inside of the class, assigning to it throws a `TypeError` at runtime"
+                } else {
+                    "this overwrites the class, any later uses of it will use the new value instead"
+                };
+
+                let err = ctx
+                    .err(
+                        self.name(),
+                        format!("`{}` is a class and should not be reassigned", binding.name),
+                    )
+                    .secondary(
+                        binding.range(),
+                        format!("`{}` is declared as a class here", binding.name),
+                    )
+                    .primary(write.node.text_range(), "but it is reassigned here")
+                    .footer_note(note);
+                ctx.add_err(err);
+            }
+        }
+        None
+    }
+}
+
+rule_tests! {
+    NoClassAssign::default(),
+    err: {
+        "
+        class A {}
+        A = 0;
+        ",
+        "
+        A = 0;
+        class A {}
+        ",
+        "
+        class A {
+            foo() {
+                A = 0;
+            }
+        }
+        ",
+        "
+        let Foo = class A {
+            foo() {
+                A++;
+            }
+        }
+        ",
+        "
+        class A {}
+        ({ A } = foo);
+        ",
+        "
+        class A {}
+        for (A in foo) {}
+        "
+    },
+    ok: {
+        "
+        let A = class {}
+        A = 0;
+        ",
+        "
+        class A {
+            foo(A) {
+                A = 0;
+            }
+        }
+        ",
+        "
+        class A {}
+        A.foo = 0;
+        ",
+        "
+        let A = class A {}
+        A = 0;
+        "
+    }
+}
@@ -0,0 +1,139 @@
+use crate::rule_prelude::*;
+use crate::semantic::BindingKind;
+
+declare_lint! {
+    /**
+    Disallow reassigning `const` variables.
+
+    Variables declared with `const` cannot be modified, assigning to them throws a `TypeError`
+    at runtime. This includes assignments, update expressions such as `a++`, destructuring
+    assignments, and `for-in`/`for-of` loop heads.
+
+    ## Incorrect Code Examples
+
+    ```js
+    const a = 0;
+    a = 1;
+    ```
+
+    ```js
+    const a = 0;
+    a += 1;
+    ```
+
+    ```js
+    const a = 0;
+    ({ a } = foo);
+    ```
+
+    ```js
+    const a = 0;
+    for (a of [1, 2, 3]) {}
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let a = 0;
+    a = 1;
+    ```
+
+    ```js
+    // a new binding is declared for every iteration
+    for (const a of [1, 2, 3]) {}
+    ```
+    */
+    #[derive(Default)]
+    NoConstAssign,
+    errors,
+    "no-const-assign"
+}
+
+#[typetag::serde]
+impl CstRule for NoConstAssign {
+    fn check_root(&self, _root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let model = ctx.semantic.clone();
+        for binding in model.bindings() {
+            if binding.kind != BindingKind::Const {
+                continue;
+            }
+            for write in model.writes(binding.id).filter(|r| !r.init) {
+                let err = ctx
+                    .err(
+                        self.name(),
+                        format!("`{}` is a constant and cannot be reassigned", binding.name),
+                    )
+                    .secondary(
+                        binding.range(),
+                        format!("`{}` is declared as a constant here", binding.name),
+                    )
+                    .primary(write.node.text_range(), "but it is reassigned here")
+                    .footer_note("assigning to a constant throws a `TypeError` at runtime");
+                ctx.add_err(err);
+            }
+        }
+        None
+    }
+}
+
+rule_tests! {
+    NoConstAssign::default(),
+    err: {
+        "
+        const a = 0;
+        a = 1;
+        ",
+        "
+        const a = 0;
+        a += 1;
+        ",
+        "
+        const a = 0;
+        ++a;
+        ",
+        "
+        const a = 0;
+        [a] = [1];
+        ",
+        "
+        const a = 0;
+        ({ a } = foo);
+        ",
+        "
+        const a = 0;
+        for (a in foo) {}
+        ",
+        "
+        const a = 0;
+        for (a of foo) {}
+        ",
+        "
+        const a = 0;
+        function foo() {
+            a = 1;
+        }
+        "
+    },
+    ok: {
+        "
+        const a = 0;
+        a;
+        ",
+        "
+        let a = 0;
+        a = 1;
+        ",
+        "for (const a of foo) {}",
+        "for (const a in foo) {}",
+        "
+        const a = {};
+        a.b = 1;
+        ",
+        "
+        const a = 0;
+        function foo(a) {
+            a = 1;
+        }
+        "
+    }
+}
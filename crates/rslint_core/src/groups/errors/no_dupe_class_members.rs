@@ -0,0 +1,219 @@
+use crate::rule_prelude::*;
+use rslint_parser::{NodeOrToken, SyntaxElement, TextRange};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow duplicate class members.
+
+    Class members with the same name silently overwrite each other, only the last declaration is used.
+    This is almost always a mistake such as a copy paste error or a forgotten rename.
+
+    A getter and a setter with the same name are allowed, as well as TypeScript overload signatures.
+    Static and non static members with the same name do not conflict.
+
+    ## Incorrect Code Examples
+
+    ```js
+    class Foo {
+        bar() {}
+        bar() {}
+    }
+    ```
+
+    ```js
+    class Foo {
+        bar = 5;
+        get bar() {}
+    }
+    ```
+
+    ```js
+    class Foo {
+        static bar() {}
+        static "bar"() {}
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    class Foo {
+        get bar() {}
+        set bar(value) {}
+    }
+    ```
+
+    ```js
+    class Foo {
+        bar() {}
+        static bar() {}
+    }
+    ```
+    */
+    #[derive(Default)]
+    NoDupeClassMembers,
+    errors,
+    "no-dupe-class-members"
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MemberKind {
+    /// A method or field.
+    Value,
+    Getter,
+    Setter,
+}
+
+impl MemberKind {
+    fn conflicts_with(self, other: MemberKind) -> bool {
+        self == MemberKind::Value || other == MemberKind::Value || self == other
+    }
+}
+
+struct Member {
+    name: String,
+    is_static: bool,
+    is_private: bool,
+    kind: MemberKind,
+    /// The range and text of the key.
+    range: TextRange,
+    text: String,
+}
+
+#[typetag::serde]
+impl CstRule for NoDupeClassMembers {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if node.kind() != CLASS_BODY {
+            return None;
+        }
+
+        let mut declared: Vec<Member> = vec![];
+        for member in node.children().filter_map(|child| member(&child)) {
+            if let Some(first) = declared.iter().find(|prev| {
+                prev.name == member.name
+                    && prev.is_static == member.is_static
+                    && prev.is_private == member.is_private
+                    && prev.kind.conflicts_with(member.kind)
+            }) {
+                let err = ctx
+                    .err(
+                        self.name(),
+                        format!("duplicate class member `{}`", member.name),
+                    )
+                    .secondary(
+                        first.range,
+                        format!("`{}` is first declared here", first.text),
+                    )
+                    .primary(
+                        member.range,
+                        format!("`{}` is then redeclared here", member.text),
+                    );
+                ctx.add_err(err);
+            }
+            declared.push(member);
+        }
+        None
+    }
+}
+
+/// Get the name of a class member, `None` for members without a static name, constructors, and overload signatures.
+fn member(node: &SyntaxNode) -> Option<Member> {
+    let kind = match node.kind() {
+        GETTER => MemberKind::Getter,
+        SETTER => MemberKind::Setter,
+        // overload signatures do not have a body
+        METHOD if node.child_with_kind(BLOCK_STMT).is_none() => return None,
+        // fields without a value are not wrapped in a node, e.g. `class A { foo; }`
+        METHOD | CLASS_PROP | PRIVATE_PROP | NAME => MemberKind::Value,
+        _ => return None,
+    };
+
+    let key = if node.kind() == NAME {
+        NodeOrToken::Node(node.clone())
+    } else {
+        member_key(node)?
+    };
+    let (range, text) = match &key {
+        NodeOrToken::Node(node) => (node.trimmed_range(), node.trimmed_text().to_string()),
+        NodeOrToken::Token(token) => (token.text_range(), token.text().to_string()),
+    };
+    Some(Member {
+        name: match &key {
+            NodeOrToken::Node(node) => util::static_prop_name(node)?,
+            NodeOrToken::Token(token) => token.text().to_string(),
+        },
+        is_static: node.token_with_kind(STATIC_KW).is_some(),
+        is_private: node.kind() == PRIVATE_PROP,
+        kind,
+        range,
+        text,
+    })
+}
+
+/// The key of a member, which is the last name before the parameters for methods and accessors,
+/// since `get` and `set` are also parsed as names.
+fn member_key(node: &SyntaxNode) -> Option<SyntaxElement> {
+    let is_key = |elem: &SyntaxElement| match elem {
+        NodeOrToken::Node(node) => matches!(
+            node.kind(),
+            NAME | LITERAL | COMPUTED_PROPERTY_NAME | PRIVATE_NAME
+        ),
+        // a method named with a keyword such as `static() {}`
+        NodeOrToken::Token(token) => token.kind() == IDENT,
+    };
+
+    match node.kind() {
+        CLASS_PROP | PRIVATE_PROP => node.children_with_tokens().find(is_key),
+        _ => node
+            .children_with_tokens()
+            .take_while(|elem| elem.kind() != PARAMETER_LIST)
+            .filter(is_key)
+            .last(),
+    }
+}
+
+rule_tests! {
+    NoDupeClassMembers::default(),
+    err: {
+        "class A { foo() {} foo() {} }",
+        "!class A { foo() {} foo() {} };",
+        "class A { 'foo'() {} foo() {} }",
+        "class A { 10() {} 1e1() {} }",
+        "class A { ['foo']() {} foo() {} }",
+        "class A { static foo() {} static foo() {} }",
+        "class A { foo() {} get foo() {} }",
+        "class A { set foo(value) {} foo() {} }",
+        "class A { get foo() {} get foo() {} }",
+        "class A { foo = 1; foo() {} }",
+        "class A { foo; foo = 2; }",
+        "class A { #foo = 1; #foo = 2; }",
+        "class A { foo() {} foo() {} foo() {} }"
+    },
+    ok: {
+        "class A { foo() {} bar() {} }",
+        "class A { get foo() {} set foo(value) {} }",
+        "class A { static foo() {} foo() {} }",
+        "class A { static get foo() {} get foo() {} }",
+        "class A { foo() {} } class B { foo() {} }",
+        "class A { [foo]() {} [foo]() {} }",
+        "class A { foo() { class B { foo() {} } } }",
+        "class A { #foo = 1; foo = 2; }",
+        "class A { #foo = 1; '#foo'() {} }",
+        "class A { get() {} set() {} }"
+    }
+}
+
+rule_tests! {
+    typescript,
+    typescript_valid,
+    typescript_invalid,
+    NoDupeClassMembers::default(),
+    err: {
+        "class A { foo(a: string): void {} foo(a: number): void {} }"
+    },
+    ok: {
+        "class A { foo(a: string): void; foo(a: number): void; foo(a) {} }",
+        "class A { static foo(): void; static foo(a?: string) {} }"
+    }
+}
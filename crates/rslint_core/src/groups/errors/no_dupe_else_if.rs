@@ -0,0 +1,215 @@
+use crate::rule_prelude::*;
+use ast::{BinOp, Expr, IfStmt};
+
+declare_lint! {
+    /**
+    Disallow duplicate conditions in `if`-`else if` chains.
+
+    Only the first branch whose condition is truthy is executed in a chain of `if` and `else if` statements.
+    A branch whose condition is the same as a previous condition can therefore never execute, this is
+    usually a copy paste mistake.
+
+    Conditions which are covered by previous conditions are also reported, for example `a` is truthy whenever
+    `a && b` is, so `else if (a && b)` after `if (a)` can never execute. Likewise `else if (a || b)` after
+    `if (a)` and `else if (b)` can never execute.
+
+    ## Incorrect Code Examples
+
+    ```js
+    if (isSomething(x)) {
+        foo();
+    } else if (isSomething(x)) {
+        bar();
+    }
+    ```
+
+    ```js
+    if (a) {
+        foo();
+    } else if (b) {
+        bar();
+    } else if (a || b) {
+        baz();
+    }
+    ```
+
+    ```js
+    if (a) {
+        foo();
+    } else if (a && b) {
+        bar();
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    if (isSomething(x)) {
+        foo();
+    } else if (isSomethingElse(x)) {
+        bar();
+    }
+    ```
+
+    ```js
+    if (a && b) {
+        foo();
+    } else if (a) {
+        bar();
+    }
+    ```
+    */
+    #[derive(Default)]
+    NoDupeElseIf,
+    errors,
+    "no-dupe-else-if"
+}
+
+#[typetag::serde]
+impl CstRule for NoDupeElseIf {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let stmt = node.try_to::<IfStmt>()?;
+        let test = stmt.condition()?.condition()?;
+
+        // `a && b` is covered by a previous `a && b`, but also by a previous `a` or `b`
+        let mut conditions = vec![test.clone()];
+        if is_logical(&test, BinOp::LogicalAnd) {
+            conditions.extend(split(&test, BinOp::LogicalAnd));
+        }
+        // every condition is a list of `||` operands, each of which is a list of `&&` operands
+        let mut remaining = conditions
+            .iter()
+            .map(|condition| {
+                split(condition, BinOp::LogicalOr)
+                    .iter()
+                    .map(|operand| split(operand, BinOp::LogicalAnd))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut covering = vec![];
+        let mut current = stmt;
+        while let Some(parent) = current.syntax().parent().and_then(|p| p.try_to::<IfStmt>()) {
+            if parent
+                .alt()
+                .map_or(true, |alt| alt.syntax() != current.syntax())
+            {
+                break;
+            }
+            current = parent;
+            let prev_test = current.condition()?.condition()?;
+            let prev_operands = split(&prev_test, BinOp::LogicalOr)
+                .iter()
+                .map(|operand| split(operand, BinOp::LogicalAnd))
+                .collect::<Vec<_>>();
+
+            // an `||` operand is covered if a previous `||` operand implies it, which is when all of the previous
+            // operand's `&&` operands are in the current operand
+            let mut covers = false;
+            for or_operands in remaining.iter_mut() {
+                let len = or_operands.len();
+                or_operands.retain(|and_operands| {
+                    !prev_operands
+                        .iter()
+                        .any(|prev_and_operands| is_subset(prev_and_operands, and_operands))
+                });
+                covers |= or_operands.len() != len;
+            }
+            if covers {
+                covering.push(prev_test);
+            }
+
+            if remaining.iter().any(|or_operands| or_operands.is_empty()) {
+                let mut err = ctx
+                    .err(
+                        self.name(),
+                        "this branch can never execute, its condition is a duplicate or covered by previous conditions",
+                    )
+                    .primary(
+                        test.syntax(),
+                        "this condition is always false when it is reached",
+                    );
+                // label the conditions in the order they appear in
+                for prev in covering.iter().rev() {
+                    err = err.secondary(prev.syntax(), "this condition is checked first");
+                }
+                ctx.add_err(err);
+                break;
+            }
+        }
+        None
+    }
+}
+
+fn unwrap_grouping(expr: &Expr) -> Expr {
+    match expr {
+        Expr::GroupingExpr(group) => group
+            .inner()
+            .map_or_else(|| expr.clone(), |inner| unwrap_grouping(&inner)),
+        _ => expr.clone(),
+    }
+}
+
+fn is_logical(expr: &Expr, op: BinOp) -> bool {
+    matches!(unwrap_grouping(expr), Expr::BinExpr(bin) if bin.op() == Some(op))
+}
+
+/// Flatten nested `&&` or `||` expressions into their operands, e.g. `a || (b || c)` is `[a, b, c]`.
+fn split(expr: &Expr, op: BinOp) -> Vec<Expr> {
+    match unwrap_grouping(expr) {
+        Expr::BinExpr(bin) if bin.op() == Some(op) => bin
+            .lhs()
+            .into_iter()
+            .chain(bin.rhs())
+            .flat_map(|operand| split(&operand, op))
+            .collect(),
+        expr => vec![expr],
+    }
+}
+
+fn is_subset(left: &[Expr], right: &[Expr]) -> bool {
+    left.iter().all(|l| right.iter().any(|r| is_equal(l, r)))
+}
+
+/// Whether two expressions are the same, `a && b` is equal to `b && a` and `a || b` is equal to `b || a`.
+fn is_equal(left: &Expr, right: &Expr) -> bool {
+    let (left, right) = (unwrap_grouping(left), unwrap_grouping(right));
+    if let (Expr::BinExpr(l), Expr::BinExpr(r)) = (&left, &right) {
+        if l.op() == r.op() && matches!(l.op(), Some(BinOp::LogicalAnd) | Some(BinOp::LogicalOr)) {
+            if let (Some(ll), Some(lr), Some(rl), Some(rr)) = (l.lhs(), l.rhs(), r.lhs(), r.rhs()) {
+                return (is_equal(&ll, &rl) && is_equal(&lr, &rr))
+                    || (is_equal(&ll, &rr) && is_equal(&lr, &rl));
+            }
+        }
+    }
+    left.syntax().lexical_eq(right.syntax())
+}
+
+rule_tests! {
+    NoDupeElseIf::default(),
+    err: {
+        "if (a) {} else if (a) {}",
+        "if (a) {} else if (b) {} else if (a) {}",
+        "if (a) {} else if (b) {} else if (c) {} else if (a) {}",
+        "if (foo.bar()) {} else if (foo . bar()) {}",
+        "if (a) {} else if ((a)) {}",
+        "if (a) {} else if (a && b) {}",
+        "if (a) {} else if (b) {} else if (a || b) {}",
+        "if (a || b) {} else if (a) {}",
+        "if (a && b) {} else if (b && a) {}",
+        "if (a || b) {} else if (c) {} else if (b || a) {}",
+        "if (a) {} else if (b && c) {} else if (c && b && a) {}"
+    },
+    ok: {
+        "if (a) {} else if (b) {}",
+        "if (a) {} else {}",
+        "if (a && b) {} else if (a) {}",
+        "if (a) {} else if (a || b) {}",
+        "if (a) { if (a) {} }",
+        "if (a) {} if (a) {}",
+        "if (a) {} else if (b) {} else if (c) {}",
+        "if (a === 1) {} else if (a === 2) {}",
+        "if (a) {} else { foo(); if (a) {} }",
+        "if (a) {} else { if (b) {} else if (a) {} }"
+    }
+}
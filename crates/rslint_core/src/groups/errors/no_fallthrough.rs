@@ -0,0 +1,366 @@
+use crate::cfg;
+use crate::rule_prelude::*;
+use regex::{Regex, RegexBuilder};
+use rslint_parser::TextRange;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow the body of a `case` from falling through into the next `case`.
+
+    When a `case` of a `switch` statement is matched, every statement after it is executed until a
+    `break`, `return`, or `throw`, even the statements of the `case` clauses after it. Forgetting to
+    end a `case` is a common mistake which makes code run when it is not expected to.
+
+    Intentional fallthrough can be marked with a comment matching the `commentPattern` option
+    (`falls? ?through` by default, ignoring case) right before the next `case`. Empty clauses
+    such as `case 1: case 2:` are always allowed.
+
+    ## Incorrect Code Examples
+
+    ```js
+    switch (foo) {
+        case 1:
+            doSomething();
+        case 2:
+            doSomethingElse();
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    switch (foo) {
+        case 1:
+            doSomething();
+            break;
+        case 2:
+            doSomethingElse();
+    }
+    ```
+
+    ```js
+    switch (foo) {
+        case 1:
+            doSomething();
+            // falls through
+        case 2:
+            doSomethingElse();
+    }
+    ```
+
+    ```js
+    switch (foo) {
+        case 1:
+        case 2:
+            doSomething();
+    }
+    ```
+    */
+    #[serde(default)]
+    NoFallthrough,
+    errors,
+    "no-fallthrough",
+    /// A regular expression which comments have to match to mark a fallthrough as intentional,
+    /// the pattern is matched ignoring case (`falls? ?through` by default).
+    #[serde(deserialize_with = "deserialize_pattern", serialize_with = "serialize_pattern")]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "pattern_schema"))]
+    pub comment_pattern: Regex
+}
+
+impl Default for NoFallthrough {
+    fn default() -> Self {
+        Self {
+            comment_pattern: comment_pattern("falls? ?through").unwrap(),
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for NoFallthrough {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if node.kind() != SWITCH_STMT {
+            return None;
+        }
+        let cfg = ctx.cfg(&cfg::owner(node)?)?;
+        let clauses = node
+            .children()
+            .filter(|child| matches!(child.kind(), CASE_CLAUSE | DEFAULT_CLAUSE))
+            .collect::<Vec<_>>();
+
+        for pair in clauses.windows(2) {
+            let (clause, next) = (&pair[0], &pair[1]);
+            let last_stmt = match clause_stmts(clause).last() {
+                Some(stmt) => stmt,
+                None => continue,
+            };
+            if !cfg.completes_normally(clause)
+                || has_fallthrough_comment(next, &self.comment_pattern)
+            {
+                continue;
+            }
+
+            let (next_range, next_text) = clause_header(next);
+            let stmt_text = format!("{}{}", last_stmt.text(), separator(&last_stmt));
+            let break_range = stmt_text.len()..stmt_text.len() + "break;".len();
+            let err = ctx
+                .err(
+                    self.name(),
+                    format!("the previous case falls through into `{}`", next_text),
+                )
+                .secondary(
+                    clause_header(clause).0,
+                    "this case does not end with a `break`, `return`, or `throw`...",
+                )
+                .primary(
+                    next_range,
+                    "...so this case is executed after it as well",
+                )
+                .suggestion_with_labels(
+                    last_stmt.text_range(),
+                    "if this is a mistake, end the case with a `break`",
+                    format!("{}break;", stmt_text),
+                    Applicability::MaybeIncorrect,
+                    vec![break_range],
+                )
+                .footer_help(
+                    "if the fallthrough is intentional, add a `// falls through` comment before the next case",
+                );
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+/// Compile a comment pattern, which is matched ignoring case.
+fn comment_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern).case_insensitive(true).build()
+}
+
+/// Deserialize and compile the comment pattern, invalid regexes are reported as errors in the config.
+fn deserialize_pattern<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Regex, D::Error> {
+    let pattern = String::deserialize(deserializer)?;
+    comment_pattern(&pattern)
+        .map_err(|err| serde::de::Error::custom(format!("invalid regex `{}`: {}", pattern, err)))
+}
+
+fn serialize_pattern<S: serde::Serializer>(
+    pattern: &Regex,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(pattern.as_str())
+}
+
+#[cfg(feature = "schema")]
+fn pattern_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    <String as schemars::JsonSchema>::json_schema(gen)
+}
+
+/// The statements of a `case` or `default` clause, the test of a `case` comes before the colon.
+fn clause_stmts(clause: &SyntaxNode) -> impl Iterator<Item = SyntaxNode> {
+    let colon = clause
+        .token_with_kind(T![:])
+        .map_or(clause.text_range().start(), |t| t.text_range().end());
+    clause
+        .children()
+        .filter(move |child| child.text_range().start() >= colon)
+}
+
+/// The range and text of `case foo:` or `default:`.
+fn clause_header(clause: &SyntaxNode) -> (TextRange, String) {
+    let start = clause.text_range().start();
+    let end = clause
+        .token_with_kind(T![:])
+        .map_or(clause.text_range().end(), |t| t.text_range().end());
+    let range = TextRange::new(start, end);
+    let text = clause
+        .text()
+        .slice(TextRange::new(0.into(), range.len()))
+        .to_string();
+    (range, text)
+}
+
+fn has_fallthrough_comment(next: &SyntaxNode, pattern: &Regex) -> bool {
+    let mut token = next.first_token().and_then(|t| t.prev_token());
+    while let Some(tok) = token.filter(|t| t.kind().is_trivia()) {
+        if tok.kind() == COMMENT && pattern.is_match(tok.text()) {
+            return true;
+        }
+        token = tok.prev_token();
+    }
+    false
+}
+
+/// The text to put between the last statement and the inserted `break`, a new line with the same
+/// indentation as the statement, or a single space if the statement does not start on its own line.
+fn separator(stmt: &SyntaxNode) -> String {
+    let whitespace = stmt
+        .first_token()
+        .and_then(|t| t.prev_token())
+        .filter(|t| t.kind() == WHITESPACE);
+    match whitespace {
+        Some(ws) if ws.text().contains('\n') => {
+            format!("\n{}", ws.text().rsplit('\n').next().unwrap_or_default())
+        }
+        _ => " ".to_string(),
+    }
+}
+
+rule_tests! {
+    NoFallthrough::default(),
+    err: {
+        "
+        switch (foo) {
+            case 1:
+                doSomething();
+            case 2:
+                doSomethingElse();
+        }
+        ",
+        "
+        switch (foo) {
+            case 1:
+                doSomething();
+            default:
+                doSomethingElse();
+        }
+        ",
+        "
+        switch (foo) {
+            default:
+                doSomething();
+            case 1:
+                doSomethingElse();
+        }
+        ",
+        "
+        switch (foo) {
+            case 1:
+                if (bar) {
+                    break;
+                }
+            case 2:
+        }
+        ",
+        "
+        switch (foo) {
+            case 1:
+                // just a comment
+            case 2:
+                bar();
+                // not an intentional fall
+            case 3:
+        }
+        ",
+        "
+        function foo() {
+            switch (foo) {
+                case 1:
+                    while (bar) {
+                        break;
+                    }
+                case 2:
+            }
+        }
+        "
+    },
+    ok: {
+        "
+        switch (foo) {
+            case 1:
+                doSomething();
+                break;
+            case 2:
+                doSomethingElse();
+        }
+        ",
+        "
+        switch (foo) {
+            case 1:
+            case 2:
+                doSomething();
+        }
+        ",
+        "
+        function foo() {
+            switch (foo) {
+                case 1:
+                    return 5;
+                case 2:
+                    throw new Error();
+                default:
+                    if (bar) {
+                        break;
+                    } else {
+                        return;
+                    }
+                case 3:
+            }
+        }
+        ",
+        "
+        switch (foo) {
+            case 1:
+                doSomething();
+                // falls through
+            case 2:
+                doSomething();
+                /* fall through */
+            case 3:
+                doSomething();
+                // FALLTHROUGH
+            default:
+        }
+        ",
+        "
+        loop: while (bar) {
+            switch (foo) {
+                case 1:
+                    continue loop;
+                case 2:
+                    break loop;
+                case 3:
+            }
+        }
+        ",
+        "
+        switch (foo) {
+            case 1: {
+                doSomething();
+                break;
+            }
+            case 2:
+        }
+        "
+    }
+}
+
+rule_tests! {
+    comment_pattern_valid,
+    comment_pattern_invalid,
+    NoFallthrough {
+        comment_pattern: comment_pattern("break omitted").unwrap(),
+    },
+    err: {
+        "
+        switch (foo) {
+            case 1:
+                doSomething();
+                // falls through
+            case 2:
+        }
+        "
+    },
+    ok: {
+        "
+        switch (foo) {
+            case 1:
+                doSomething();
+                // break omitted
+            case 2:
+        }
+        "
+    }
+}
@@ -0,0 +1,147 @@
+use crate::rule_prelude::*;
+use crate::semantic::BindingKind;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow reassigning function declarations.
+
+    Overwriting a function declaration is almost always a mistake, later calls to the function will
+    call the new value instead, which is confusing and hard to track down.
+    The name of a function expression is read-only inside of the function, assigning to it fails silently
+    in sloppy mode and throws a `TypeError` in strict mode.
+
+    ## Incorrect Code Examples
+
+    ```js
+    function foo() {}
+    foo = bar;
+    ```
+
+    ```js
+    function foo() {
+        foo = bar;
+    }
+    ```
+
+    ```js
+    let a = function foo() {
+        foo = bar;
+    }
+    ```
+
+    ```js
+    function foo() {}
+    [foo] = bar;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let foo = function () {}
+    foo = bar;
+    ```
+
+    ```js
+    function foo(foo) {
+        foo = bar;
+    }
+    ```
+    */
+    #[derive(Default)]
+    NoFuncAssign,
+    errors,
+    "no-func-assign"
+}
+
+#[typetag::serde]
+impl CstRule for NoFuncAssign {
+    fn check_root(&self, _root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let model = ctx.semantic.clone();
+        for binding in model.bindings() {
+            if binding.kind != BindingKind::Function {
+                continue;
+            }
+            let note = if binding.decl.kind() == FN_EXPR {
+                "the name of a function expression is read-only, assigning to it fails silently in sloppy mode and throws a `TypeError` in strict mode"
+            } else {
+                "this overwrites the function, any later calls to it will use the new value instead"
+            };
+
+            for write in model.writes(binding.id).filter(|r| !r.init) {
+                let err = ctx
+                    .err(
+                        self.name(),
+                        format!(
+                            "`{}` is a function and should not be reassigned",
+                            binding.name
+                        ),
+                    )
+                    .secondary(
+                        binding.range(),
+                        format!("`{}` is declared as a function here", binding.name),
+                    )
+                    .primary(write.node.text_range(), "but it is reassigned here")
+                    .footer_note(note);
+                ctx.add_err(err);
+            }
+        }
+        None
+    }
+}
+
+rule_tests! {
+    NoFuncAssign::default(),
+    err: {
+        "
+        function foo() {}
+        foo = bar;
+        ",
+        "
+        function foo() {
+            foo = bar;
+        }
+        ",
+        "
+        foo = bar;
+        function foo() {}
+        ",
+        "
+        let a = function foo() {
+            foo = bar;
+        }
+        ",
+        "
+        function foo() {}
+        [foo] = bar;
+        ",
+        "
+        function foo() {}
+        ({ x: foo = 0 } = bar);
+        ",
+        "
+        function foo() {}
+        for (foo of bar) {}
+        "
+    },
+    ok: {
+        "
+        let foo = function () {}
+        foo = bar;
+        ",
+        "
+        function foo(foo) {
+            foo = bar;
+        }
+        ",
+        "
+        function foo() {
+            var foo = bar;
+        }
+        ",
+        "
+        function foo() {}
+        foo.bar = 5;
+        "
+    }
+}
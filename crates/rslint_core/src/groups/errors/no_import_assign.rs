@@ -0,0 +1,297 @@
+use crate::rule_prelude::*;
+use crate::semantic::{Binding, BindingKind};
+use ast::UnaryOp;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow assigning to imported bindings.
+
+    Imported bindings are read-only live bindings of the exported values, assigning to them throws a `TypeError`
+    at runtime. The same goes for the properties of a namespace import (`import * as ns from "foo"`),
+    which cannot be assigned, updated, or deleted either.
+
+    ## Incorrect Code Examples
+
+    ```js
+    import foo from "foo";
+    foo = 5;
+    ```
+
+    ```js
+    import { foo } from "foo";
+    foo++;
+    ```
+
+    ```js
+    import * as ns from "foo";
+    ns.bar = 5;
+    ```
+
+    ```js
+    import * as ns from "foo";
+    delete ns.bar;
+    ```
+
+    ```js
+    import * as ns from "foo";
+    Object.assign(ns, { bar: 5 });
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    import foo from "foo";
+    foo.bar = 5;
+    ```
+
+    ```js
+    import * as ns from "foo";
+    ns.bar.baz = 5;
+    ```
+    */
+    #[derive(Default)]
+    NoImportAssign,
+    errors,
+    "no-import-assign"
+}
+
+/// Calls which modify the properties of their first argument.
+const MUTATING_CALLS: [&str; 8] = [
+    "Object.assign",
+    "Object.defineProperty",
+    "Object.defineProperties",
+    "Object.setPrototypeOf",
+    "Reflect.set",
+    "Reflect.defineProperty",
+    "Reflect.deleteProperty",
+    "Reflect.setPrototypeOf",
+];
+
+#[typetag::serde]
+impl CstRule for NoImportAssign {
+    fn check_root(&self, _root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let model = ctx.semantic.clone();
+        for binding in model.bindings() {
+            if binding.kind != BindingKind::Import {
+                continue;
+            }
+            for write in model.writes(binding.id).filter(|r| !r.init) {
+                let err = self
+                    .err(binding, ctx)
+                    .primary(write.node.text_range(), "but it is reassigned here")
+                    .footer_note(
+                        "imports are read-only, assigning to them throws a `TypeError` at runtime",
+                    );
+                ctx.add_err(err);
+            }
+
+            let is_namespace = binding
+                .name_node()
+                .parent()
+                .map_or(false, |parent| parent.kind() == WILDCARD_IMPORT);
+            if !is_namespace {
+                continue;
+            }
+            for read in model.reads(binding.id) {
+                if let Some(node) = namespace_write(&read.node) {
+                    let err = self
+                        .err(binding, ctx)
+                        .primary(node.text_range(), "but one of its members is modified here")
+                        .footer_note("the members of a namespace import are read-only, modifying them throws a `TypeError` at runtime");
+                    ctx.add_err(err);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl NoImportAssign {
+    fn err(&self, binding: &Binding, ctx: &mut RuleCtx) -> Diagnostic {
+        ctx.err(
+            self.name(),
+            format!("`{}` is an import and cannot be modified", binding.name),
+        )
+        .secondary(
+            binding.range(),
+            format!("`{}` is imported here", binding.name),
+        )
+    }
+}
+
+fn skip_grouping(mut node: SyntaxNode) -> SyntaxNode {
+    while let Some(parent) = node.parent().filter(|p| p.kind() == GROUPING_EXPR) {
+        node = parent;
+    }
+    node
+}
+
+/// Get the node which modifies a member of a namespace import, if the reference to the namespace
+/// is the object of an assigned, updated, or deleted member, or the target of a mutating call like `Object.assign`.
+fn namespace_write(reference: &SyntaxNode) -> Option<SyntaxNode> {
+    let expr = skip_grouping(reference.clone());
+    let parent = expr.parent()?;
+    match parent.kind() {
+        DOT_EXPR | BRACKET_EXPR if parent.first_child().as_ref() == Some(&expr) => {
+            let member = skip_grouping(parent);
+            let target = member.parent()?;
+            let is_write = match target.kind() {
+                UNARY_EXPR => matches!(
+                    target.to::<ast::UnaryExpr>().op(),
+                    Some(UnaryOp::Increment) | Some(UnaryOp::Decrement) | Some(UnaryOp::Delete)
+                ),
+                _ => is_assign_target(&member),
+            };
+            if is_write {
+                Some(member)
+            } else {
+                None
+            }
+        }
+        ARG_LIST if parent.first_child().as_ref() == Some(&expr) => {
+            let call = parent.parent().and_then(ast::CallExpr::cast)?;
+            let callee = call.callee()?.syntax().text().to_string();
+            if MUTATING_CALLS.contains(&callee.as_str()) {
+                Some(call.syntax().clone())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether a node is assigned to, either directly or through a destructuring pattern
+/// such as `[ns.a] = arr` or `for ({ x: ns.a } of arr)`.
+fn is_assign_target(node: &SyntaxNode) -> bool {
+    let parent = match node.parent() {
+        Some(parent) => parent,
+        None => return false,
+    };
+    match parent.kind() {
+        ASSIGN_EXPR | ASSIGN_PATTERN => {
+            parent.first_child().as_ref() == Some(node)
+                && (parent.kind() == ASSIGN_EXPR || is_assign_target(&parent))
+        }
+        KEY_VALUE_PATTERN | LITERAL_PROP => {
+            parent.last_child().as_ref() == Some(node) && is_assign_target(&parent)
+        }
+        ARRAY_PATTERN | OBJECT_PATTERN | REST_PATTERN | ARRAY_EXPR | OBJECT_EXPR
+        | SPREAD_ELEMENT | SPREAD_PROP => is_assign_target(&parent),
+        FOR_STMT_INIT => parent
+            .parent()
+            .map_or(false, |stmt| matches!(stmt.kind(), FOR_IN_STMT | FOR_OF_STMT)),
+        _ => false,
+    }
+}
+
+rule_tests! {
+    NoImportAssign::default(),
+    err: {
+        "
+        import foo from 'foo';
+        foo = 5;
+        ",
+        "
+        import { foo } from 'foo';
+        foo++;
+        ",
+        "
+        import { bar as foo } from 'foo';
+        [foo] = bar;
+        ",
+        "
+        import foo from 'foo';
+        for (foo of bar) {}
+        ",
+        "
+        import * as ns from 'foo';
+        ns = 5;
+        ",
+        "
+        import * as ns from 'foo';
+        ns.bar = 5;
+        ",
+        "
+        import * as ns from 'foo';
+        ns[bar] += 5;
+        ",
+        "
+        import * as ns from 'foo';
+        (ns).bar++;
+        ",
+        "
+        import * as ns from 'foo';
+        delete ns.bar;
+        ",
+        "
+        import * as ns from 'foo';
+        for (ns.bar in baz) {}
+        ",
+        "
+        import * as ns from 'foo';
+        [ns.bar] = baz;
+        ",
+        "
+        import * as ns from 'foo';
+        ({ bar: ns.bar } = baz);
+        ",
+        "
+        import * as ns from 'foo';
+        [ns[bar] = 5] = baz;
+        ",
+        "
+        import * as ns from 'foo';
+        ({ ...ns.bar } = baz);
+        ",
+        "
+        import * as ns from 'foo';
+        for ([ns.bar] of baz) {}
+        ",
+        "
+        import * as ns from 'foo';
+        Object.assign(ns, { bar: 5 });
+        ",
+        "
+        import * as ns from 'foo';
+        Reflect.deleteProperty(ns, 'bar');
+        "
+    },
+    ok: {
+        "
+        import foo from 'foo';
+        foo.bar = 5;
+        ",
+        "
+        import { foo } from 'foo';
+        foo[bar]++;
+        ",
+        "
+        import * as ns from 'foo';
+        ns.bar.baz = 5;
+        ",
+        "
+        import * as ns from 'foo';
+        foo[ns] = 5;
+        ",
+        "
+        import * as ns from 'foo';
+        [bar = ns.bar] = baz;
+        ",
+        "
+        import * as ns from 'foo';
+        ({ [ns.bar]: bar } = baz);
+        ",
+        "
+        import * as ns from 'foo';
+        Object.assign({}, ns);
+        ",
+        "
+        import foo from 'foo';
+        function bar(foo) {
+            foo = 5;
+        }
+        "
+    }
+}
@@ -0,0 +1,249 @@
+use crate::rule_prelude::*;
+use rslint_parser::{format_js_num, parse_js_num, JsNum};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow number literals which lose precision at runtime.
+
+    Numbers in JavaScript are 64 bit floating point values, they can only exactly represent integers up to
+    `2^53` and have about 17 significant digits of precision. Number literals which cannot be represented exactly
+    are silently rounded to the closest value which can, which means the value used at runtime is not the one
+    which was written. BigInt literals such as `9007199254740993n` can be used for large integers instead.
+
+    ## Incorrect Code Examples
+
+    ```js
+    const a = 9007199254740993;
+    ```
+
+    ```js
+    const a = 5123000000000000000000000000001;
+    ```
+
+    ```js
+    const a = 1230000000000000000000000.0;
+    ```
+
+    ```js
+    const a = 0x20000000000001;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const a = 9007199254740991;
+    ```
+
+    ```js
+    const a = 9007199254740993n;
+    ```
+
+    ```js
+    const a = 123e34;
+    ```
+
+    ```js
+    const a = 0x1FFFFFFFFFFFFF;
+    ```
+    */
+    #[derive(Default)]
+    NoLossOfPrecision,
+    errors,
+    "no-loss-of-precision"
+}
+
+#[typetag::serde]
+impl CstRule for NoLossOfPrecision {
+    fn check_token(&self, token: &SyntaxToken, ctx: &mut RuleCtx) -> Option<()> {
+        if token.kind() != NUMBER {
+            return None;
+        }
+        let raw = token
+            .text()
+            .chars()
+            .filter(|c| *c != '_')
+            .collect::<String>();
+        let value = match parse_js_num(raw.clone())? {
+            JsNum::Float(value) => value,
+            JsNum::BigInt(_) => return None,
+        };
+        let radix = radix(&raw);
+        let loses_precision = if radix == 10 {
+            decimal_loses_precision(&raw, value)
+        } else {
+            integer_loses_precision(&raw, radix, value)
+        };
+        if !loses_precision {
+            return None;
+        }
+
+        let mut err = ctx
+            .err(
+                self.name(),
+                format!("`{}` cannot be represented exactly", token.text()),
+            )
+            .primary(
+                token,
+                format!("this number evaluates to `{}`", format_js_num(value)),
+            )
+            .footer_note("numbers are 64 bit floating point values, values which do not fit are silently rounded");
+
+        let is_integer = if radix == 10 {
+            !raw.contains(&['.', 'e', 'E'][..]) && !raw.starts_with('0')
+        } else {
+            !is_legacy_octal(&raw)
+        };
+        if is_integer && value.is_finite() {
+            err = err.footer_help(format!(
+                "if the exact value is needed, use a BigInt literal instead: `{}n`",
+                token.text()
+            ));
+        }
+        ctx.add_err(err);
+        None
+    }
+}
+
+fn is_legacy_octal(raw: &str) -> bool {
+    raw.len() > 1 && raw.starts_with('0') && raw.chars().all(|c| ('0'..='7').contains(&c))
+}
+
+fn radix(raw: &str) -> u32 {
+    match raw.get(0..2) {
+        Some("0x") | Some("0X") => 16,
+        Some("0b") | Some("0B") => 2,
+        Some("0o") | Some("0O") => 8,
+        _ if is_legacy_octal(raw) => 8,
+        _ => 10,
+    }
+}
+
+/// Whether an integer literal in a non decimal radix has more than 53 significant bits.
+fn integer_loses_precision(raw: &str, radix: u32, value: f64) -> bool {
+    let digits = raw.trim_start_matches("0x").trim_start_matches("0X");
+    let digits = match radix {
+        2 => digits.trim_start_matches("0b").trim_start_matches("0B"),
+        8 => digits.trim_start_matches("0o").trim_start_matches("0O"),
+        _ => digits,
+    };
+    let bits = match BigInt::parse_bytes(digits.as_bytes(), radix) {
+        Some(int) => int.to_str_radix(2),
+        None => return false,
+    };
+    value.is_infinite() || bits.trim_end_matches('0').len() > 53
+}
+
+/// Whether the significant digits of a decimal literal are different to the digits of the value it evaluates to.
+fn decimal_loses_precision(raw: &str, value: f64) -> bool {
+    if value.is_infinite() {
+        return true;
+    }
+    let raw = raw.to_ascii_lowercase();
+    let (mantissa, exponent) = match raw.find('e') {
+        Some(idx) => (&raw[..idx], raw[idx + 1..].parse::<i64>().unwrap_or(0)),
+        None => (raw.as_str(), 0),
+    };
+    let (int, frac) = match mantissa.find('.') {
+        Some(idx) => (&mantissa[..idx], &mantissa[idx + 1..]),
+        None => (mantissa, ""),
+    };
+    let digits = format!("{}{}", int, frac);
+    let trimmed = digits.trim_start_matches('0');
+    // trailing zeros of an integer are not significant, but they are after a decimal point, e.g. `1.00`
+    let significant = if mantissa.contains('.') {
+        trimmed
+    } else {
+        trimmed.trim_end_matches('0')
+    };
+    if significant.is_empty() {
+        // the literal is zero, which is always exact
+        return false;
+    }
+    // the exponent of the first significant digit in scientific notation
+    let leading_zeros = (digits.len() - trimmed.len()) as i64;
+    let expected_exponent = int.len() as i64 - leading_zeros - 1 + exponent;
+
+    let (actual_digits, actual_exponent) = to_precision(value, significant.len());
+    actual_digits != significant || actual_exponent != expected_exponent
+}
+
+/// The significant digits and exponent of a positive number rounded to `precision` digits like
+/// `Number.prototype.toPrecision`, which rounds ties up unlike the ties to even rounding of `format!`.
+fn to_precision(value: f64, precision: usize) -> (String, i64) {
+    // the exact decimal expansion of a 64 bit float has at most 767 significant digits
+    let exact = format!("{:.800e}", value);
+    let (mantissa, exponent) = exact.split_at(exact.find('e').unwrap());
+    let mut exponent = exponent[1..].parse::<i64>().unwrap();
+    let exact_digits = mantissa.replace('.', "");
+    let (digits, rest) = exact_digits.split_at(precision.min(exact_digits.len()));
+    let mut digits = digits.as_bytes().to_vec();
+
+    if rest
+        .as_bytes()
+        .first()
+        .map_or(false, |digit| *digit >= b'5')
+    {
+        match digits.iter().rposition(|digit| *digit != b'9') {
+            Some(idx) => {
+                digits[idx] += 1;
+                digits[idx + 1..].iter_mut().for_each(|digit| *digit = b'0');
+            }
+            None => {
+                // all digits are nines, e.g. `9.99` rounded to `1.00e1`
+                digits.iter_mut().for_each(|digit| *digit = b'0');
+                digits[0] = b'1';
+                exponent += 1;
+            }
+        }
+    }
+    (String::from_utf8(digits).unwrap(), exponent)
+}
+
+rule_tests! {
+    NoLossOfPrecision::default(),
+    err: {
+        "9007199254740993",
+        "5123000000000000000000000000001",
+        "-9007199254740993",
+        "9_007_199_254_740_993",
+        "1230000000000000000000000.0",
+        ".1230000000000000000000000",
+        "900719925474099_3.0e2",
+        "0.12345678901234567890",
+        "1.0000000000000001",
+        "9e999",
+        "1e-400",
+        "0x20000000000001",
+        "0X2_0000_0000_0000_1",
+        "0b100000000000000000000000000000000000000000000000000001",
+        "0o400000000000000001",
+        "0400000000000000001"
+    },
+    ok: {
+        "9007199254740991",
+        "9007199254740993n",
+        "0",
+        "0.0",
+        "000",
+        "12345",
+        "123.456",
+        "1.000",
+        ".5",
+        "900719925474099.3",
+        "123e34",
+        "123.0e34",
+        "1230000000000000000000000",
+        "0.000000000000000000000123",
+        "1_000_000",
+        "0x1FFFFFFFFFFFFF",
+        "0x20000000000000",
+        "0xFFFFFFFFFFFFF800",
+        "0b11111111111111111111111111111111111111111111111111111",
+        "0o377777777777777777",
+        "0377777777777777777",
+        "099",
+        "1.7976931348623157e308",
+        "5e-324"
+    }
+}
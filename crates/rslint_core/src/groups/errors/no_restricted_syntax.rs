@@ -0,0 +1,154 @@
+use crate::rule_prelude::*;
+use util::Selector;
+
+declare_lint! {
+    /**
+    Disallow syntax matching configured selectors.
+
+    This rule allows banning constructs which are specific to a codebase without writing a new rule.
+    Each restricted construct is a selector and an optional message explaining why it is not allowed.
+
+    Selectors match syntax nodes by their kind, the field of their parent they are in, and their text,
+    similar to CSS selectors:
+
+    - `CALL_EXPR` matches nodes of a kind, `*` matches nodes of any kind. The kinds of nodes can be seen
+      by running the linter with `-Z dumpast`.
+    - `A B` matches `B` nodes inside of `A` nodes, `A > B` matches `B` nodes which are direct children of `A` nodes.
+    - `callee` matches nodes in a field of their parent, such as the callee of a call. A field can be followed
+      by a kind, `callee NAME_REF` matches a name which is the callee of its parent.
+    - `[text="eval"]` matches nodes by their text. The other operators are `!=`, `^=` (starts with),
+      `$=` (ends with), and `*=` (contains). Regexes such as `[text=/^set/i]` can be used with `=` and `!=`.
+    - `:has(A)` matches nodes which contain an `A` node, `:has(> A)` matches nodes with an `A` child,
+      `:not(A)` matches nodes which do not match `A`.
+    - `A, B` matches nodes which match either `A` or `B`.
+
+    ```toml
+    [rules.errors.no-restricted-syntax]
+    selectors = [
+        { selector = "WITH_STMT", message = "with statements are not allowed" },
+        { selector = "CALL_EXPR > callee NAME_REF[text=\"eval\"]", message = "eval is evil" },
+        { selector = "FN_DECL:has(YIELD_EXPR)" },
+    ]
+    ```
+
+    ## Incorrect Code Examples
+
+    With the config above:
+
+    ```js
+    with (foo) {}
+    ```
+
+    ```js
+    eval("foo");
+    ```
+
+    ```js
+    function* foo() {
+        yield 5;
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    foo.eval("bar");
+    ```
+
+    ```js
+    const foo = function* () {
+        yield 5;
+    };
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    NoRestrictedSyntax,
+    errors,
+    "no-restricted-syntax",
+    /// The selectors for syntax which is not allowed, each with an optional `message` which is shown
+    /// instead of the default message.
+    pub selectors: Vec<RestrictedSyntax>
+}
+
+/// A selector for syntax which is not allowed and the message to report it with.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RestrictedSyntax {
+    pub selector: Selector,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+#[typetag::serde]
+impl CstRule for NoRestrictedSyntax {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        for restricted in &self.selectors {
+            if !restricted.selector.matches(node) {
+                continue;
+            }
+            let message = restricted.message.clone().unwrap_or_else(|| {
+                format!(
+                    "using `{}` is not allowed",
+                    restricted.selector.source().trim()
+                )
+            });
+            let err = ctx.err(self.name(), message).primary(
+                node,
+                format!("this matches `{}`", restricted.selector.source().trim()),
+            );
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+rule_tests! {
+    NoRestrictedSyntax {
+        selectors: vec![
+            RestrictedSyntax {
+                selector: Selector::parse("DEBUGGER_STMT").unwrap(),
+                message: Some("debugger statements are not allowed".to_string()),
+            },
+            RestrictedSyntax {
+                selector: Selector::parse("CALL_EXPR > callee NAME_REF[text=\"eval\"]").unwrap(),
+                message: None,
+            },
+            RestrictedSyntax {
+                selector: Selector::parse("FN_DECL:has(YIELD_EXPR)").unwrap(),
+                message: None,
+            },
+            RestrictedSyntax {
+                selector: Selector::parse("BIN_EXPR > rhs LITERAL[text=/^null$/], FOR_IN_STMT").unwrap(),
+                message: None,
+            },
+        ]
+    },
+    err: {
+        "debugger;",
+        "eval('foo');",
+        "if (a) { eval(b); }",
+        "function* foo() { yield 5; }",
+        "a === null;",
+        "for (const a in b) {}"
+    },
+    ok: {
+        "foo.eval('bar');",
+        "foo(eval);",
+        "new eval();",
+        "const foo = function* () { yield 5; };",
+        "null === a;",
+        "for (const a of b) {}"
+    }
+}
+
+rule_tests! {
+    default_valid,
+    default_invalid,
+    NoRestrictedSyntax::default(),
+    err: {},
+    ok: {
+        "debugger;",
+        "eval('foo');"
+    }
+}
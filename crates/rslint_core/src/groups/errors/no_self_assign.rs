@@ -0,0 +1,307 @@
+use crate::rule_prelude::*;
+use ast::{AssignExpr, AssignOp, Expr};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow assigning a variable to itself.
+
+    Assigning a variable to itself has no effect, it is usually a mistake left over from refactoring or
+    a typo of a different variable name.
+
+    Destructuring assignments are also checked, `[a, b] = [a, b]` assigns both `a` and `b` to themselves.
+    Assigning a property to itself, such as `obj.a = obj.a`, is reported unless the `props` option is
+    disabled, since property assignments may call setters.
+
+    ## Incorrect Code Examples
+
+    ```js
+    foo = foo;
+    ```
+
+    ```js
+    [a, b] = [a, b];
+    ```
+
+    ```js
+    ({ a, b } = { a, b: c });
+    ```
+
+    ```js
+    obj.a = obj.a;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    foo = bar;
+    ```
+
+    ```js
+    [a, b] = [b, a];
+    ```
+
+    ```js
+    obj.a = obj.b;
+    ```
+    */
+    #[serde(default)]
+    NoSelfAssign,
+    errors,
+    "no-self-assign",
+    /// Whether to report properties assigned to themselves, such as `obj.a = obj.a` (true by default).
+    pub props: bool
+}
+
+impl Default for NoSelfAssign {
+    fn default() -> Self {
+        Self { props: true }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for NoSelfAssign {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let expr = node.try_to::<AssignExpr>()?;
+        if expr.op()? != AssignOp::Assign {
+            return None;
+        }
+
+        let mut found = vec![];
+        self.self_assignments(expr.lhs()?.syntax(), expr.rhs()?.syntax(), &mut found);
+        for (target, value) in found {
+            let err = ctx
+                .err(
+                    self.name(),
+                    format!("`{}` is assigned to itself", target.trimmed_text()),
+                )
+                .secondary(target.trimmed_range(), "this is assigned...")
+                .primary(value.trimmed_range(), "...to itself");
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+impl NoSelfAssign {
+    /// Collect the targets of an assignment which are assigned to themselves along with the value assigned to them.
+    fn self_assignments(
+        &self,
+        target: &SyntaxNode,
+        value: &SyntaxNode,
+        found: &mut Vec<(SyntaxNode, SyntaxNode)>,
+    ) {
+        let value = unwrap_grouping(value);
+        match (target.kind(), value.kind()) {
+            (NAME_REF, _) | (SINGLE_PATTERN, _) => {
+                let target_name = match target.kind() {
+                    NAME_REF => Some(target.text()),
+                    _ => target.child_with_kind(NAME).map(|name| name.text()),
+                };
+                // shorthand properties such as `{ a }` are a name which is also the value
+                let value_name = match value.kind() {
+                    NAME_REF => Some(value.text()),
+                    IDENT_PROP => value.child_with_kind(NAME).map(|name| name.text()),
+                    _ => None,
+                };
+                if target_name.is_some() && target_name == value_name {
+                    found.push((target.clone(), value));
+                }
+            }
+            (DOT_EXPR, DOT_EXPR) | (BRACKET_EXPR, BRACKET_EXPR)
+                if self.props && is_reference(target) && target.lexical_eq(&value) =>
+            {
+                found.push((target.clone(), value));
+            }
+            (ARRAY_PATTERN, ARRAY_EXPR) => {
+                let targets = elements(target);
+                let values = elements(&value);
+                for (target, value) in targets.iter().zip(values.iter()) {
+                    let (target, value) = match (target, value) {
+                        (Some(target), Some(value)) => (target, value),
+                        _ => continue,
+                    };
+                    match (target.kind(), value.kind()) {
+                        // `[...a] = [...a]`
+                        (REST_PATTERN, SPREAD_ELEMENT) => {
+                            let rest = target.to::<ast::RestPattern>();
+                            let spread = value.to::<ast::SpreadElement>();
+                            if let (Some(pat), Some(element)) = (rest.pat(), spread.element()) {
+                                self.self_assignments(pat.syntax(), element.syntax(), found);
+                            }
+                            break;
+                        }
+                        // the elements after a spread do not line up anymore
+                        (_, SPREAD_ELEMENT) | (REST_PATTERN, _) => break,
+                        _ => self.self_assignments(target, value, found),
+                    }
+                }
+            }
+            (OBJECT_PATTERN, OBJECT_EXPR) => {
+                // properties before a spread may be overwritten by the spread
+                let props = value
+                    .to::<ast::ObjectExpr>()
+                    .props()
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .rev()
+                    .take_while(|prop| !matches!(prop, ast::ObjectProp::SpreadProp(_)))
+                    .collect::<Vec<_>>();
+
+                for target_prop in target.to::<ast::ObjectPattern>().elements() {
+                    let (key, target) = match pattern_prop(&target_prop) {
+                        Some(prop) => prop,
+                        None => continue,
+                    };
+                    for (value_key, value) in props.iter().filter_map(object_prop) {
+                        if key == value_key {
+                            self.self_assignments(&target, &value, found);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn unwrap_grouping(node: &SyntaxNode) -> SyntaxNode {
+    match node
+        .try_to::<ast::GroupingExpr>()
+        .and_then(|group| group.inner())
+    {
+        Some(inner) => unwrap_grouping(inner.syntax()),
+        None => node.clone(),
+    }
+}
+
+/// Whether a member expression only consists of names and member accesses, `a.b().c` is not the same
+/// reference every time it is evaluated.
+fn is_reference(node: &SyntaxNode) -> bool {
+    match node.kind() {
+        NAME_REF | THIS_EXPR => true,
+        DOT_EXPR => node
+            .to::<ast::DotExpr>()
+            .object()
+            .map_or(false, |object| is_reference(object.syntax())),
+        BRACKET_EXPR => {
+            let expr = node.to::<ast::BracketExpr>();
+            expr.object()
+                .map_or(false, |object| is_reference(object.syntax()))
+                && expr.prop().map_or(false, |prop| {
+                    matches!(prop, Expr::Literal(_)) || is_reference(prop.syntax())
+                })
+        }
+        GROUPING_EXPR => node
+            .to::<ast::GroupingExpr>()
+            .inner()
+            .map_or(false, |inner| is_reference(inner.syntax())),
+        _ => false,
+    }
+}
+
+/// The elements of an array pattern or array expression, with `None` for holes such as in `[a, , b]`.
+fn elements(node: &SyntaxNode) -> Vec<Option<SyntaxNode>> {
+    let mut elements = vec![];
+    let mut current = None;
+    for elem in node.children_with_tokens() {
+        match elem.kind() {
+            COMMA => elements.push(current.take()),
+            _ => {
+                if let Some(child) = elem.into_node() {
+                    current = Some(child);
+                }
+            }
+        }
+    }
+    if current.is_some() {
+        elements.push(current);
+    }
+    elements
+}
+
+/// The static key and the target of a property of an object pattern.
+fn pattern_prop(prop: &ast::ObjectPatternProp) -> Option<(String, SyntaxNode)> {
+    match prop {
+        ast::ObjectPatternProp::SinglePattern(pattern) => Some((
+            pattern.name()?.syntax().text().to_string(),
+            pattern.syntax().clone(),
+        )),
+        ast::ObjectPatternProp::KeyValuePattern(pattern) => Some((
+            util::static_prop_name(pattern.key()?.syntax())?,
+            pattern.value()?.syntax().clone(),
+        )),
+        _ => None,
+    }
+}
+
+/// The static key and the value of a property of an object expression, the value of a shorthand
+/// property is the property itself.
+fn object_prop(prop: &ast::ObjectProp) -> Option<(String, SyntaxNode)> {
+    match prop {
+        ast::ObjectProp::IdentProp(prop) => Some((
+            prop.name()?.syntax().text().to_string(),
+            prop.syntax().clone(),
+        )),
+        ast::ObjectProp::LiteralProp(prop) => Some((
+            util::static_prop_name(prop.key()?.syntax())?,
+            prop.value()?.syntax().clone(),
+        )),
+        _ => None,
+    }
+}
+
+rule_tests! {
+    NoSelfAssign::default(),
+    err: {
+        "a = a;",
+        "a = (a);",
+        "[a] = [a];",
+        "[a, b] = [a, b];",
+        "[a, b] = [a, c];",
+        "[a, , b] = [a, , b];",
+        "[a, ...b] = [a, ...b];",
+        "[[a], b] = [[a], b];",
+        "({ a } = { a });",
+        "({ a: b } = { a: b });",
+        "({ a, b } = { a, b: c });",
+        "({ 'a': b } = { a: b });",
+        "({ a } = { ...c, a });",
+        "obj.a = obj.a;",
+        "obj['a'] = obj['a'];",
+        "obj[a] = obj[a];",
+        "obj.a.b = obj.a.b;",
+        "this.a = this . a;"
+    },
+    ok: {
+        "a = b;",
+        "a += a;",
+        "a = +a;",
+        "a = [a];",
+        "[a, b] = [b, a];",
+        "[a, , b] = [, b, a];",
+        "[...a] = [a];",
+        "[a, ...b] = [...a, b];",
+        "({ a } = { b: a });",
+        "({ a } = { a, ...c });",
+        "({ [a]: b } = { [a]: b });",
+        "obj.a = obj.b;",
+        "obj.a() = obj.a();",
+        "a.b().c = a.b().c;",
+        "let a = a;"
+    }
+}
+
+rule_tests! {
+    props_false_valid,
+    props_false_invalid,
+    NoSelfAssign { props: false },
+    err: {
+        "a = a;",
+        "[a] = [a];"
+    },
+    ok: {
+        "obj.a = obj.a;",
+        "obj['a'] = obj['a'];"
+    }
+}
@@ -0,0 +1,149 @@
+use crate::rule_prelude::*;
+use ast::{BinExpr, BinOp, Expr};
+
+declare_lint! {
+    /**
+    Disallow comparing an expression to itself.
+
+    Comparing an expression to itself is pointless, the result is always the same. The only value for which
+    this is not the case is `NaN`, which is not equal to anything including itself, so `x !== x` is only true
+    if `x` is `NaN`. Using `Number.isNaN(x)` makes that intent clear.
+
+    ## Incorrect Code Examples
+
+    ```js
+    if (x === x) {}
+    ```
+
+    ```js
+    if (foo.bar < foo.bar) {}
+    ```
+
+    ```js
+    if (x !== x) {
+        // x is NaN
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    if (x === y) {}
+    ```
+
+    ```js
+    if (Number.isNaN(x)) {}
+    ```
+    */
+    #[derive(Default)]
+    NoSelfCompare,
+    errors,
+    "no-self-compare"
+}
+
+#[typetag::serde]
+impl CstRule for NoSelfCompare {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let expr = node.try_to::<BinExpr>()?;
+        if !expr.comparison() {
+            return None;
+        }
+        let (lhs, rhs) = (expr.lhs()?, expr.rhs()?);
+        if !unwrap_grouping(&lhs)
+            .syntax()
+            .lexical_eq(unwrap_grouping(&rhs).syntax())
+        {
+            return None;
+        }
+
+        let op_token = expr.op_token()?;
+        // the result if the operand is not `NaN`, and the replacement checking for `NaN` with the same result
+        let (result, replacement) = match expr.op()? {
+            BinOp::Equality
+            | BinOp::StrictEquality
+            | BinOp::LessThanOrEqual
+            | BinOp::GreaterThanOrEqual => (
+                "true",
+                Some(format!("!Number.isNaN({})", lhs.syntax().trimmed_text())),
+            ),
+            BinOp::Inequality | BinOp::StrictInequality => (
+                "false",
+                Some(format!("Number.isNaN({})", lhs.syntax().trimmed_text())),
+            ),
+            _ => ("false", None),
+        };
+
+        let text = lhs.syntax().trimmed_text().to_string();
+        let mut err = if text.len() <= 20 {
+            ctx.err(
+                self.name(),
+                format!("comparing `{}` to itself is pointless", text),
+            )
+        } else {
+            ctx.err(
+                self.name(),
+                "comparing an expression to itself is pointless",
+            )
+        }
+        .secondary(lhs.syntax(), "this expression...")
+        .primary(rhs.syntax(), "...is compared to itself");
+
+        err = match replacement {
+            Some(replacement) => err
+                .footer_note(format!(
+                    "this comparison is always {} unless the expression is `NaN`, which is not equal to anything including itself",
+                    result
+                ))
+                .suggestion(
+                    expr.range(),
+                    "if this is checking for `NaN`, use `Number.isNaN` instead",
+                    replacement,
+                    Applicability::MaybeIncorrect,
+                ),
+            None => err.footer_note(format!(
+                "`{}` with the same operands is always {}",
+                op_token.text(),
+                result
+            )),
+        };
+        ctx.add_err(err);
+        None
+    }
+}
+
+fn unwrap_grouping(expr: &Expr) -> Expr {
+    match expr {
+        Expr::GroupingExpr(group) => group
+            .inner()
+            .map_or_else(|| expr.clone(), |inner| unwrap_grouping(&inner)),
+        _ => expr.clone(),
+    }
+}
+
+rule_tests! {
+    NoSelfCompare::default(),
+    err: {
+        "x === x;",
+        "x !== x;",
+        "x == x;",
+        "x != x;",
+        "x > x;",
+        "x < x;",
+        "x >= x;",
+        "x <= x;",
+        "foo.bar === foo . bar;",
+        "(x) === x;",
+        "foo.bar().baz < foo.bar().baz;",
+        "if (x !== x) {}"
+    },
+    ok: {
+        "x === y;",
+        "x !== y;",
+        "x === x.y;",
+        "x.y === x;",
+        "x + x;",
+        "x = x;",
+        "Number.isNaN(x);",
+        "foo.bar === foo.baz;"
+    }
+}
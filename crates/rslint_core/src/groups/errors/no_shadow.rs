@@ -0,0 +1,235 @@
+use crate::rule_prelude::*;
+use crate::semantic::{Binding, BindingKind};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow variable declarations from shadowing variables declared in an outer scope.
+
+    Shadowing is when a variable declared in an inner scope has the same name as a variable in an outer scope.
+    Inside of the inner scope the outer variable cannot be used anymore, which makes it easy to accidentally
+    use the wrong variable, and makes it harder to tell which variable a name refers to.
+
+    ## Incorrect Code Examples
+
+    ```js
+    let a = 5;
+
+    function foo() {
+        let a = 6;
+    }
+    ```
+
+    ```js
+    function foo(bar) {
+        if (bar) {
+            let bar = 5;
+        }
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let a = 5;
+
+    function foo() {
+        let b = 6;
+    }
+    ```
+    */
+    #[serde(default)]
+    NoShadow,
+    errors,
+    "no-shadow",
+    /// Whether to also report declarations which shadow globals such as `Object` or `Promise` (false by default).
+    pub builtin_globals: bool,
+    /// Whether to report shadowing a declaration which comes after the shadowing declaration,
+    /// either "all", "functions" (only report functions declared later), or "never" ("functions" by default).
+    pub hoist: String,
+    /// Names which are allowed to shadow other declarations.
+    pub allow: Vec<String>
+}
+
+impl Default for NoShadow {
+    fn default() -> Self {
+        Self {
+            builtin_globals: false,
+            hoist: "functions".to_string(),
+            allow: vec![],
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for NoShadow {
+    fn check_root(&self, _root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let model = ctx.semantic.clone();
+        for binding in model.bindings() {
+            if self.allow.iter().any(|name| *name == binding.name) {
+                continue;
+            }
+            let outer = model
+                .scope(binding.scope)
+                .parent
+                .and_then(|parent| model.lookup(parent, &binding.name));
+
+            match outer {
+                Some(outer) if self.should_report(binding, outer) => {
+                    let err = ctx
+                        .err(
+                            self.name(),
+                            format!("`{}` shadows a declaration in an outer scope", binding.name),
+                        )
+                        .secondary(
+                            outer.range(),
+                            format!("`{}` is first declared here", binding.name),
+                        )
+                        .primary(
+                            binding.range(),
+                            format!(
+                                "`{}` is then redeclared here, which makes the outer `{}` unusable in this scope",
+                                binding.name, binding.name
+                            ),
+                        );
+                    ctx.add_err(err);
+                }
+                None if self.builtin_globals && model.global(&binding.name).is_some() => {
+                    let err = ctx
+                        .err(
+                            self.name(),
+                            format!("`{}` shadows a global variable", binding.name),
+                        )
+                        .primary(
+                            binding.range(),
+                            format!("the global `{}` is unusable in this scope", binding.name),
+                        );
+                    ctx.add_err(err);
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+impl NoShadow {
+    fn should_report(&self, inner: &Binding, outer: &Binding) -> bool {
+        // `let a = function a() {}` is not confusing, both names refer to the same thing.
+        let is_initializer = matches!(inner.decl.kind(), FN_EXPR | CLASS_EXPR)
+            && outer
+                .name_node()
+                .ancestors()
+                .find(|node| node.kind() == DECLARATOR)
+                .map_or(false, |declarator| {
+                    declarator
+                        .text_range()
+                        .contains_range(inner.decl.text_range())
+                });
+        if is_initializer {
+            return false;
+        }
+
+        if outer.range().end() <= inner.range().start() {
+            return true;
+        }
+        match self.hoist.as_str() {
+            "all" => true,
+            "never" => false,
+            _ => outer.kind == BindingKind::Function,
+        }
+    }
+}
+
+rule_tests! {
+    NoShadow::default(),
+    err: {
+        "
+        let a = 5;
+        function foo() {
+            let a = 6;
+        }
+        ",
+        "
+        function foo(bar) {
+            if (bar) {
+                let bar = 5;
+            }
+        }
+        ",
+        "
+        var a;
+        try {} catch (a) {}
+        ",
+        "
+        const a = 1;
+        const b = (a) => a;
+        ",
+        "
+        function foo() {
+            var foo;
+        }
+        ",
+        "
+        function foo() {
+            let a;
+        }
+        function a() {}
+        "
+    },
+    ok: {
+        "
+        let a = 5;
+        function foo() {
+            let b = 6;
+        }
+        ",
+        "
+        let foo = function foo() {};
+        ",
+        "
+        function foo() {
+            let a;
+        }
+        let a;
+        ",
+        "
+        {
+            let a;
+        }
+        {
+            let a;
+        }
+        ",
+        "let Object = 5;"
+    }
+}
+
+rule_tests! {
+    options_valid,
+    options_invalid,
+    NoShadow {
+        builtin_globals: true,
+        hoist: "all".to_string(),
+        allow: vec!["done".to_string()],
+    },
+    err: {
+        "
+        function foo() {
+            let Promise;
+        }
+        ",
+        "
+        function foo() {
+            let a;
+        }
+        let a;
+        "
+    },
+    ok: {
+        "
+        let done;
+        function foo(done) {}
+        "
+    }
+}
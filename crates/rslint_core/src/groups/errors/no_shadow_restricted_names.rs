@@ -0,0 +1,111 @@
+use crate::rule_prelude::*;
+use crate::semantic::{Binding, BindingKind, SemanticModel};
+
+declare_lint! {
+    /**
+    Disallow declarations which shadow restricted names.
+
+    `undefined`, `NaN`, `Infinity`, `arguments`, and `eval` are global properties or special bindings which are
+    expected to always have the same value. Declaring variables, functions, or parameters with these names
+    shadows them, which is extremely confusing, for example `undefined` could suddenly be `5`.
+
+    Declaring `var undefined` without assigning to it is allowed since the value stays `undefined`.
+
+    ## Incorrect Code Examples
+
+    ```js
+    function NaN() {}
+    ```
+
+    ```js
+    let undefined = 5;
+    ```
+
+    ```js
+    try {} catch (eval) {}
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    var undefined;
+    ```
+    */
+    #[derive(Default)]
+    NoShadowRestrictedNames,
+    errors,
+    "no-shadow-restricted-names"
+}
+
+pub const RESTRICTED_NAMES: [&str; 5] = ["undefined", "NaN", "Infinity", "arguments", "eval"];
+
+#[typetag::serde]
+impl CstRule for NoShadowRestrictedNames {
+    fn check_root(&self, _root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let model = ctx.semantic.clone();
+        for binding in model.bindings() {
+            if !RESTRICTED_NAMES.contains(&binding.name.as_str())
+                || is_unassigned_undefined(binding, &model)
+            {
+                continue;
+            }
+
+            let note = if binding.name == "arguments" {
+                "`arguments` is implicitly declared in every function, and holds the arguments the function was called with"
+            } else if binding.name == "eval" {
+                "`eval` is a global function which evaluates code, redeclaring it makes calls to it very confusing"
+            } else {
+                "this is a global property which always has the same value"
+            };
+
+            let mut err = ctx
+                .err(
+                    self.name(),
+                    format!("shadowing of the restricted name `{}`", binding.name),
+                )
+                .primary(
+                    binding.range(),
+                    format!("`{}` is declared here", binding.name),
+                );
+            for redeclaration in binding.declarations.iter().skip(1) {
+                err = err.secondary(
+                    redeclaration.text_range(),
+                    format!("`{}` is also redeclared here", binding.name),
+                );
+            }
+            ctx.add_err(err.footer_note(note));
+        }
+        None
+    }
+}
+
+fn is_unassigned_undefined(binding: &Binding, model: &SemanticModel) -> bool {
+    binding.name == "undefined"
+        && binding.kind == BindingKind::Var
+        && model.writes(binding.id).next().is_none()
+}
+
+rule_tests! {
+    NoShadowRestrictedNames::default(),
+    err: {
+        "function NaN() {}",
+        "let undefined = 5;",
+        "var undefined = 5;",
+        "
+        var undefined;
+        undefined = 5;
+        ",
+        "try {} catch (eval) {}",
+        "function foo(arguments) {}",
+        "let [Infinity] = foo;",
+        "class undefined {}",
+        "import NaN from 'foo';"
+    },
+    ok: {
+        "var undefined;",
+        "let foo = undefined;",
+        "foo.NaN = 5;",
+        "let obj = { eval: 5 };",
+        "function foo() { return arguments; }"
+    }
+}
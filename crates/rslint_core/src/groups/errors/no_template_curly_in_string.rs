@@ -0,0 +1,108 @@
+use crate::rule_prelude::*;
+use ast::Literal;
+use rslint_parser::{TextRange, TextSize};
+
+declare_lint! {
+    /**
+    Disallow template literal placeholder syntax in ordinary strings.
+
+    Placeholders such as `${name}` only insert values into template literals, which are quoted with backticks.
+    Inside of a string quoted with `"` or `'` they are included as is, which is usually a mistake made while
+    converting a string into a template literal or the other way around.
+
+    ## Incorrect Code Examples
+
+    ```js
+    "Hello ${name}!";
+    ```
+
+    ```js
+    'Time: ${12 * 60 * 60 * 1000}';
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    `Hello ${name}!`;
+    ```
+
+    ```js
+    "Hello {name}!";
+    ```
+    */
+    #[derive(Default)]
+    NoTemplateCurlyInString,
+    errors,
+    "no-template-curly-in-string"
+}
+
+#[typetag::serde]
+impl CstRule for NoTemplateCurlyInString {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let literal = node.try_to::<Literal>()?;
+        let text = literal.inner_string_text()?.to_string();
+        let placeholder = find_placeholder(&text)?;
+        let token = literal.token();
+        let start = token.text_range().start() + TextSize::from(1);
+
+        let mut err = ctx
+            .err(
+                self.name(),
+                "unexpected template literal placeholder syntax in a string",
+            )
+            .primary(
+                placeholder + start,
+                "this is not a placeholder, it is included in the string as is",
+            );
+        if let Some(template) = util::string_to_template_text(&text, false) {
+            err = err.suggestion(
+                token.text_range(),
+                "use a template literal if this was meant to be a placeholder",
+                format!("`{}`", template),
+                Applicability::MaybeIncorrect,
+            );
+        }
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// The range of the first `${...}` with a non-empty body in the text.
+fn find_placeholder(text: &str) -> Option<TextRange> {
+    let mut offset = 0;
+    while let Some(start) = text[offset..].find("${").map(|idx| idx + offset) {
+        let body_start = start + 2;
+        if let Some(len) = text[body_start..].find('}') {
+            if len > 0 {
+                let end = body_start + len + 1;
+                return Some(TextRange::new(
+                    TextSize::from(start as u32),
+                    TextSize::from(end as u32),
+                ));
+            }
+        }
+        offset = body_start;
+    }
+    None
+}
+
+rule_tests! {
+    NoTemplateCurlyInString::default(),
+    err: {
+        "'Hello ${name}';",
+        "\"Hello, ${name}\";",
+        "'${greeting}, ${name}';",
+        "'Time: ${12 * 60 * 60 * 1000}';",
+        "'${}${a}';",
+        "foo('${bar}');"
+    },
+    ok: {
+        "`Hello ${name}`;",
+        "`Hello, ${name}`;",
+        "'Hello {name}';",
+        "'Hello, $name';",
+        "'${}';",
+        "'$ {foo}';",
+        "tag`${foo}`;"
+    }
+}
@@ -0,0 +1,236 @@
+use crate::rule_prelude::*;
+use crate::semantic::Reference;
+
+declare_lint! {
+    /**
+    Disallow the use of variables which are never declared.
+
+    Using a variable which was never declared throws a `ReferenceError` at runtime, or creates
+    a new global variable when it is assigned to outside of strict mode. This is usually caused by
+    a typo or a missing import.
+
+    Globals provided by the environment the code runs in are allowed, ECMAScript builtins such as `Promise`
+    are always available, other environments can be enabled with the `env` table in the config:
+
+    ```toml
+    [env]
+    browser = true
+    jest = true
+
+    [globals]
+    myGlobal = "readonly"
+    ```
+
+    Globals can also be declared inside of a file with a comment:
+
+    ```js
+    /* global myGlobal, otherGlobal: writable */
+    ```
+
+    The available environments are `builtin`, `es5`, `es6`/`es2015`, `es2017`, `es2020`, `es2021`, `browser`,
+    `node`, `commonjs`, `shared-node-browser`, `worker`, `jest`, and `mocha`.
+
+    ## Incorrect Code Examples
+
+    ```js
+    let foo = bar;
+    ```
+
+    ```js
+    function foo(result) {
+        return reslt;
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    /* global bar */
+    let foo = bar;
+    ```
+
+    ```js
+    // undeclared variables used with `typeof` are allowed by default
+    if (typeof window !== "undefined") {}
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    NoUndef,
+    errors,
+    "no-undef",
+    /// Whether to also report undeclared variables used with `typeof`, for example `typeof foo === "undefined"` (false by default).
+    pub check_typeof: bool
+}
+
+#[typetag::serde]
+impl CstRule for NoUndef {
+    fn check_root(&self, _root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let model = ctx.semantic.clone();
+        for reference in model.unresolved_references() {
+            if model.global(&reference.name).is_some()
+                || (!self.check_typeof && reference.is_typeof())
+            {
+                continue;
+            }
+            self.output(reference, ctx);
+        }
+        None
+    }
+}
+
+impl NoUndef {
+    fn output(&self, reference: &Reference, ctx: &mut RuleCtx) {
+        let model = ctx.semantic.clone();
+        let mut err = ctx
+            .err(self.name(), format!("`{}` is not defined", reference.name))
+            .primary(
+                reference.node.text_range(),
+                "this variable is never declared",
+            );
+
+        let mut visible = vec![];
+        let mut scope = Some(reference.scope);
+        while let Some(id) = scope {
+            let scope_ref = model.scope(id);
+            visible.extend(
+                scope_ref
+                    .bindings
+                    .iter()
+                    .map(|binding| model.binding(*binding).name.as_str()),
+            );
+            scope = scope_ref.parent;
+        }
+        visible.extend(model.globals().names());
+        visible.extend(model.file_globals().map(|(name, _)| name));
+
+        if let Some(name) =
+            util::find_best_match_for_name(visible.into_iter(), &reference.name, None)
+        {
+            err = err.footer_help(format!("a variable with a similar name exists: `{}`", name));
+        } else {
+            err = err.footer_help(format!(
+                "if `{}` is a global, declare it with `/* global {} */` or enable the environment which provides it",
+                reference.name, reference.name
+            ));
+        }
+        ctx.add_err(err);
+    }
+}
+
+rule_tests! {
+    NoUndef::default(),
+    err: {
+        "let foo = bar;",
+        "foo = 5;",
+        "
+        function foo() {
+            return baz;
+        }
+        ",
+        "
+        {
+            let a = 5;
+        }
+        a;
+        ",
+        "window.alert('hi');",
+        "
+        /* global foo: off */
+        foo;
+        "
+    },
+    ok: {
+        "
+        let a = 5;
+        a;
+        ",
+        "
+        foo();
+        function foo() {}
+        ",
+        "
+        /* global foo, bar: writable */
+        foo(bar);
+        ",
+        "
+        /*globals foo*/
+        foo;
+        ",
+        "Promise.resolve(undefined, NaN, Infinity, globalThis);",
+        "typeof foo === 'undefined';",
+        "
+        function foo() {
+            return arguments;
+        }
+        ",
+        "
+        import foo from 'foo';
+        foo();
+        "
+    }
+}
+
+rule_tests! {
+    check_typeof_valid,
+    check_typeof_invalid,
+    NoUndef {
+        check_typeof: true,
+    },
+    err: {
+        "typeof foo;"
+    },
+    ok: {
+        "
+        let foo;
+        typeof foo;
+        "
+    }
+}
+
+rule_tests! {
+    typescript,
+    typescript_valid,
+    typescript_invalid,
+    NoUndef::default(),
+    err: {
+        "
+        enum Color { Red }
+        Colour.Red;
+        "
+    },
+    ok: {
+        "
+        enum Color { Red }
+        Color.Red;
+        ",
+        "
+        const enum Color { Red }
+        Color.Red;
+        ",
+        "
+        namespace Foo {
+            export const a = 5;
+        }
+        Foo.a;
+        ",
+        "
+        module Foo {
+            export const a = 5;
+        }
+        Foo.a;
+        ",
+        "
+        declare namespace Foo {
+            const a: number;
+        }
+        Foo.a;
+        ",
+        "
+        namespace Foo.Bar {
+            export const a = 5;
+        }
+        Foo.Bar.a;
+        "
+    }
+}
@@ -0,0 +1,304 @@
+use crate::cfg::{self, ControlFlowGraph};
+use crate::rule_prelude::*;
+use rslint_parser::TextRange;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow unreachable code after `return`, `throw`, `break`, and `continue` statements.
+
+    Statements which come after a statement that unconditionally transfers control
+    can never be executed. This is also the case for code after loops which never end,
+    and after `if`/`else` statements in which every branch ends execution. Unreachable
+    code is almost always a mistake, such as a forgotten condition or a leftover from refactoring.
+
+    Function declarations and `var` declarations without initializers are not reported since they
+    are hoisted, so they are still usable from the reachable code.
+
+    ## Incorrect Code Examples
+
+    ```js
+    function foo() {
+        return 5;
+        console.log("this is never logged");
+    }
+    ```
+
+    ```js
+    while (true) {}
+    doSomething();
+    ```
+
+    ```js
+    function foo(bar) {
+        if (bar) {
+            return 1;
+        } else {
+            throw new Error("...");
+        }
+        bar();
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function foo() {
+        return bar();
+
+        // hoisted, so this can be called from above
+        function bar() {
+            return 5;
+        }
+    }
+    ```
+    */
+    #[derive(Default)]
+    NoUnreachable,
+    errors,
+    "no-unreachable"
+}
+
+#[typetag::serde]
+impl CstRule for NoUnreachable {
+    fn check_root(&self, root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        self.check_node(root, ctx)
+    }
+
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !matches!(
+            node.kind(),
+            SCRIPT | MODULE | BLOCK_STMT | CASE_CLAUSE | DEFAULT_CLAUSE
+        ) {
+            return None;
+        }
+        let owner = if cfg::is_cfg_owner(node.kind()) {
+            node.to_owned()
+        } else {
+            cfg::owner(node)?
+        };
+        let cfg = ctx.cfg(&owner)?;
+
+        // only report the top-most unreachable statements, anything nested inside of them
+        // is unreachable as well and reporting it would be noise.
+        let is_body = node == &owner || node.parent().as_ref() == Some(&owner);
+        if !is_body && !cfg.is_reachable(node) {
+            return None;
+        }
+
+        let stmts = node
+            .children()
+            .filter(|child| child.is::<ast::Stmt>() || child.kind() == FN_DECL)
+            .collect::<Vec<_>>();
+
+        let mut dead: Vec<SyntaxNode> = vec![];
+        for stmt in &stmts {
+            if !cfg.is_reachable(stmt) && !is_hoisted_or_empty(stmt) {
+                dead.push(stmt.to_owned());
+            } else if !dead.is_empty() {
+                self.output(&stmts, std::mem::take(&mut dead), &cfg, ctx);
+            }
+        }
+        if !dead.is_empty() {
+            self.output(&stmts, dead, &cfg, ctx);
+        }
+        None
+    }
+}
+
+impl NoUnreachable {
+    fn output(
+        &self,
+        stmts: &[SyntaxNode],
+        dead: Vec<SyntaxNode>,
+        cfg: &ControlFlowGraph,
+        ctx: &mut RuleCtx,
+    ) {
+        let first = &dead[0];
+        let last = dead.last().unwrap();
+        let range = TextRange::new(first.trimmed_range().start(), last.trimmed_range().end());
+        let terminator = stmts
+            .iter()
+            .take_while(|stmt| *stmt != first)
+            .filter(|stmt| stmt.kind() != FN_DECL && cfg.is_reachable(stmt))
+            .last()
+            .filter(|stmt| !cfg.completes_normally(stmt));
+
+        let mut err = ctx
+            .err(self.name(), "unreachable code")
+            .primary(range, "this code can never be executed");
+
+        if let Some(stmt) = terminator {
+            err = err.secondary(stmt.trimmed_range(), terminator_message(stmt));
+        }
+
+        err = err.suggestion(
+            range,
+            "remove the unreachable code",
+            "",
+            Applicability::MaybeIncorrect,
+        );
+        ctx.add_err(err);
+    }
+}
+
+fn terminator_message(stmt: &SyntaxNode) -> String {
+    match stmt.kind() {
+        RETURN_STMT => "...because this statement returns from the function".to_string(),
+        THROW_STMT => "...because this statement throws an error".to_string(),
+        BREAK_STMT => "...because this statement breaks out of the enclosing statement".to_string(),
+        CONTINUE_STMT => "...because this statement continues the loop".to_string(),
+        _ if stmt.is_loop() => "...because this loop never ends".to_string(),
+        _ => format!(
+            "...because control never flows past this {}",
+            stmt.readable_stmt_name().to_ascii_lowercase()
+        ),
+    }
+}
+
+/// Whether a statement does nothing when it is executed, either because it is hoisted or because it is empty.
+fn is_hoisted_or_empty(stmt: &SyntaxNode) -> bool {
+    match stmt.kind() {
+        FN_DECL | EMPTY_STMT => true,
+        VAR_DECL => {
+            let decl = stmt.to::<ast::VarDecl>();
+            decl.is_var()
+                && decl
+                    .declared()
+                    .all(|declarator| declarator.value().is_none())
+        }
+        _ => false,
+    }
+}
+
+rule_tests! {
+    NoUnreachable::default(),
+    err: {
+        "
+        function foo() {
+            return 5;
+            foo();
+        }
+        ",
+        "
+        function foo() {
+            throw new Error();
+            let a = 5;
+        }
+        ",
+        "
+        while (foo) {
+            break;
+            bar();
+        }
+        ",
+        "
+        for (const a of b) {
+            continue;
+            a();
+        }
+        ",
+        "
+        while (true) {}
+        foo();
+        ",
+        "
+        for (;;) {}
+        foo();
+        ",
+        "
+        function foo() {
+            if (a) {
+                return 1;
+            } else {
+                throw a;
+            }
+            var b = 5;
+        }
+        ",
+        "
+        switch (a) {
+            case 1:
+                break;
+                foo();
+        }
+        ",
+        "
+        function foo() {
+            try {
+                return 1;
+            } finally {
+                bar();
+            }
+            baz();
+        }
+        ",
+        "
+        () => {
+            return;
+            function foo() {}
+            bar();
+        }
+        "
+    },
+    ok: {
+        "
+        function foo() {
+            return bar();
+            function bar() {}
+        }
+        ",
+        "
+        function foo() {
+            return a;
+            var a, b;
+        }
+        ",
+        "
+        function foo() {
+            if (a) {
+                return 1;
+            }
+            bar();
+        }
+        ",
+        "
+        while (true) {
+            if (a) {
+                break;
+            }
+        }
+        foo();
+        ",
+        "
+        try {
+            throw a;
+        } catch (e) {
+            foo();
+        }
+        bar();
+        ",
+        "
+        outer: for (;;) {
+            for (;;) {
+                break outer;
+            }
+        }
+        foo();
+        ",
+        "
+        function foo() {
+            return;
+            ;
+        }
+        ",
+        "
+        switch (a) {
+            case 1:
+                break;
+            case 2:
+                foo();
+        }
+        "
+    }
+}
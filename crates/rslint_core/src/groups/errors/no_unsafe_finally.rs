@@ -1,3 +1,4 @@
+use crate::cfg;
 use crate::rule_prelude::*;
 use SyntaxKind::*;
 
@@ -54,24 +55,30 @@ pub const CONTROL_FLOW_STMT: [SyntaxKind; 4] = [BREAK_STMT, CONTINUE_STMT, THROW
 #[typetag::serde]
 impl CstRule for NoUnsafeFinally {
     fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
-        if CONTROL_FLOW_STMT.contains(&node.kind())
-            && node.parent()?.parent()?.is::<ast::Finalizer>()
-        {
-            self.output(node, ctx);
+        if !CONTROL_FLOW_STMT.contains(&node.kind()) {
+            return None;
+        }
+        let finalizer = node
+            .ancestors()
+            .take_while(|ancestor| !cfg::is_cfg_owner(ancestor.kind()))
+            .find(|ancestor| ancestor.kind() == FINALIZER)?;
+        let cfg = ctx.cfg(&cfg::owner(node)?)?;
+
+        // control flow which stays inside of the finally block such as breaking out of a loop is fine.
+        // we only report statements in finally blocks which always override the paused control flow.
+        let leaves_finalizer = cfg.jump_target(node).map_or(true, |target| {
+            !finalizer.text_range().contains_range(target.text_range())
+        });
+        if leaves_finalizer && !cfg.completes_normally(&finalizer) {
+            self.output(node, finalizer, ctx);
         }
         None
     }
 }
 
 impl NoUnsafeFinally {
-    fn output(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
-        let parent = if node.parent()?.kind() == FINALIZER {
-            node.parent()?
-        } else {
-            node.parent()?.parent()?
-        };
-
-        let try_stmt = parent.parent()?.to::<ast::TryStmt>();
+    fn output(&self, node: &SyntaxNode, finalizer: SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let try_stmt = finalizer.parent()?.to::<ast::TryStmt>();
 
         let err = if let Some(control) = try_stmt
             .test()?
@@ -160,6 +167,19 @@ rule_tests! {
                 return 5;
             }
         }
+        ",
+        "
+        function foo() {
+            try {
+                return 1;
+            } finally {
+                if (bar) {
+                    return 2;
+                } else {
+                    throw new Error();
+                }
+            }
+        }
         "
     },
     ok: {
@@ -171,6 +191,17 @@ rule_tests! {
                 return true;
             }
         }
+        ",
+        "
+        function foo() {
+            try {
+                return 1;
+            } finally {
+                for (let i of bar) {
+                    break;
+                }
+            }
+        }
         "
     }
 }
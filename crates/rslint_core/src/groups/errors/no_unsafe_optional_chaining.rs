@@ -0,0 +1,362 @@
+use crate::rule_prelude::*;
+use ast::{AssignOp, BinOp, Expr, UnaryOp};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow using optional chains where `undefined` is not allowed.
+
+    An optional chain such as `a?.b` short-circuits to `undefined` if `a` is `null` or `undefined`.
+    Using the result somewhere `undefined` is not allowed throws a `TypeError` at runtime, for example
+    calling it, accessing a property of it after the chain was ended with parentheses, spreading it
+    into an array, destructuring it, or iterating over it. Arithmetic on a chain which short-circuits
+    does not throw, but silently results in `NaN`.
+
+    Chains are also checked through expressions which may result in them, such as `(a && b?.c)()`.
+
+    ## Incorrect Code Examples
+
+    ```js
+    (obj?.foo)();
+    ```
+
+    ```js
+    (obj?.foo).bar;
+    ```
+
+    ```js
+    new (obj?.foo)();
+    ```
+
+    ```js
+    [...obj?.foo];
+    ```
+
+    ```js
+    const { bar } = obj?.foo;
+    ```
+
+    ```js
+    for (const bar of obj?.foo) {}
+    ```
+
+    ```js
+    obj?.foo + 1;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    obj?.foo();
+    ```
+
+    ```js
+    obj?.foo?.bar;
+    ```
+
+    ```js
+    (obj?.foo ?? bar)();
+    ```
+
+    ```js
+    [...(obj?.foo ?? [])];
+    ```
+    */
+    #[derive(Default)]
+    NoUnsafeOptionalChaining,
+    errors,
+    "no-unsafe-optional-chaining"
+}
+
+/// The way the result of an optional chain is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Usage {
+    Call,
+    New,
+    Access,
+    Tag,
+    Spread,
+    Destructure,
+    Iterate,
+    In,
+    Instanceof,
+    Extends,
+    With,
+    Arithmetic,
+}
+
+impl Usage {
+    fn note(self) -> &'static str {
+        match self {
+            Usage::Call => "calling `undefined` throws a `TypeError`",
+            Usage::New => "constructing `undefined` with `new` throws a `TypeError`",
+            Usage::Access => "accessing a property of `undefined` throws a `TypeError`",
+            Usage::Tag => "using `undefined` as a template tag throws a `TypeError`",
+            Usage::Spread => "spreading `undefined` throws a `TypeError`",
+            Usage::Destructure => "destructuring `undefined` throws a `TypeError`",
+            Usage::Iterate => "iterating over `undefined` throws a `TypeError`",
+            Usage::In => "checking if a key is `in` `undefined` throws a `TypeError`",
+            Usage::Instanceof => {
+                "using `undefined` on the right of `instanceof` throws a `TypeError`"
+            }
+            Usage::Extends => "extending `undefined` throws a `TypeError`",
+            Usage::With => {
+                "using `undefined` as the object of a `with` statement throws a `TypeError`"
+            }
+            Usage::Arithmetic => "arithmetic on `undefined` does not throw, but results in `NaN`",
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for NoUnsafeOptionalChaining {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let mut chains = vec![];
+        for (expr, usage) in checked_exprs(node) {
+            let mut found = vec![];
+            short_circuiting_chains(&expr, &mut found);
+            chains.extend(found.into_iter().map(|chain| (chain, usage)));
+        }
+
+        for (chain, usage) in chains {
+            let err = ctx
+                .err(
+                    self.name(),
+                    "unsafe usage of an optional chain which can short-circuit to `undefined`",
+                )
+                .primary(
+                    chain.syntax().trimmed_range(),
+                    "this is `undefined` if the chain short-circuits",
+                )
+                .footer_note(usage.note());
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+/// The expressions used by a node where `undefined` is not allowed.
+fn checked_exprs(node: &SyntaxNode) -> Vec<(Expr, Usage)> {
+    let grouped = |expr: Option<Expr>, usage| match expr {
+        Some(expr @ Expr::GroupingExpr(_)) => vec![(expr, usage)],
+        _ => vec![],
+    };
+    let used = |expr: Option<Expr>, usage| expr.map(|expr| (expr, usage)).into_iter().collect();
+
+    // chains are only ended by parentheses, `a?.b()` or `a?.b.c` are part of the chain
+    match node.kind() {
+        CALL_EXPR => {
+            let call = node.to::<ast::CallExpr>();
+            if call.opt_chain_token().is_some() {
+                return vec![];
+            }
+            grouped(call.callee(), Usage::Call)
+        }
+        DOT_EXPR => {
+            let expr = node.to::<ast::DotExpr>();
+            if expr.opt_chain_token().is_some() {
+                return vec![];
+            }
+            grouped(expr.object(), Usage::Access)
+        }
+        BRACKET_EXPR => {
+            let expr = node.to::<ast::BracketExpr>();
+            if expr.opt_chain_token().is_some() {
+                return vec![];
+            }
+            grouped(expr.object(), Usage::Access)
+        }
+        NEW_EXPR => grouped(node.to::<ast::NewExpr>().object(), Usage::New),
+        TEMPLATE => grouped(node.to::<ast::Template>().tag(), Usage::Tag),
+        SPREAD_ELEMENT => used(node.to::<ast::SpreadElement>().element(), Usage::Spread),
+        DECLARATOR => {
+            let declarator = node.to::<ast::Declarator>();
+            if is_destructuring(declarator.pattern().map(|pattern| pattern.syntax().clone())) {
+                used(declarator.value(), Usage::Destructure)
+            } else {
+                vec![]
+            }
+        }
+        ASSIGN_PATTERN => {
+            let pattern = node.to::<ast::AssignPattern>();
+            // the parser currently flattens `{ a } = b` in parameters into the assign pattern itself
+            let flattened = node
+                .first_token()
+                .map_or(false, |token| matches!(token.kind(), L_CURLY | L_BRACK));
+            if flattened || is_destructuring(pattern.key().map(|key| key.syntax().clone())) {
+                used(pattern.value(), Usage::Destructure)
+            } else {
+                vec![]
+            }
+        }
+        ASSIGN_EXPR => {
+            let expr = node.to::<ast::AssignExpr>();
+            match expr.op() {
+                Some(AssignOp::Assign)
+                    if is_destructuring(expr.lhs().map(|lhs| lhs.syntax().clone())) =>
+                {
+                    used(expr.rhs(), Usage::Destructure)
+                }
+                Some(AssignOp::AddAssign)
+                | Some(AssignOp::SubtractAssign)
+                | Some(AssignOp::TimesAssign)
+                | Some(AssignOp::DivideAssign)
+                | Some(AssignOp::RemainderAssign)
+                | Some(AssignOp::ExponentAssign) => used(expr.rhs(), Usage::Arithmetic),
+                _ => vec![],
+            }
+        }
+        FOR_OF_STMT => used(node.to::<ast::ForOfStmt>().right(), Usage::Iterate),
+        BIN_EXPR => {
+            let expr = node.to::<ast::BinExpr>();
+            match expr.op() {
+                Some(BinOp::In) => used(expr.rhs(), Usage::In),
+                Some(BinOp::Instanceof) => used(expr.rhs(), Usage::Instanceof),
+                Some(BinOp::Plus)
+                | Some(BinOp::Minus)
+                | Some(BinOp::Times)
+                | Some(BinOp::Divide)
+                | Some(BinOp::Remainder)
+                | Some(BinOp::Exponent) => {
+                    let mut exprs = used(expr.lhs(), Usage::Arithmetic);
+                    exprs.extend(used(expr.rhs(), Usage::Arithmetic));
+                    exprs
+                }
+                _ => vec![],
+            }
+        }
+        UNARY_EXPR => {
+            let expr = node.to::<ast::UnaryExpr>();
+            match expr.op() {
+                Some(UnaryOp::Plus) | Some(UnaryOp::Minus) => used(expr.expr(), Usage::Arithmetic),
+                _ => vec![],
+            }
+        }
+        CLASS_DECL => used(node.to::<ast::ClassDecl>().parent(), Usage::Extends),
+        CLASS_EXPR => used(node.to::<ast::ClassExpr>().parent(), Usage::Extends),
+        WITH_STMT => used(
+            node.to::<ast::WithStmt>()
+                .condition()
+                .and_then(|condition| condition.condition()),
+            Usage::With,
+        ),
+        _ => vec![],
+    }
+}
+
+fn is_destructuring(pattern: Option<SyntaxNode>) -> bool {
+    pattern.map_or(false, |pattern| {
+        matches!(pattern.kind(), ARRAY_PATTERN | OBJECT_PATTERN)
+    })
+}
+
+/// Collect the optional chains an expression may result in if they short-circuit.
+fn short_circuiting_chains(expr: &Expr, chains: &mut Vec<Expr>) {
+    match expr {
+        Expr::GroupingExpr(group) => {
+            if let Some(inner) = group.inner() {
+                short_circuiting_chains(&inner, chains);
+            }
+        }
+        Expr::BinExpr(bin) => match bin.op() {
+            // `a && b?.c` is `undefined` if either `a` or `b?.c` short-circuits to `undefined`
+            Some(BinOp::LogicalAnd) => {
+                for operand in bin.lhs().into_iter().chain(bin.rhs()) {
+                    short_circuiting_chains(&operand, chains);
+                }
+            }
+            Some(BinOp::LogicalOr) | Some(BinOp::NullishCoalescing) => {
+                if let Some(rhs) = bin.rhs() {
+                    short_circuiting_chains(&rhs, chains);
+                }
+            }
+            _ => {}
+        },
+        Expr::CondExpr(cond) => {
+            for branch in cond.cons().into_iter().chain(cond.alt()) {
+                short_circuiting_chains(&branch, chains);
+            }
+        }
+        Expr::SequenceExpr(sequence) => {
+            if let Some(last) = sequence.exprs().last() {
+                short_circuiting_chains(&last, chains);
+            }
+        }
+        Expr::AwaitExpr(await_expr) => {
+            if let Some(inner) = await_expr.expr() {
+                short_circuiting_chains(&inner, chains);
+            }
+        }
+        _ if is_optional_chain(expr) => chains.push(expr.clone()),
+        _ => {}
+    }
+}
+
+/// Whether an expression is a chain of member accesses and calls which contains `?.`.
+fn is_optional_chain(expr: &Expr) -> bool {
+    if expr.opt_chain() {
+        return true;
+    }
+    let inner = match expr {
+        Expr::DotExpr(dot) => dot.object(),
+        Expr::BracketExpr(bracket) => bracket.object(),
+        Expr::CallExpr(call) => call.callee(),
+        _ => None,
+    };
+    inner.map_or(false, |inner| is_optional_chain(&inner))
+}
+
+rule_tests! {
+    NoUnsafeOptionalChaining::default(),
+    err: {
+        "(obj?.foo)();",
+        "(obj?.foo.bar)();",
+        "(obj.foo?.())();",
+        "(obj?.foo).bar;",
+        "(obj?.foo)[1];",
+        "new (obj?.foo)();",
+        "(obj?.foo)`template`;",
+        "[...obj?.foo];",
+        "bar(...obj?.foo);",
+        "const { bar } = obj?.foo;",
+        "const [bar] = obj?.foo;",
+        "[bar] = obj?.foo;",
+        "({ bar } = obj?.foo);",
+        "function f({ bar } = obj?.foo) {}",
+        "const f = ([bar] = obj?.foo) => bar;",
+        "for (const bar of obj?.foo) {}",
+        "'bar' in obj?.foo;",
+        "bar instanceof obj?.foo;",
+        "class A extends obj?.foo {}",
+        "(a && obj?.foo)();",
+        "(obj?.foo && bar)();",
+        "(a || obj?.foo)();",
+        "(a ?? obj?.foo).bar;",
+        "(a ? obj?.foo : bar)();",
+        "(a, obj?.foo)();",
+        "async function f() { (await obj?.foo)(); }",
+        "obj?.foo + 1;",
+        "1 - obj?.foo;",
+        "-obj?.foo;",
+        "a += obj?.foo;"
+    },
+    ok: {
+        "obj?.foo();",
+        "obj?.foo.bar;",
+        "obj?.foo?.bar;",
+        "(obj?.foo)?.();",
+        "(obj?.foo)?.bar;",
+        "obj.foo();",
+        "(obj?.foo ?? bar)();",
+        "(obj?.foo || bar)();",
+        "[...(obj?.foo ?? [])];",
+        "({ ...obj?.foo });",
+        "const bar = obj?.foo;",
+        "function f(bar = obj?.foo) {}",
+        "const { bar } = obj.foo;",
+        "for (const bar in obj?.foo) {}",
+        "obj?.foo === 1;",
+        "`${obj?.foo}`;",
+        "a = obj?.foo;"
+    }
+}
@@ -0,0 +1,591 @@
+use crate::rule_prelude::*;
+use crate::semantic::{Binding, BindingKind, ReferenceKind, SemanticModel};
+use regex::Regex;
+use rslint_lexer::is_linebreak;
+use rslint_parser::{TextRange, TextSize};
+use std::collections::HashMap;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow variables, functions, parameters, and imports which are declared but never used.
+
+    Unused declarations are most likely leftovers from refactoring, or a sign of a typo in a name
+    which was meant to use the declaration. They clutter the code and make it harder to read.
+
+    A variable is only counted as used if its value is read, assigning to a variable, or only using it to update
+    itself such as `a++` or `a += 1` does not count as a use. `typeof a` reads the variable and is counted as a use.
+    Exported declarations are always used.
+
+    Parameters are only reported if they come after the last used parameter, since earlier parameters
+    cannot be removed without changing the position of the ones which are used.
+
+    Unused imports are automatically removed, either by removing the single specifier or the entire import.
+
+    ## Incorrect Code Examples
+
+    ```js
+    let a = 5;
+    a = 6;
+    ```
+
+    ```js
+    function foo(a, b) {
+        return a;
+    }
+    ```
+
+    ```js
+    import { foo, bar } from "./foo.js";
+
+    bar();
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let a = 5;
+    console.log(a);
+    ```
+
+    ```js
+    // `a` is unused, but `b` is used, therefore `a` is not reported.
+    function foo(a, b) {
+        return b;
+    }
+    ```
+
+    ```js
+    export function foo() {}
+    ```
+    */
+    #[serde(default)]
+    NoUnusedVars,
+    errors,
+    "no-unused-vars",
+    /// A regular expression for variable names which should not be reported even if unused,
+    /// for example `^_` allows any variable starting with an underscore.
+    #[serde(deserialize_with = "deserialize_pattern")]
+    pub vars_ignore_pattern: Option<String>,
+    /// A regular expression for parameter names which should not be reported even if unused.
+    #[serde(deserialize_with = "deserialize_pattern")]
+    pub args_ignore_pattern: Option<String>,
+    /// Whether to report unused errors in catch clauses, either "all" or "none" ("none" by default).
+    pub caught_errors: String,
+    /// Whether to ignore unused variables declared next to a rest property in object destructuring,
+    /// e.g. `bar` in `let { bar, ...rest } = foo`, since `bar` is only declared to leave it out of `rest` (false by default).
+    pub ignore_rest_siblings: bool
+}
+
+impl Default for NoUnusedVars {
+    fn default() -> Self {
+        Self {
+            vars_ignore_pattern: None,
+            args_ignore_pattern: None,
+            caught_errors: "none".to_string(),
+            ignore_rest_siblings: false,
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for NoUnusedVars {
+    fn check_root(&self, _root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let model = ctx.semantic.clone();
+        // patterns from configs are already validated when they are deserialized
+        let pattern = |pat: &Option<String>| pat.as_ref().and_then(|pat| Regex::new(pat).ok());
+        let vars_pattern = pattern(&self.vars_ignore_pattern);
+        let args_pattern = pattern(&self.args_ignore_pattern);
+
+        let used = model
+            .bindings()
+            .iter()
+            .map(|binding| is_used(binding, &model))
+            .collect::<Vec<_>>();
+
+        // the position of the last used parameter of every parameter list
+        let mut last_used_params: HashMap<TextRange, usize> = HashMap::new();
+        for binding in model.bindings().iter().filter(|b| used[b.id]) {
+            if let Some(idx) = param_index(binding) {
+                let last = last_used_params
+                    .entry(binding.decl.text_range())
+                    .or_insert(idx);
+                *last = idx.max(*last);
+            }
+        }
+
+        let mut unused_imports = vec![];
+        for binding in model.bindings() {
+            if used[binding.id] || matches!(binding.decl.kind(), FN_EXPR | CLASS_EXPR) {
+                continue;
+            }
+            let ignore_pattern = match binding.kind {
+                BindingKind::Param => {
+                    let is_before_used = param_index(binding).map_or(false, |idx| {
+                        last_used_params
+                            .get(&binding.decl.text_range())
+                            .map_or(false, |last| idx < *last)
+                    });
+                    let is_ts_property = binding
+                        .name_node()
+                        .ancestors()
+                        .any(|node| node.kind() == TS_CONSTRUCTOR_PARAM);
+                    if is_before_used || is_ts_property {
+                        continue;
+                    }
+                    &args_pattern
+                }
+                BindingKind::CatchParam if self.caught_errors != "all" => continue,
+                BindingKind::CatchParam => &None,
+                _ => &vars_pattern,
+            };
+            if ignore_pattern
+                .as_ref()
+                .map_or(false, |pat| pat.is_match(&binding.name))
+                || self.ignore_rest_siblings && has_rest_sibling(binding.name_node())
+            {
+                continue;
+            }
+
+            if binding.kind == BindingKind::Import {
+                unused_imports.push(binding.name_node().clone());
+            }
+            self.output(binding, &model, ctx);
+        }
+
+        if !unused_imports.is_empty() {
+            let ranges = import_deletions(&unused_imports, &ctx.src);
+            ctx.fix().delete_multiple(ranges);
+        }
+        None
+    }
+}
+
+impl NoUnusedVars {
+    fn output(&self, binding: &Binding, model: &SemanticModel, ctx: &mut RuleCtx) {
+        let (kind, verb) = match binding.kind {
+            BindingKind::Var | BindingKind::Let | BindingKind::Const => ("variable", "declared"),
+            BindingKind::Function => ("function", "declared"),
+            BindingKind::Class => ("class", "declared"),
+            BindingKind::Import => ("import", "imported"),
+            BindingKind::Param => ("parameter", "declared"),
+            BindingKind::CatchParam => ("caught error", "declared"),
+            BindingKind::Enum => ("enum", "declared"),
+            BindingKind::Namespace => ("namespace", "declared"),
+        };
+        let assigned = model.writes(binding.id).next().is_some()
+            && !matches!(
+                binding.kind,
+                BindingKind::Function | BindingKind::Class | BindingKind::Import
+            );
+
+        let msg = if assigned {
+            format!("`{}` is assigned a value but never used", binding.name)
+        } else {
+            format!("`{}` is {} but never used", binding.name, verb)
+        };
+        let mut err = ctx
+            .err(self.name(), msg)
+            .primary(binding.range(), format!("this {} is never used", kind));
+
+        for write in model
+            .writes(binding.id)
+            .filter(|write| !write.init && write.kind == ReferenceKind::Write)
+        {
+            err = err.secondary(write.node.text_range(), "a value is assigned here");
+        }
+        ctx.add_err(err);
+    }
+}
+
+/// Deserialize an ignore pattern, invalid regexes are reported as errors in the config.
+fn deserialize_pattern<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    let pattern = Option::<String>::deserialize(deserializer)?;
+    if let Some(pattern) = &pattern {
+        if let Err(err) = Regex::new(pattern) {
+            return Err(serde::de::Error::custom(format!(
+                "invalid regex `{}`: {}",
+                pattern, err
+            )));
+        }
+    }
+    Ok(pattern)
+}
+
+fn is_used(binding: &Binding, model: &SemanticModel) -> bool {
+    binding.exported
+        || model.reads(binding.id).any(|reference| {
+            // `a++` and `a += 1` as statements only use the variable to update itself
+            let updates_itself = reference.kind == ReferenceKind::ReadWrite
+                && reference
+                    .node
+                    .ancestors()
+                    .skip(1)
+                    .find(|node| node.kind() != GROUPING_EXPR)
+                    .and_then(|expr| expr.parent())
+                    .map_or(false, |parent| {
+                        matches!(parent.kind(), EXPR_STMT | FOR_STMT_UPDATE)
+                    });
+            // recursive functions and classes which refer to themselves are not used by that
+            let refers_to_itself =
+                matches!(binding.kind, BindingKind::Function | BindingKind::Class)
+                    && binding
+                        .decl
+                        .text_range()
+                        .contains_range(reference.node.text_range());
+            !updates_itself && !refers_to_itself
+        })
+}
+
+/// The position of a parameter in its function's parameter list.
+fn param_index(binding: &Binding) -> Option<usize> {
+    if binding.kind != BindingKind::Param {
+        return None;
+    }
+    if binding.decl.kind() == ARROW_EXPR {
+        return Some(0);
+    }
+    let param = binding
+        .name_node()
+        .ancestors()
+        .find(|node| node.parent().as_ref() == Some(&binding.decl))?;
+    binding.decl.children().position(|child| child == param)
+}
+
+fn has_rest_sibling(name: &SyntaxNode) -> bool {
+    name.ancestors()
+        .find(|node| {
+            node.parent()
+                .map_or(false, |parent| parent.kind() == OBJECT_PATTERN)
+        })
+        .map_or(false, |prop| {
+            prop.kind() != REST_PATTERN
+                && prop
+                    .parent()
+                    .unwrap()
+                    .children()
+                    .any(|sibling| sibling.kind() == REST_PATTERN)
+        })
+}
+
+/// Get the ranges which have to be deleted to remove unused import names, this removes the entire import
+/// if none of its names are used.
+fn import_deletions(unused: &[SyntaxNode], src: &str) -> Vec<TextRange> {
+    let is_unused = |name: Option<SyntaxNode>| name.map_or(false, |name| unused.contains(&name));
+    let is_unused_specifier = |specifier: &SyntaxNode| {
+        is_unused(specifier.children().filter(|c| c.kind() == NAME).last())
+    };
+
+    let mut decls = unused
+        .iter()
+        .filter_map(|name| name.ancestors().find(|node| node.kind() == IMPORT_DECL))
+        .collect::<Vec<_>>();
+    decls.dedup();
+
+    let mut ranges = vec![];
+    let mut removed_decls: Vec<TextRange> = vec![];
+    for decl in decls {
+        let items = decl
+            .children()
+            .filter(|child| matches!(child.kind(), NAME | NAMED_IMPORTS | WILDCARD_IMPORT))
+            .collect::<Vec<_>>();
+        let removed = items
+            .iter()
+            .map(|item| match item.kind() {
+                NAME => is_unused(Some(item.clone())),
+                WILDCARD_IMPORT => is_unused(item.child_with_kind(NAME)),
+                _ => item
+                    .children()
+                    .filter(|child| child.kind() == SPECIFIER)
+                    .all(|specifier| is_unused_specifier(&specifier)),
+            })
+            .collect::<Vec<_>>();
+
+        if removed.iter().all(|removed| *removed) {
+            removed_decls.push(decl.text_range());
+            continue;
+        }
+        delete_runs(&items, &removed, &mut ranges);
+
+        for named in items
+            .iter()
+            .zip(removed)
+            .filter(|(item, removed)| item.kind() == NAMED_IMPORTS && !removed)
+        {
+            let specifiers = named
+                .0
+                .children()
+                .filter(|child| child.kind() == SPECIFIER)
+                .collect::<Vec<_>>();
+            let removed = specifiers
+                .iter()
+                .map(is_unused_specifier)
+                .collect::<Vec<_>>();
+            delete_runs(&specifiers, &removed, &mut ranges);
+        }
+    }
+
+    // imports only separated by spaces are removed together so the whitespace around them does not overlap,
+    // e.g. `import a from "a"; import b from "b";`
+    removed_decls.sort_by_key(|range| range.start());
+    let mut merged: Vec<TextRange> = vec![];
+    for range in removed_decls {
+        match merged.last_mut() {
+            Some(last)
+                if src[last.end().into()..range.start().into()]
+                    .chars()
+                    .all(|c| c.is_whitespace() && !is_linebreak(c)) =>
+            {
+                *last = last.cover(range)
+            }
+            _ => merged.push(range),
+        }
+    }
+    ranges.extend(merged.into_iter().map(|range| decl_deletion(range, src)));
+    ranges
+}
+
+/// The range to delete to remove entire imports, this includes the whitespace around them,
+/// and the line they are on if nothing else is on that line.
+fn decl_deletion(range: TextRange, src: &str) -> TextRange {
+    let (mut start, mut end) = (usize::from(range.start()), usize::from(range.end()));
+    let is_space = |c: char| c.is_whitespace() && !is_linebreak(c);
+
+    let after = src[end..].trim_start_matches(is_space);
+    end = src.len() - after.len();
+    if !after.chars().next().map_or(true, is_linebreak) {
+        // other code follows on the same line, e.g. `import a from "a"; foo();`
+        return TextRange::new(range.start(), TextSize::from(end as u32));
+    }
+
+    let before = src[..start].trim_end_matches(is_space);
+    start = before.len();
+    if before.chars().next_back().map_or(true, is_linebreak) {
+        // nothing else is on the line, so the line and its linebreak are removed
+        end += if after.starts_with("\r\n") {
+            2
+        } else {
+            after.chars().next().map_or(0, char::len_utf8)
+        };
+    }
+    TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32))
+}
+
+/// Delete every run of removed items in a comma separated list along with the commas separating them.
+/// At least one item in the list must be kept.
+fn delete_runs(items: &[SyntaxNode], removed: &[bool], ranges: &mut Vec<TextRange>) {
+    let mut idx = 0;
+    while idx < items.len() {
+        if !removed[idx] {
+            idx += 1;
+            continue;
+        }
+        let start = idx;
+        while idx < items.len() && removed[idx] {
+            idx += 1;
+        }
+        // remove up to the next kept item, or from the end of the previous kept item if this is the end of the list.
+        let range = if let Some(next) = items.get(idx) {
+            TextRange::new(items[start].text_range().start(), next.text_range().start())
+        } else {
+            TextRange::new(
+                items[start - 1].text_range().end(),
+                items[idx - 1].text_range().end(),
+            )
+        };
+        ranges.push(range);
+    }
+}
+
+rule_tests! {
+    NoUnusedVars::default(),
+    err: {
+        "let a = 5;",
+        "
+        let a = 5;
+        a = 6;
+        ",
+        "
+        let a = 5;
+        a++;
+        ",
+        "
+        let a = 5;
+        a += 1;
+        ",
+        "function foo() {}",
+        "
+        function foo() {
+            foo();
+        }
+        ",
+        "
+        function foo(a, b) {
+            return a;
+        }
+        foo();
+        ",
+        "
+        const { a, ...rest } = obj;
+        rest();
+        ",
+        "import foo from 'foo';",
+        "
+        import { foo, bar } from 'foo';
+        bar();
+        ",
+        "
+        import foo, * as bar from 'foo';
+        foo();
+        ",
+        "
+        for (const key in obj) {}
+        ",
+        "class Foo {}"
+    },
+    ok: {
+        "
+        let a = 5;
+        console.log(a);
+        ",
+        "
+        let a = 5;
+        let b = a++;
+        b();
+        ",
+        "
+        var a;
+        if (typeof a === 'undefined') {}
+        ",
+        "export function foo() {}",
+        "export const foo = 5;",
+        "
+        const foo = 5;
+        export { foo };
+        ",
+        "
+        const foo = 5;
+        export default foo;
+        ",
+        "
+        function foo(a, b) {
+            return b;
+        }
+        foo();
+        ",
+        "
+        try {} catch (e) {}
+        ",
+        "
+        import { foo } from 'foo';
+        foo();
+        ",
+        "
+        let foo = function bar() {};
+        foo();
+        ",
+        "
+        export class Foo {
+            constructor(a) {
+                this.a = a;
+            }
+        }
+        "
+    }
+}
+
+rule_tests! {
+    ignore_patterns_valid,
+    ignore_patterns_invalid,
+    NoUnusedVars {
+        vars_ignore_pattern: Some("^_".to_string()),
+        args_ignore_pattern: Some("^ignored".to_string()),
+        caught_errors: "all".to_string(),
+        ignore_rest_siblings: true,
+    },
+    err: {
+        "try {} catch (e) {}",
+        "
+        function foo(a, b) {}
+        foo();
+        "
+    },
+    ok: {
+        "let _a = 5;",
+        "
+        function foo(a, ignoredB) {
+            return a;
+        }
+        foo();
+        ",
+        "
+        const { a, ...rest } = obj;
+        rest();
+        "
+    }
+}
+
+rule_tests! {
+    typescript,
+    typescript_valid,
+    typescript_invalid,
+    NoUnusedVars::default(),
+    err: {
+        "
+        import { Foo } from './foo';
+        let a: number = 5;
+        a();
+        "
+    },
+    ok: {
+        "
+        import { Foo } from './foo';
+        export let a: Foo = null;
+        ",
+        "
+        import { Foo } from './foo';
+        export function foo(a: Foo.Bar<string>): typeof Foo {
+            return a;
+        }
+        ",
+        "
+        import { Foo } from './foo';
+        export class Bar implements Foo {}
+        ",
+        "
+        import { Foo } from './foo';
+        export interface Bar extends Foo {}
+        ",
+        "
+        import { Foo } from './foo';
+        export const a = {} as Foo;
+        "
+    }
+}
+
+fix_tests! {
+    typescript,
+    typescript_fixes,
+    NoUnusedVars::default(),
+    "import { Foo } from \"./foo\";\nexport let a: Foo = null;" => "import { Foo } from \"./foo\";\nexport let a: Foo = null;",
+    "import { Foo, Bar } from \"./foo\";\nexport let a: Foo = null;" => "import { Foo } from \"./foo\";\nexport let a: Foo = null;"
+}
+
+fix_tests! {
+    NoUnusedVars::default(),
+    "import { b } from \"x\"; export {};" => "export {};",
+    "import a from \"a\";\nexport {};" => "export {};",
+    "import a from \"a\";\r\nexport {};" => "export {};",
+    "    import a from \"a\";\n    export {};" => "    export {};",
+    "import a from \"a\"; import b from \"b\";\nexport {};" => "export {};",
+    "foo(); import a from \"a\";\nbar();" => "foo();\nbar();",
+    "import a from \"a\"; /* foo */\nexport {};" => "/* foo */\nexport {};",
+    "import { a, b } from \"x\";\nb();" => "import { b } from \"x\";\nb();",
+    "import { a, b } from \"x\";\na();" => "import { a } from \"x\";\na();",
+    "import { a, b, c } from \"x\";\nb();" => "import { b } from \"x\";\nb();",
+    "import a, { b } from \"x\";\nb();" => "import { b } from \"x\";\nb();",
+    "import a, * as b from \"x\";\na();" => "import a from \"x\";\na();",
+    "import a from \"a\";\na();" => "import a from \"a\";\na();"
+}
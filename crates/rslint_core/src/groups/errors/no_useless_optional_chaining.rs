@@ -0,0 +1,179 @@
+use crate::rule_prelude::*;
+use ast::Expr;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow optional chaining on values which can never be `null` or `undefined`.
+
+    `a?.b` only differs from `a.b` if `a` is `null` or `undefined`. Using `?.` on a value which can never
+    be nullish, such as an object or array literal, a function, a class, or the result of `new`, is
+    useless and misleading, since it suggests the value might not exist.
+
+    `this` is only reported outside of strict mode code, in strict mode `this` is `undefined` in functions
+    which are called without an object, and at the top level of modules.
+
+    ## Incorrect Code Examples
+
+    ```js
+    ({ a: 1 })?.a;
+    ```
+
+    ```js
+    [1, 2, 3]?.[0];
+    ```
+
+    ```js
+    new Foo()?.bar();
+    ```
+
+    ```js
+    (() => 5)?.();
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    ({ a: 1 }).a;
+    ```
+
+    ```js
+    foo?.bar;
+    ```
+
+    ```js
+    foo.bar?.baz;
+    ```
+    */
+    #[derive(Default)]
+    NoUselessOptionalChaining,
+    errors,
+    "no-useless-optional-chaining"
+}
+
+#[typetag::serde]
+impl CstRule for NoUselessOptionalChaining {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let (token, object) = match node.kind() {
+            DOT_EXPR => {
+                let expr = node.to::<ast::DotExpr>();
+                (expr.opt_chain_token()?, expr.object()?)
+            }
+            BRACKET_EXPR => {
+                let expr = node.to::<ast::BracketExpr>();
+                (expr.opt_chain_token()?, expr.object()?)
+            }
+            CALL_EXPR => {
+                let expr = node.to::<ast::CallExpr>();
+                (expr.opt_chain_token()?, expr.callee()?)
+            }
+            _ => return None,
+        };
+        let kind = never_nullish(&object)?;
+
+        // `a?.b` becomes `a.b`, while `a?.[b]` and `a?.()` become `a[b]` and `a()`
+        let replacement = if node.kind() == DOT_EXPR { "." } else { "" };
+        ctx.extend_fix().replace(token.text_range(), replacement);
+        let err = ctx
+            .err(
+                self.name(),
+                format!("useless optional chain on {} which is never nullish", kind),
+            )
+            .secondary(
+                object.syntax().trimmed_range(),
+                format!("this is {}, it cannot be `null` or `undefined`", kind),
+            )
+            .primary(token.text_range(), "so this `?.` never short-circuits")
+            .suggestion(
+                token.text_range(),
+                "remove the optional chaining",
+                replacement,
+                Applicability::Always,
+            );
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// A description of an expression which can never be `null` or `undefined`.
+fn never_nullish(expr: &Expr) -> Option<&'static str> {
+    Some(match expr {
+        Expr::GroupingExpr(group) => return never_nullish(&group.inner()?),
+        Expr::ObjectExpr(_) => "an object literal",
+        Expr::ArrayExpr(_) => "an array literal",
+        Expr::FnExpr(_) | Expr::ArrowExpr(_) => "a function",
+        Expr::ClassExpr(_) => "a class",
+        Expr::NewExpr(_) => "the result of `new`",
+        Expr::Template(template) if template.tag().is_none() => "a template literal",
+        Expr::ThisExpr(this) if !util::is_strict(this.syntax()) => "`this` in sloppy mode",
+        // `1?.toString()` cannot be fixed to `1.toString()`
+        Expr::Literal(literal)
+            if matches!(literal.token().kind(), STRING | REGEX | TRUE_KW | FALSE_KW) =>
+        {
+            "a literal"
+        }
+        _ => return None,
+    })
+}
+
+rule_tests! {
+    NoUselessOptionalChaining::default(),
+    err: {
+        "({ a: 1 })?.a;",
+        "({})?.a.b;",
+        "[1, 2, 3]?.[0];",
+        "[]?.length;",
+        "new Foo()?.bar();",
+        "(() => 5)?.();",
+        "(function () {})?.call(this);",
+        "(class {})?.name;",
+        "`foo`?.length;",
+        "'foo'?.length;",
+        "/a/?.test(b);"
+    },
+    ok: {
+        "foo?.bar;",
+        "foo.bar?.baz;",
+        "foo?.();",
+        "null?.foo;",
+        "(1)?.toString();",
+        "this?.foo;",
+        "class A { foo() { return this?.bar; } }",
+        "tag`foo`?.length;",
+        "({}).a;"
+    }
+}
+
+rule_tests! {
+    script,
+    sloppy_valid,
+    sloppy_invalid,
+    NoUselessOptionalChaining::default(),
+    err: {
+        "this?.foo;",
+        "function foo() { return this?.bar; }"
+    },
+    ok: {
+        "'use strict'; this?.foo;",
+        "function foo() { 'use strict'; return this?.bar; }",
+        "class A { foo() { return this?.bar; } }"
+    }
+}
+
+fix_tests! {
+    NoUselessOptionalChaining::default(),
+    "({ a: 1 })?.a;" => "({ a: 1 }).a;",
+    "({})?.a.b;" => "({}).a.b;",
+    "[1, 2, 3]?.[0];" => "[1, 2, 3][0];",
+    "(() => 5)?.();" => "(() => 5)();",
+    "new Foo()?.bar();" => "new Foo().bar();",
+    "'foo'?.length + []?.length;" => "'foo'.length + [].length;",
+    "(1)?.toString();" => "(1)?.toString();"
+}
+
+fix_tests! {
+    script,
+    sloppy_fixes,
+    NoUselessOptionalChaining::default(),
+    "this?.foo;" => "this.foo;"
+}
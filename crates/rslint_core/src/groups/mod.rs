@@ -1,8 +1,12 @@
 //! All of the groups of built in rules in the linter.
 
+pub mod complexity;
 pub mod errors;
+pub mod regex;
 pub mod style;
 
+pub use self::complexity::complexity;
+pub use self::regex::regex;
 pub use errors::errors;
 pub use style::style;
 
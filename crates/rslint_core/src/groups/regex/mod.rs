@@ -0,0 +1,13 @@
+//! Rules which relate to the patterns of regular expressions.
+
+use crate::group;
+
+group! {
+    /// Rules which relate to the patterns of regular expressions, both in regex literals
+    /// and in strings passed to the `RegExp` constructor.
+    regex,
+    no_control_regex::NoControlRegex,
+    no_empty_character_class::NoEmptyCharacterClass,
+    no_regex_spaces::NoRegexSpaces,
+    no_misleading_character_class::NoMisleadingCharacterClass,
+}
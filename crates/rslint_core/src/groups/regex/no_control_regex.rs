@@ -0,0 +1,132 @@
+use crate::rule_prelude::*;
+use rslint_parser::regex::{Char, CharKind, ClassItem, Node};
+
+declare_lint! {
+    /**
+    Disallow control characters in regular expressions.
+
+    Control characters are the invisible ASCII characters from `\x00` to `\x1F`, they are very rarely
+    matched on purpose, so using them in a regex, either literally or as a `\x` or `\u` escape,
+    is most likely a mistake. Escapes such as `\t` or `\n` are allowed.
+
+    ## Incorrect Code Examples
+
+    ```js
+    let pattern = /\x1f/;
+    ```
+
+    ```js
+    let pattern = /\u000C/;
+    ```
+
+    ```js
+    let pattern = new RegExp("\x0C");
+    ```
+
+    ```js
+    let pattern = new RegExp("\\x0C");
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let pattern = /\x20/;
+    ```
+
+    ```js
+    let pattern = /\t\n/;
+    ```
+
+    ```js
+    let pattern = new RegExp("\\t");
+    ```
+    */
+    #[derive(Default)]
+    NoControlRegex,
+    regex,
+    "no-control-regex"
+}
+
+#[typetag::serde]
+impl CstRule for NoControlRegex {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let pattern = util::regex_pattern(node, &ctx.semantic)?;
+        let mut control_chars = vec![];
+        pattern.regex.pattern.walk(&mut |node| match node {
+            Node::Char(c) => control_chars.extend(is_control_char(c)),
+            Node::CharacterClass(class) => {
+                for item in &class.items {
+                    match item {
+                        ClassItem::Char(c) => control_chars.extend(is_control_char(c)),
+                        ClassItem::Range(range) => {
+                            control_chars.extend(is_control_char(&range.min));
+                            control_chars.extend(is_control_char(&range.max));
+                        }
+                        ClassItem::Escape(_) => {}
+                    }
+                }
+            }
+            _ => {}
+        });
+
+        for c in control_chars {
+            let escape = format!("\\x{:02X}", c.value);
+            let err = ctx
+                .err(
+                    self.name(),
+                    format!("unexpected control character `{}` in a regex", escape),
+                )
+                .primary(
+                    pattern.text_range(c.range.clone()),
+                    format!("this matches the control character `{}`", escape),
+                )
+                .footer_note(
+                    "control characters are invisible and are very rarely matched on purpose",
+                );
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+fn is_control_char(c: &Char) -> Option<Char> {
+    let is_reported = c.value <= 0x1F
+        && matches!(
+            c.kind,
+            CharKind::Literal | CharKind::Hex | CharKind::Unicode
+        );
+    if is_reported {
+        Some(c.clone())
+    } else {
+        None
+    }
+}
+
+rule_tests! {
+    NoControlRegex::default(),
+    err: {
+        "/\\x1f/",
+        "/\\x00/",
+        "/\\u000C/",
+        "/\\u{1F}/u",
+        "/[\\x00-\\x1F]/",
+        "/a|(b\\x0c)/",
+        "new RegExp('\\x0C')",
+        "new RegExp('\\\\x0C')",
+        "RegExp('\\\\u001F', 'g')",
+        "/\u{1}/"
+    },
+    ok: {
+        "/\\x20/",
+        "/\\t\\n\\r/",
+        "/\\cJ/",
+        "/\\0/",
+        "new RegExp('\\\\t')",
+        "new RegExp(x)",
+        "new RegExp('\\\\x0C', flags)",
+        "
+        function RegExp() {}
+        RegExp('\\\\x0C');
+        "
+    }
+}
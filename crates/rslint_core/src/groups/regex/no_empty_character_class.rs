@@ -0,0 +1,100 @@
+use crate::rule_prelude::*;
+use rslint_parser::regex::Node;
+
+declare_lint! {
+    /**
+    Disallow empty character classes in regular expressions.
+
+    An empty character class (`[]`) does not match any character, so a regex containing one can never
+    match anything. This is usually a mistake, such as forgetting to escape a `]`.
+    The negated empty class `[^]`, which matches any character, is allowed.
+
+    ## Incorrect Code Examples
+
+    ```js
+    /^abc[]/.test("abcdefg");
+    ```
+
+    ```js
+    "abcdefg".match(/^abc[]/);
+    ```
+
+    ```js
+    new RegExp("a[]b");
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    /^abc/.test("abcdefg");
+    ```
+
+    ```js
+    /^abc[a-z]/.test("abcdefg");
+    ```
+
+    ```js
+    /^abc[^]/.test("abcdefg");
+    ```
+
+    ```js
+    /^abc[\]]/.test("abcdefg");
+    ```
+    */
+    #[derive(Default)]
+    NoEmptyCharacterClass,
+    regex,
+    "no-empty-character-class"
+}
+
+#[typetag::serde]
+impl CstRule for NoEmptyCharacterClass {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let pattern = util::regex_pattern(node, &ctx.semantic)?;
+        let mut empty_classes = vec![];
+        pattern.regex.pattern.walk(&mut |node| {
+            if let Node::CharacterClass(class) = node {
+                if !class.negated && class.items.is_empty() {
+                    empty_classes.push(class.range.clone());
+                }
+            }
+        });
+
+        for range in empty_classes {
+            let err = ctx
+                .err(
+                    self.name(),
+                    "empty character classes in regexes never match anything",
+                )
+                .primary(
+                    pattern.text_range(range),
+                    "this class does not match any character",
+                )
+                .footer_help("if this is meant to match a `]`, escape it like `[\\]]`");
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+rule_tests! {
+    NoEmptyCharacterClass::default(),
+    err: {
+        "/^abc[]/.test('abcdefg');",
+        "'abcdefg'.match(/^abc[]/);",
+        "/[]]/",
+        "/a|([]b)/",
+        "/(?=[])/",
+        "/[]+/u",
+        "new RegExp('a[]b')"
+    },
+    ok: {
+        "/^abc[a-z]/.test('abcdefg');",
+        "/^abc[^]/",
+        "/[\\]]/",
+        "/\\[]/",
+        "/[[]/",
+        "new RegExp('[' + ']')",
+        "var foo = '[]';"
+    }
+}
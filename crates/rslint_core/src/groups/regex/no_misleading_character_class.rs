@@ -0,0 +1,264 @@
+use crate::rule_prelude::*;
+use rslint_parser::regex::{Char, ClassItem, Node};
+use rslint_parser::TextRange;
+use std::ops::{Range, RangeInclusive};
+
+declare_lint! {
+    /**
+    Disallow characters made of multiple code points in character classes.
+
+    A character class matches a single code point (or a single UTF-16 code unit without the `u` flag),
+    characters which are made of multiple code points are split up and every part is matched on its own.
+    For example `/^[A\u0301]$/` does not match `"A\u0301"` (`Á`), since the class matches either `A`
+    or the accent, but not both.
+
+    This rule reports:
+     - characters outside of the BMP, such as emojis, without the `u` flag, they are two separate code units
+     - characters followed by combining marks, such as `Á` written as `A\u0301`
+     - emojis followed by a skin tone modifier, such as `👶🏻`
+     - pairs of regional indicators, which are flags such as `🇯🇵`
+     - characters joined with a zero width joiner, such as `👨‍👩‍👦`
+
+    ## Incorrect Code Examples
+
+    ```js
+    /^[A\u0301]$/u;
+    ```
+
+    ```js
+    /^[❇️]$/u;
+    ```
+
+    ```js
+    /^[👶🏻]$/u;
+    ```
+
+    ```js
+    /^[🇯🇵]$/u;
+    ```
+
+    ```js
+    /^[👨‍👩‍👦]$/u;
+    ```
+
+    ```js
+    /^[👍]$/;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    /^[abc]$/;
+    ```
+
+    ```js
+    /^[👍]$/u;
+    ```
+    */
+    #[derive(Default)]
+    NoMisleadingCharacterClass,
+    regex,
+    "no-misleading-character-class"
+}
+
+/// Blocks of combining marks (`\p{M}`) of the most commonly used scripts.
+const COMBINING_MARKS: [RangeInclusive<u32>; 28] = [
+    0x0300..=0x036F,
+    0x0483..=0x0489,
+    0x0591..=0x05BD,
+    0x05BF..=0x05BF,
+    0x05C1..=0x05C2,
+    0x05C4..=0x05C5,
+    0x05C7..=0x05C7,
+    0x0610..=0x061A,
+    0x064B..=0x065F,
+    0x0670..=0x0670,
+    0x06D6..=0x06DC,
+    0x06DF..=0x06E4,
+    0x0900..=0x0903,
+    0x093A..=0x093C,
+    0x093E..=0x094F,
+    0x0951..=0x0957,
+    0x0962..=0x0963,
+    0x0E31..=0x0E31,
+    0x0E34..=0x0E3A,
+    0x0E47..=0x0E4E,
+    0x1AB0..=0x1AFF,
+    0x1DC0..=0x1DFF,
+    0x20D0..=0x20FF,
+    0x302A..=0x302F,
+    0x3099..=0x309A,
+    0xFE00..=0xFE0F,
+    0xFE20..=0xFE2F,
+    0xE0100..=0xE01EF,
+];
+
+const EMOJI_MODIFIERS: RangeInclusive<u32> = 0x1F3FB..=0x1F3FF;
+const REGIONAL_INDICATORS: RangeInclusive<u32> = 0x1F1E6..=0x1F1FF;
+const ZWJ: u32 = 0x200D;
+
+fn is_combining_mark(value: u32) -> bool {
+    COMBINING_MARKS.iter().any(|range| range.contains(&value))
+}
+
+#[typetag::serde]
+impl CstRule for NoMisleadingCharacterClass {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let pattern = util::regex_pattern(node, &ctx.semantic)?;
+        let unicode = pattern.regex.flags.unicode;
+        let mut reports = vec![];
+        pattern.regex.pattern.walk(&mut |node| {
+            if let Node::CharacterClass(class) = node {
+                for sequence in char_sequences(&class.items) {
+                    check_sequence(&sequence, unicode, &mut reports);
+                }
+            }
+        });
+
+        for (range, kind) in reports {
+            let text = pattern.text(range.clone());
+            let label = match kind {
+                Misleading::SurrogatePair => format!("`{}` is made of two UTF-16 code units, without the `u` flag they are matched separately", text),
+                Misleading::CombiningMark => format!("`{}` is a character followed by a combining mark, they are matched separately", text),
+                Misleading::EmojiModifier => format!("`{}` is an emoji followed by a modifier, they are matched separately", text),
+                Misleading::RegionalIndicators => format!("`{}` is a pair of regional indicators, they are matched separately", text),
+                Misleading::Zwj => format!("`{}` are characters joined by a zero width joiner, they are matched separately", text),
+            };
+            let mut err = ctx
+                .err(
+                    self.name(),
+                    "character classes cannot match characters made of multiple code points",
+                )
+                .primary(pattern.text_range(range), label);
+            if kind == Misleading::SurrogatePair {
+                if pattern.is_literal() {
+                    let flags_end = pattern.text_range(pattern.regex.flags_range.clone()).end();
+                    let flag = 0.."u".len();
+                    err = err.suggestion_with_labels(
+                        TextRange::new(flags_end, flags_end),
+                        "add the `u` flag to match code points instead of code units",
+                        "u",
+                        Applicability::MaybeIncorrect,
+                        vec![flag],
+                    );
+                } else {
+                    err = err
+                        .footer_help("add the `u` flag to match code points instead of code units");
+                }
+            } else {
+                err = err.footer_help(
+                    "use an alternative such as `(?:a|b)` to match the whole character",
+                );
+            }
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Misleading {
+    SurrogatePair,
+    CombiningMark,
+    EmojiModifier,
+    RegionalIndicators,
+    Zwj,
+}
+
+/// Split the items of a class into sequences of adjacent characters, class escapes end a sequence,
+/// and the minimum and maximum of a range end and start a sequence respectively.
+fn char_sequences(items: &[ClassItem]) -> Vec<Vec<&Char>> {
+    let mut sequences = vec![];
+    let mut sequence = vec![];
+    for item in items {
+        match item {
+            ClassItem::Char(c) => sequence.push(c),
+            ClassItem::Range(range) => {
+                sequence.push(&range.min);
+                sequences.push(std::mem::replace(&mut sequence, vec![&range.max]));
+            }
+            ClassItem::Escape(_) => sequences.push(std::mem::take(&mut sequence)),
+        }
+    }
+    sequences.push(sequence);
+    sequences.retain(|sequence| !sequence.is_empty());
+    sequences
+}
+
+fn check_sequence(chars: &[&Char], unicode: bool, reports: &mut Vec<(Range<usize>, Misleading)>) {
+    let mut idx = 0;
+    while idx < chars.len() {
+        let c = chars[idx];
+        let prev = idx.checked_sub(1).map(|prev| chars[prev]);
+        let next = chars.get(idx + 1);
+        let is_lead = (0xD800..=0xDBFF).contains(&c.value);
+        let next_is_trail = next.map_or(false, |next| (0xDC00..=0xDFFF).contains(&next.value));
+
+        let report = if !unicode && c.value > 0xFFFF {
+            Some((c.range.clone(), Misleading::SurrogatePair))
+        } else if !unicode && is_lead && next_is_trail {
+            idx += 1;
+            Some((
+                c.range.start..chars[idx].range.end,
+                Misleading::SurrogatePair,
+            ))
+        } else if let Some(prev) = prev {
+            if is_combining_mark(c.value) && !is_combining_mark(prev.value) {
+                Some((prev.range.start..c.range.end, Misleading::CombiningMark))
+            } else if EMOJI_MODIFIERS.contains(&c.value) && !EMOJI_MODIFIERS.contains(&prev.value) {
+                Some((prev.range.start..c.range.end, Misleading::EmojiModifier))
+            } else if REGIONAL_INDICATORS.contains(&c.value)
+                && REGIONAL_INDICATORS.contains(&prev.value)
+            {
+                // each flag is a pair of indicators, the next indicator starts a new pair
+                idx += 1;
+                Some((
+                    prev.range.start..c.range.end,
+                    Misleading::RegionalIndicators,
+                ))
+            } else if c.value == ZWJ && next.is_some() {
+                // sequences such as a family emoji are reported as a whole
+                idx += 1;
+                while chars.get(idx + 1).map_or(false, |c| c.value == ZWJ) && idx + 2 < chars.len()
+                {
+                    idx += 2;
+                }
+                Some((prev.range.start..chars[idx].range.end, Misleading::Zwj))
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        reports.extend(report);
+        idx += 1;
+    }
+}
+
+rule_tests! {
+    NoMisleadingCharacterClass::default(),
+    err: {
+        "/^[A\\u0301]$/u",
+        "/^[Á]$/u",
+        "/^[❇️]$/u",
+        "/^[👶🏻]$/u",
+        "/^[🇯🇵]$/u",
+        "/^[👨‍👩‍👦]$/u",
+        "/^[👍]$/",
+        "/^[\\uD83D\\uDC4D]$/",
+        "/^[a-zA\\u0301]$/u",
+        "new RegExp('[Á]')",
+        "new RegExp('[\\\\u0041\\\\u0301]')",
+        "new RegExp('^[👍]$')"
+    },
+    ok: {
+        "/^[abc]$/",
+        "/^[👍]$/u",
+        "/^[\\uD83D\\uDC4D]$/u",
+        "/^[\\u{1F44D}]$/u",
+        "/^[\\d\\u0301]$/u",
+        "/^[\\u0301]$/u",
+        "/^A\\u0301$/u",
+        "new RegExp('^[👍]$', 'u')"
+    }
+}
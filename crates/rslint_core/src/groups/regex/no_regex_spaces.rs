@@ -0,0 +1,148 @@
+use crate::rule_prelude::*;
+use rslint_parser::regex::{CharKind, Disjunction, Node};
+use std::ops::Range;
+
+declare_lint! {
+    /**
+    Disallow multiple consecutive spaces in regular expressions.
+
+    Several spaces in a row are hard to count, `/a   b/` could easily be read as matching two or four
+    spaces instead of three. A single space with a quantifier, such as `/a {3}b/`, makes the amount explicit.
+
+    Spaces inside of character classes and spaces which are already followed by a quantifier are not counted.
+
+    ## Incorrect Code Examples
+
+    ```js
+    let pattern = /foo   bar/;
+    ```
+
+    ```js
+    let pattern = new RegExp("foo   bar");
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let pattern = /foo {3}bar/;
+    ```
+
+    ```js
+    let pattern = /foo[  ]bar/;
+    ```
+
+    ```js
+    let pattern = new RegExp("foo {3}bar");
+    ```
+    */
+    #[derive(Default)]
+    NoRegexSpaces,
+    regex,
+    "no-regex-spaces"
+}
+
+#[typetag::serde]
+impl CstRule for NoRegexSpaces {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let pattern = util::regex_pattern(node, &ctx.semantic)?;
+        let mut runs = vec![];
+        space_runs(&pattern.regex.pattern, &mut runs);
+        // spaces written as escapes in a string, such as `"\x20\x20"`, are not hard to count
+        runs.retain(|run| pattern.is_verbatim(run.clone()));
+        if runs.is_empty() {
+            return None;
+        }
+
+        let fixer = ctx.extend_fix();
+        for run in &runs {
+            fixer.replace(pattern.text_range(run.clone()), quantifier(run));
+        }
+        for run in runs {
+            let range = pattern.text_range(run.clone());
+            let quantifier = quantifier(&run);
+            let label = 0..quantifier.len();
+            let err = ctx
+                .err(self.name(), "multiple spaces in a regex are hard to count")
+                .primary(range, format!("there are {} spaces here", run.len()))
+                .suggestion_with_labels(
+                    range,
+                    "use a quantifier instead",
+                    quantifier,
+                    Applicability::Always,
+                    vec![label],
+                );
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+fn quantifier(run: &Range<usize>) -> String {
+    format!(" {{{}}}", run.len())
+}
+
+/// Collect the ranges of two or more consecutive spaces which are not followed by a quantifier.
+/// Quantified spaces are not a plain char in the AST, so they are never part of a run.
+fn space_runs(disjunction: &Disjunction, runs: &mut Vec<Range<usize>>) {
+    for alternative in &disjunction.alternatives {
+        let mut run: Option<Range<usize>> = None;
+        for node in &alternative.nodes {
+            match node {
+                Node::Char(c) if c.value == ' ' as u32 && c.kind == CharKind::Literal => {
+                    run = Some(match run {
+                        Some(run) => run.start..c.range.end,
+                        None => c.range.clone(),
+                    });
+                    continue;
+                }
+                Node::Group(group) => space_runs(&group.body, runs),
+                Node::Lookaround(lookaround) => space_runs(&lookaround.body, runs),
+                Node::Quantified(quantified) => {
+                    if let Node::Group(group) = &*quantified.node {
+                        space_runs(&group.body, runs);
+                    }
+                }
+                _ => {}
+            }
+            runs.extend(run.take().filter(|run| run.len() > 1));
+        }
+        runs.extend(run.take().filter(|run| run.len() > 1));
+    }
+}
+
+rule_tests! {
+    NoRegexSpaces::default(),
+    err: {
+        "/foo  bar/",
+        "/foo   bar/g",
+        "/foo    */",
+        "/ (  a)/",
+        "/a|b   c/",
+        "/(?=  a)/",
+        "new RegExp('foo   bar')",
+        "RegExp('a  b', 'u')"
+    },
+    ok: {
+        "/foo bar/",
+        "/foo {3}bar/",
+        "/foo [  ] bar/",
+        "/foo \\  bar/",
+        "/foo +/",
+        "new RegExp('foo {3}bar')",
+        "new RegExp('foo' + '  bar')",
+        "new RegExp('foo\\x20\\x20bar')",
+        "var foo = 'a   b';"
+    }
+}
+
+fix_tests! {
+    NoRegexSpaces::default(),
+    "/foo  bar/" => "/foo {2}bar/",
+    "/foo   bar/g" => "/foo {3}bar/g",
+    "/foo    */" => "/foo {3} */",
+    "/ (  a)/" => "/ ( {2}a)/",
+    "/a  |b   c/" => "/a {2}|b {3}c/",
+    "new RegExp('foo   bar')" => "new RegExp('foo {3}bar')",
+    "/a  b/; /c   d/;\nnew RegExp('e  f');" => "/a {2}b/; /c {3}d/;\nnew RegExp('e {2}f');",
+    "/foo [  ] bar/" => "/foo [  ] bar/"
+}
@@ -0,0 +1,136 @@
+use crate::rule_prelude::*;
+use crate::Inferable;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce consistent spacing before and after the arrow of arrow functions.
+
+    Spaces are required before and after `=>` by default. Arrows at the start or end of a line
+    and arrows next to comments are not checked.
+
+    ## Incorrect Code Examples
+
+    ```js
+    let foo = a=>a;
+    ```
+
+    ```js
+    let foo = () =>{};
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let foo = a => a;
+    ```
+
+    ```js
+    let foo = () => {};
+    ```
+    */
+    #[serde(default)]
+    #[derive(rslint_macros::Mergeable)]
+    ArrowSpacing,
+    style,
+    "arrow-spacing",
+    /// Require a space before the arrow (true by default).
+    pub before: bool,
+    /// Require a space after the arrow (true by default).
+    pub after: bool
+}
+
+impl Default for ArrowSpacing {
+    fn default() -> Self {
+        Self {
+            before: true,
+            after: true,
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for ArrowSpacing {
+    fn check_token(&self, token: &SyntaxToken, ctx: &mut RuleCtx) -> Option<()> {
+        if token.kind() != FAT_ARROW {
+            return None;
+        }
+        util::check_adjacent_whitespace(ctx, self.name(), token, true, self.before);
+        util::check_adjacent_whitespace(ctx, self.name(), token, false, self.after);
+        None
+    }
+}
+
+#[typetag::serde]
+impl Inferable for ArrowSpacing {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let arrows = nodes
+            .iter()
+            .flat_map(|node| node.children_with_tokens())
+            .filter_map(|elem| elem.into_token())
+            .filter(|token| token.kind() == FAT_ARROW)
+            .collect::<Vec<_>>();
+        let spacing = |before: bool| {
+            arrows
+                .iter()
+                .filter_map(|arrow| util::adjacent_whitespace(arrow, before))
+                .map(|whitespace| util::tokens_have_whitespace(&whitespace, false))
+                .collect::<Vec<_>>()
+        };
+
+        let before = spacing(true);
+        if !before.is_empty() {
+            self.before = util::most_frequent(before);
+        }
+        let after = spacing(false);
+        if !after.is_empty() {
+            self.after = util::most_frequent(after);
+        }
+    }
+}
+
+rule_tests! {
+    ArrowSpacing::default(),
+    err: {
+        "let foo = a=>a;",
+        "let foo = a =>a;",
+        "let foo = a=> a;",
+        "let foo = (a, b)=>{};",
+        "let foo = async () =>{};"
+    },
+    ok: {
+        "let foo = a => a;",
+        "let foo = (a, b) => {};",
+        "let foo = a =>\n a;",
+        "let foo = a /* bar */=> a;"
+    }
+}
+
+rule_tests! {
+    never_valid,
+    never_invalid,
+    ArrowSpacing { before: false, after: false },
+    err: {
+        "let foo = a => a;",
+        "let foo = a =>a;"
+    },
+    ok: {
+        "let foo = a=>a;",
+        "let foo = ()=>{};"
+    }
+}
+
+fix_tests! {
+    ArrowSpacing::default(),
+    "let foo = a=>a;" => "let foo = a => a;",
+    "let foo = (a, b)=>{};" => "let foo = (a, b) => {};",
+    "let foo = async () =>{};" => "let foo = async () => {};",
+    "let foo = a /* bar */=>a;" => "let foo = a /* bar */=> a;"
+}
+
+fix_tests! {
+    never_fixes,
+    ArrowSpacing { before: false, after: false },
+    "let foo = a => a;" => "let foo = a=>a;",
+    "let foo = a  =>\n  a;" => "let foo = a=>\n  a;"
+}
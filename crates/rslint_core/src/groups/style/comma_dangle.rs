@@ -0,0 +1,342 @@
+use crate::rule_prelude::*;
+use crate::util::StyleExt;
+use crate::Inferable;
+use ast::ArrayExpr;
+use rslint_parser::{Direction, NodeOrToken};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Require or disallow trailing commas in lists.
+
+    Trailing commas make adding items to the end of multiline lists simpler and their diffs cleaner,
+    on the other hand they are unnecessary in lists on a single line.
+
+    Every kind of list can be configured on its own with one of these values:
+
+    - `"always"` requires trailing commas.
+    - `"always-multiline"` requires trailing commas if the closing bracket is on a different line than the last item,
+      and disallows them otherwise.
+    - `"only-multiline"` allows trailing commas if the closing bracket is on a different line than the last item,
+      and disallows them otherwise.
+    - `"never"` disallows trailing commas.
+
+    Trailing commas are never required after rest elements such as `...foo` in patterns or parameters since they are
+    not allowed there. Commas at the end of arrays which make holes, such as the second comma in `[foo,,]`,
+    are not trailing commas and are therefore never removed.
+
+    ## Incorrect Code Examples
+
+    ```js
+    let foo = [1, 2,];
+    ```
+
+    ```js
+    import { foo, bar, } from "baz";
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let foo = [1, 2];
+    ```
+
+    ```js
+    let foo = [1, , ];
+    ```
+
+    With `objects` set to `"always-multiline"`:
+
+    ```js
+    let foo = {
+        bar: 1,
+        baz: 2,
+    };
+    let qux = { bar: 1, baz: 2 };
+    ```
+    */
+    #[serde(default)]
+    CommaDangle,
+    style,
+    "comma-dangle",
+    /// Trailing commas in array literals and array patterns, "never" by default.
+    pub arrays: String,
+    /// Trailing commas in object literals and object patterns, "never" by default.
+    pub objects: String,
+    /// Trailing commas in the named imports of import declarations, "never" by default.
+    pub imports: String,
+    /// Trailing commas in the named exports of export declarations, "never" by default.
+    pub exports: String,
+    /// Trailing commas in the parameters of functions and the arguments of calls, "never" by default.
+    pub functions: String
+}
+
+impl Default for CommaDangle {
+    fn default() -> Self {
+        Self {
+            arrays: "never".to_string(),
+            objects: "never".to_string(),
+            imports: "never".to_string(),
+            exports: "never".to_string(),
+            functions: "never".to_string(),
+        }
+    }
+}
+
+const STYLES: [&str; 4] = ["never", "always-multiline", "always", "only-multiline"];
+
+#[typetag::serde]
+impl CstRule for CommaDangle {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let style = self.style(node.kind())?;
+        match violation(style, node)? {
+            Violation::Missing(last) => {
+                let err = ctx
+                    .err(self.name(), "missing trailing comma")
+                    .primary(last.trimmed_range(), "a comma is expected after this");
+                ctx.add_err(err);
+                ctx.extend_fix().insert_after(last.trimmed_range(), ",");
+            }
+            Violation::Unexpected(comma) => {
+                let err = ctx
+                    .err(self.name(), "unexpected trailing comma")
+                    .primary(comma.text_range(), "this comma can be removed");
+                ctx.add_err(err);
+                ctx.extend_fix().delete(comma.text_range());
+            }
+        }
+        None
+    }
+}
+
+impl CommaDangle {
+    fn style(&self, kind: SyntaxKind) -> Option<&str> {
+        let style = match kind {
+            ARRAY_EXPR | ARRAY_PATTERN => &self.arrays,
+            OBJECT_EXPR | OBJECT_PATTERN => &self.objects,
+            NAMED_IMPORTS => &self.imports,
+            EXPORT_NAMED => &self.exports,
+            PARAMETER_LIST | ARG_LIST => &self.functions,
+            _ => return None,
+        };
+        Some(style)
+    }
+
+    fn style_mut(&mut self, kind: SyntaxKind) -> Option<&mut String> {
+        let style = match kind {
+            ARRAY_EXPR | ARRAY_PATTERN => &mut self.arrays,
+            OBJECT_EXPR | OBJECT_PATTERN => &mut self.objects,
+            NAMED_IMPORTS => &mut self.imports,
+            EXPORT_NAMED => &mut self.exports,
+            PARAMETER_LIST | ARG_LIST => &mut self.functions,
+            _ => return None,
+        };
+        Some(style)
+    }
+}
+
+enum Violation {
+    /// The list has no trailing comma after its last item.
+    Missing(SyntaxNode),
+    /// The list has a trailing comma which is not allowed.
+    Unexpected(SyntaxToken),
+}
+
+fn violation(style: &str, node: &SyntaxNode) -> Option<Violation> {
+    // parameters of arrow functions without parentheses do not have a closing token
+    let close = node
+        .children_with_tokens()
+        .filter_map(|elem| elem.into_token())
+        .filter(|token| matches!(token.kind(), R_BRACK | R_CURLY | R_PAREN))
+        .last()?;
+    let multiline = close.leading_trivia_has_linebreak(false);
+    let expected = match style {
+        "always" => true,
+        "always-multiline" => multiline,
+        "only-multiline" if multiline => return None,
+        _ => false,
+    };
+
+    let prev = close
+        .siblings_with_tokens(Direction::Prev)
+        .skip(1)
+        .find(|elem| !elem.kind().is_trivia())?;
+    match prev {
+        NodeOrToken::Token(comma) if comma.kind() == COMMA => {
+            if expected || is_hole(node, &comma) {
+                None
+            } else {
+                Some(Violation::Unexpected(comma))
+            }
+        }
+        NodeOrToken::Node(last) if expected && last.kind() != REST_PATTERN => {
+            Some(Violation::Missing(last))
+        }
+        _ => None,
+    }
+}
+
+/// Whether a comma makes a hole in an array instead of separating items.
+fn is_hole(node: &SyntaxNode, comma: &SyntaxToken) -> bool {
+    if let Some(array) = node.try_to::<ArrayExpr>() {
+        return array.sparse_elements().contains(comma);
+    }
+    comma
+        .siblings_with_tokens(Direction::Prev)
+        .skip(1)
+        .find(|elem| !elem.kind().is_trivia())
+        .map_or(true, |elem| elem.into_token().is_some())
+}
+
+#[typetag::serde]
+impl Inferable for CommaDangle {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let constructs: [&[SyntaxKind]; 5] = [
+            &[ARRAY_EXPR, ARRAY_PATTERN],
+            &[OBJECT_EXPR, OBJECT_PATTERN],
+            &[NAMED_IMPORTS],
+            &[EXPORT_NAMED],
+            &[PARAMETER_LIST, ARG_LIST],
+        ];
+        for kinds in &constructs {
+            let lists = nodes
+                .iter()
+                .filter(|node| kinds.contains(&node.kind()))
+                .collect::<Vec<_>>();
+            if lists.is_empty() {
+                continue;
+            }
+            // pick the style which the fewest lists violate
+            let best = STYLES
+                .iter()
+                .min_by_key(|style| {
+                    lists
+                        .iter()
+                        .filter(|node| violation(style, node).is_some())
+                        .count()
+                })
+                .unwrap();
+            *self.style_mut(kinds[0]).unwrap() = best.to_string();
+        }
+    }
+}
+
+rule_tests! {
+    CommaDangle::default(),
+    err: {
+        "let foo = [1, 2,];",
+        "let foo = {\n a: 1,\n};",
+        "let [a, b,] = foo;",
+        "let { a, b, } = foo;",
+        "import { a, b, } from 'foo';",
+        "export { a, b, };",
+        "function foo(a, b,) {}",
+        "foo(a, b,);",
+        "let foo = (a,) => a;"
+    },
+    ok: {
+        "let foo = [1, 2];",
+        "let foo = [1, , ];",
+        "let foo = [,];",
+        "let [a, , ] = foo;",
+        "let foo = {};",
+        "import { a, b } from 'foo';",
+        "export { a, b } from 'foo';",
+        "foo(a, b);",
+        "let foo = a => a;"
+    }
+}
+
+rule_tests! {
+    always_multiline_valid,
+    always_multiline_invalid,
+    CommaDangle {
+        arrays: "always-multiline".to_string(),
+        objects: "always-multiline".to_string(),
+        imports: "always-multiline".to_string(),
+        exports: "always-multiline".to_string(),
+        functions: "always-multiline".to_string(),
+    },
+    err: {
+        "let foo = [\n 1,\n 2\n];",
+        "let foo = {\n a: 1\n};",
+        "let foo = { a: 1, };",
+        "import {\n a,\n b\n} from 'foo';",
+        "foo(\n a,\n b\n);"
+    },
+    ok: {
+        "let foo = [\n 1,\n 2,\n];",
+        "let foo = [1, 2];",
+        "let foo = { a: 1, b: {\n c: 2,\n} };",
+        "let {\n a,\n ...b\n} = foo;",
+        "function foo(\n a,\n ...b\n) {}",
+        "foo(\n a,\n b,\n);",
+        "foo();"
+    }
+}
+
+rule_tests! {
+    only_multiline_valid,
+    only_multiline_invalid,
+    CommaDangle {
+        arrays: "only-multiline".to_string(),
+        ..CommaDangle::default()
+    },
+    err: {
+        "let foo = [1, 2,];"
+    },
+    ok: {
+        "let foo = [\n 1,\n 2,\n];",
+        "let foo = [\n 1,\n 2\n];",
+        "let foo = [1, 2];"
+    }
+}
+
+rule_tests! {
+    always_valid,
+    always_invalid,
+    CommaDangle {
+        arrays: "always".to_string(),
+        objects: "always".to_string(),
+        ..CommaDangle::default()
+    },
+    err: {
+        "let foo = [1, 2];",
+        "let foo = { a: 1 };",
+        "let [a, b] = foo;"
+    },
+    ok: {
+        "let foo = [1, 2,];",
+        "let foo = { a: 1, };",
+        "let [a, ...b] = foo;",
+        "let foo = [];",
+        "foo(a, b);"
+    }
+}
+
+fix_tests! {
+    CommaDangle::default(),
+    "let foo = [1, 2,];" => "let foo = [1, 2];",
+    "let foo = {\n a: 1,\n};" => "let foo = {\n a: 1\n};",
+    "foo(a, b,);" => "foo(a, b);",
+    "let foo = (a,) => a;" => "let foo = (a) => a;",
+    "import { a, b, } from 'foo';\nfoo([1,], { a, });" => "import { a, b } from 'foo';\nfoo([1], { a });",
+    "let foo = [1,, ];" => "let foo = [1,, ];",
+    "let foo = [1, 2,,];" => "let foo = [1, 2,,];",
+    "let foo = [,];" => "let foo = [,];"
+}
+
+fix_tests! {
+    always_fixes,
+    CommaDangle {
+        arrays: "always".to_string(),
+        objects: "always".to_string(),
+        ..CommaDangle::default()
+    },
+    "let foo = [1, 2];" => "let foo = [1, 2,];",
+    "let foo = { a: 1 /* b */ };" => "let foo = { a: 1, /* b */ };",
+    "let foo = [1, , 2];" => "let foo = [1, , 2,];",
+    "let foo = [1, ...bar];" => "let foo = [1, ...bar,];",
+    "let [a, ...b] = foo;" => "let [a, ...b] = foo;",
+    "let foo = [,];" => "let foo = [,];"
+}
@@ -0,0 +1,219 @@
+use crate::rule_prelude::*;
+use crate::util::StyleExt;
+use crate::Inferable;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce consistent spacing before and after commas.
+
+    By default spaces are disallowed before commas and required after commas. Commas at the start or
+    end of a line, commas before closing brackets, and commas next to holes in arrays such as `[foo,,bar]`
+    are ignored. Only whitespace is added or removed, comments next to commas are never changed.
+
+    ## Incorrect Code Examples
+
+    ```js
+    let foo = [1 , 2];
+    ```
+
+    ```js
+    foo(a,b);
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let foo = [1, 2];
+    ```
+
+    ```js
+    let foo = [1,, 2];
+    ```
+
+    ```js
+    foo(
+        a
+        , b
+    );
+    ```
+    */
+    #[serde(default)]
+    #[derive(rslint_macros::Mergeable)]
+    CommaSpacing,
+    style,
+    "comma-spacing",
+    /// Require whitespace before commas (false by default).
+    pub before: bool,
+    /// Require whitespace after commas (true by default).
+    pub after: bool
+}
+
+impl Default for CommaSpacing {
+    fn default() -> Self {
+        Self {
+            before: false,
+            after: true,
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for CommaSpacing {
+    fn check_token(&self, token: &SyntaxToken, ctx: &mut RuleCtx) -> Option<()> {
+        if token.kind() != COMMA {
+            return None;
+        }
+
+        if let Some(whitespace) = whitespace_before(token) {
+            if self.before && whitespace.is_empty() {
+                let err = ctx
+                    .err(self.name(), "expected a space before this comma")
+                    .primary(token, "");
+                ctx.add_err(err);
+                ctx.extend_fix().insert_before(token, " ");
+            } else if !self.before && !whitespace.is_empty() {
+                let err = ctx
+                    .err(self.name(), "unexpected space before this comma")
+                    .primary(
+                        util::token_list_range(&whitespace),
+                        "this space can be removed",
+                    );
+                ctx.add_err(err);
+                ctx.extend_fix().delete_multiple(whitespace);
+            }
+        }
+
+        if let Some(whitespace) = whitespace_after(token) {
+            if self.after && whitespace.is_empty() {
+                let err = ctx
+                    .err(self.name(), "expected a space after this comma")
+                    .primary(token, "");
+                ctx.add_err(err);
+                ctx.extend_fix().insert_after(token, " ");
+            } else if !self.after && !whitespace.is_empty() {
+                let err = ctx
+                    .err(self.name(), "unexpected space after this comma")
+                    .primary(
+                        util::token_list_range(&whitespace),
+                        "this space can be removed",
+                    );
+                ctx.add_err(err);
+                ctx.extend_fix().delete_multiple(whitespace);
+            }
+        }
+        None
+    }
+}
+
+/// The whitespace directly before a comma, or `None` if the spacing before the comma is not checked.
+fn whitespace_before(comma: &SyntaxToken) -> Option<Vec<SyntaxToken>> {
+    let prev = std::iter::successors(comma.prev_token(), |token| token.prev_token())
+        .find(|token| !token.kind().is_trivia())?;
+    if matches!(prev.kind(), COMMA | L_BRACK | L_PAREN | L_CURLY) {
+        return None;
+    }
+    Some(comma.leading_whitespace(false)).filter(|whitespace| !has_linebreak(whitespace))
+}
+
+/// The whitespace directly after a comma, or `None` if the spacing after the comma is not checked.
+fn whitespace_after(comma: &SyntaxToken) -> Option<Vec<SyntaxToken>> {
+    let next = std::iter::successors(comma.next_token(), |token| token.next_token())
+        .find(|token| !token.kind().is_trivia())?;
+    if matches!(next.kind(), COMMA | R_BRACK | R_PAREN | R_CURLY | EOF) {
+        return None;
+    }
+    Some(comma.trailing_whitespace(false)).filter(|whitespace| !has_linebreak(whitespace))
+}
+
+fn has_linebreak(whitespace: &[SyntaxToken]) -> bool {
+    whitespace
+        .iter()
+        .any(|token| parseutil::contains_js_linebreak(token.text().as_str()))
+}
+
+#[typetag::serde]
+impl Inferable for CommaSpacing {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let commas = nodes
+            .iter()
+            .flat_map(|node| node.children_with_tokens())
+            .filter_map(|elem| elem.into_token())
+            .filter(|token| token.kind() == COMMA)
+            .collect::<Vec<_>>();
+
+        let before = commas
+            .iter()
+            .filter_map(whitespace_before)
+            .map(|whitespace| !whitespace.is_empty())
+            .collect::<Vec<_>>();
+        if !before.is_empty() {
+            self.before = util::most_frequent(before);
+        }
+
+        let after = commas
+            .iter()
+            .filter_map(whitespace_after)
+            .map(|whitespace| !whitespace.is_empty())
+            .collect::<Vec<_>>();
+        if !after.is_empty() {
+            self.after = util::most_frequent(after);
+        }
+    }
+}
+
+rule_tests! {
+    CommaSpacing::default(),
+    err: {
+        "let foo = [1 , 2];",
+        "let foo = [1,2];",
+        "foo(a,b);",
+        "let a = 1 ,b = 2;",
+        "function foo(a ,b) {}",
+        "let { a,b } = foo;"
+    },
+    ok: {
+        "let foo = [1, 2];",
+        "let foo = [1,, 2];",
+        "let foo = [, 2];",
+        "let foo = [1, , 2];",
+        "let foo = [1, 2,];",
+        "foo(a, b);",
+        "foo(a /* bar */, b);",
+        "foo(\na\n, b\n);",
+        "foo(a,\nb);"
+    }
+}
+
+rule_tests! {
+    before_valid,
+    before_invalid,
+    CommaSpacing { before: true, after: false },
+    err: {
+        "let foo = [1, 2];",
+        "foo(a,b);"
+    },
+    ok: {
+        "let foo = [1 ,2];",
+        "foo(a ,b);",
+        "let foo = [1 ,,2];"
+    }
+}
+
+fix_tests! {
+    CommaSpacing::default(),
+    "let foo = [1 , 2];" => "let foo = [1, 2];",
+    "foo(a,b);" => "foo(a, b);",
+    "foo(a  ,  b);" => "foo(a,  b);",
+    "let a = 1 ,b = 2;" => "let a = 1, b = 2;",
+    "foo(a /* bar */,b);" => "foo(a /* bar */, b);",
+    "let foo = [1,, 2];" => "let foo = [1,, 2];",
+    "foo(a,\nb);" => "foo(a,\nb);"
+}
+
+fix_tests! {
+    before_fixes,
+    CommaSpacing { before: true, after: false },
+    "let foo = [1, 2];" => "let foo = [1 ,2];",
+    "foo(a,b);" => "foo(a ,b);"
+}
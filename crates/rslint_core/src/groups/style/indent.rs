@@ -0,0 +1,594 @@
+use crate::rule_prelude::*;
+use crate::Inferable;
+use rslint_lexer::is_linebreak;
+use rslint_parser::{NodeOrToken, TextRange, TextSize};
+use std::collections::HashMap;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce consistent indentation.
+
+    The indentation of every line is checked against how deeply nested the code on it is. Lines are
+    indented one level inside blocks, brackets, and parentheses, as well as in statement bodies without
+    braces and in expressions which continue on the next line. A level is either a tab or a configured
+    number of spaces.
+
+    Lines which continue a binary expression, lines inside of template literals, and lines starting
+    with a comment are not checked.
+
+    ## Incorrect Code Examples
+
+    ```js
+    function foo() {
+      return bar;
+    }
+    ```
+
+    ```js
+    if (foo) {
+        bar();
+      }
+    ```
+
+    ```js
+    foo(
+    bar
+    );
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function foo() {
+        if (bar) {
+            return baz;
+        }
+    }
+    ```
+
+    ```js
+    const foo = bar
+        .baz()
+        .qux();
+    ```
+
+    ```js
+    switch (foo) {
+    case 1:
+        bar();
+    }
+    ```
+    */
+    #[serde(default)]
+    #[derive(rslint_macros::Mergeable)]
+    Indent,
+    style,
+    "indent",
+    /// The character used for indentation, either "spaces" (default) or "tabs".
+    pub style: String,
+    /// The number of spaces in an indentation level, 4 by default. This is ignored if indenting with tabs.
+    pub size: usize,
+    /// The number of levels `case` and `default` clauses are indented inside of `switch` statements, 0 by default.
+    pub switch_case: usize,
+    /// The number of levels member accesses on a new line are indented, such as `.bar()` in `foo\n.bar()`,
+    /// 1 by default.
+    pub member_expression: usize,
+    /// The number of levels the arguments of calls are indented, 1 by default.
+    pub call_expression_arguments: usize,
+    /// Whether conditional expressions nested in other conditional expressions are not indented (false by default).
+    pub flat_ternary_expressions: bool
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Self {
+            style: "spaces".to_string(),
+            size: 4,
+            switch_case: 0,
+            member_expression: 1,
+            call_expression_arguments: 1,
+            flat_ternary_expressions: false,
+        }
+    }
+}
+
+/// How a token is indented.
+enum Offset {
+    /// The token is not checked, its indentation is the indentation of its line.
+    Ignored,
+    /// The token is indented a number of levels more than another token, or than the start of the file.
+    /// Tokens on the same line as the token they are relative to are not indented.
+    Relative(Option<SyntaxToken>, usize),
+}
+
+#[typetag::serde]
+impl CstRule for Indent {
+    fn check_root(&self, root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let src = root.text().to_string();
+        let mut cache = HashMap::new();
+        let mut edits = vec![];
+
+        let tokens = root
+            .descendants_with_tokens()
+            .filter_map(|elem| elem.into_token())
+            .filter(|token| !token.kind().is_trivia());
+        for token in tokens {
+            let indentation = match line_indentation(&src, token.text_range().start()) {
+                Some(range) => range,
+                None => continue,
+            };
+            if let Offset::Ignored = self.offset(&token, &src) {
+                continue;
+            }
+
+            let expected = self.desired_indent(&token, &src, &mut cache);
+            let actual = &src[usize::from(indentation.start())..usize::from(indentation.end())];
+            if actual.chars().all(|c| c == self.indent_char()) && actual.chars().count() == expected
+            {
+                continue;
+            }
+
+            let err = ctx
+                .err(
+                    self.name(),
+                    format!(
+                        "expected indentation of {} but found {}",
+                        self.describe(expected),
+                        describe_found(actual)
+                    ),
+                )
+                .primary(
+                    if indentation.is_empty() {
+                        token.text_range()
+                    } else {
+                        indentation
+                    },
+                    "",
+                );
+            ctx.add_err(err);
+            edits.push((indentation, self.indent_char().to_string().repeat(expected)));
+        }
+
+        if !edits.is_empty() {
+            let fixer = ctx.fix();
+            for (range, text) in edits {
+                fixer.replace(range, text);
+            }
+        }
+        None
+    }
+}
+
+impl Indent {
+    fn indent_char(&self) -> char {
+        if self.style == "tabs" {
+            '\t'
+        } else {
+            ' '
+        }
+    }
+
+    /// The number of indentation characters in a level.
+    fn level_width(&self) -> usize {
+        if self.style == "tabs" {
+            1
+        } else {
+            self.size
+        }
+    }
+
+    fn describe(&self, count: usize) -> String {
+        let unit = if self.style == "tabs" { "tab" } else { "space" };
+        format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" })
+    }
+
+    /// The number of indentation characters a token should be indented by, this is computed for every token,
+    /// even if it is not the first token on its line.
+    fn desired_indent(
+        &self,
+        token: &SyntaxToken,
+        src: &str,
+        cache: &mut HashMap<TextSize, usize>,
+    ) -> usize {
+        let start = token.text_range().start();
+        if let Some(indent) = cache.get(&start) {
+            return *indent;
+        }
+
+        let indent = match self.offset(token, src) {
+            Offset::Ignored => {
+                let line_start = src[..usize::from(start)]
+                    .rfind(is_linebreak)
+                    .map_or(0, |idx| idx + 1);
+                src[line_start..]
+                    .chars()
+                    .take_while(|c| *c == ' ' || *c == '\t')
+                    .count()
+            }
+            Offset::Relative(None, levels) => levels * self.level_width(),
+            Offset::Relative(Some(anchor), levels) => {
+                // tokens are indented relative to the line of their anchor, not the anchor itself,
+                // unless the indentation of that line is ignored such as with binary expressions
+                let line_start = first_token_on_line(&anchor, src);
+                let base = match self.offset(&line_start, src) {
+                    Offset::Ignored => self.desired_indent(&anchor, src, cache),
+                    _ => self.desired_indent(&line_start, src, cache),
+                };
+                if same_line(src, anchor.text_range().start(), start) {
+                    base
+                } else {
+                    base + levels * self.level_width()
+                }
+            }
+        };
+        cache.insert(start, indent);
+        indent
+    }
+
+    /// Find how a token is indented by going up its ancestors until one of them decides how its
+    /// descendant is indented.
+    fn offset(&self, token: &SyntaxToken, src: &str) -> Offset {
+        let mut child = SyntaxElement::from(token.clone());
+        for node in token.parent().ancestors() {
+            let first = first_token(&node);
+            let starts_child = first_token_of(&child).as_ref() == Some(token);
+            let child_range = child.text_range();
+
+            if node.kind() == TEMPLATE && first.as_ref() != Some(token) {
+                return Offset::Ignored;
+            }
+
+            if let Some((open, close)) = brackets(&node) {
+                let after_open = child_range.start() >= open.text_range().end();
+                let before_close = close.as_ref().map_or(true, |close| {
+                    child_range.end() <= close.text_range().start()
+                });
+                if close.as_ref() == Some(token) {
+                    return Offset::Relative(Some(open), 0);
+                }
+                if after_open && before_close {
+                    let levels = match node.kind() {
+                        SWITCH_STMT => self.switch_case,
+                        ARG_LIST => self.call_expression_arguments,
+                        _ => 1,
+                    };
+                    return Offset::Relative(Some(open), levels);
+                }
+            }
+
+            if first.as_ref() == Some(token) {
+                child = node.into();
+                continue;
+            }
+
+            match node.kind() {
+                BIN_EXPR => {
+                    let lhs = node.first_child().map(|lhs| lhs.text_range());
+                    if lhs.map_or(false, |lhs| child_range.start() >= lhs.end()) {
+                        if starts_child {
+                            return Offset::Ignored;
+                        }
+                        return Offset::Relative(first, 1);
+                    }
+                }
+                DOT_EXPR | BRACKET_EXPR => {
+                    let object = node.first_child().map(|object| object.text_range());
+                    if object.map_or(false, |object| child_range.start() >= object.end()) {
+                        return Offset::Relative(first, self.member_expression);
+                    }
+                }
+                COND_EXPR => {
+                    let test = node.first_child().map(|test| test.text_range());
+                    let in_test = test.map_or(true, |test| child_range.end() <= test.end());
+                    if !in_test && !self.is_flat_ternary(&node, src) {
+                        return Offset::Relative(first, 1);
+                    }
+                }
+                kind if is_continued(kind) => {
+                    if !starts_child {
+                        return Offset::Relative(first, 1);
+                    }
+                    match child.kind() {
+                        // `else if` is as indented as the `else` before it
+                        IF_STMT if kind == IF_STMT => {
+                            let else_token =
+                                std::iter::successors(child.prev_sibling_or_token(), |elem| {
+                                    elem.prev_sibling_or_token()
+                                })
+                                .find(|elem| !elem.kind().is_trivia())
+                                .and_then(|elem| elem.into_token())
+                                .filter(|token| token.kind() == ELSE_KW);
+                            if else_token.is_some() {
+                                return Offset::Relative(else_token, 0);
+                            }
+                            return Offset::Relative(first, 1);
+                        }
+                        BLOCK_STMT | CLASS_BODY | CATCH_CLAUSE | FINALIZER | L_CURLY | ELSE_KW
+                        | WHILE_KW => return Offset::Relative(first, 0),
+                        _ => return Offset::Relative(first, 1),
+                    }
+                }
+                _ => {}
+            }
+            child = node.into();
+        }
+        Offset::Relative(None, 0)
+    }
+
+    /// Whether a conditional expression is not indented because of `flat_ternary_expressions`, which is the case
+    /// if its test and consequent are on the same line and it does not start on the first line of its statement.
+    fn is_flat_ternary(&self, node: &SyntaxNode, src: &str) -> bool {
+        if !self.flat_ternary_expressions {
+            return false;
+        }
+        let expr = node.to::<ast::CondExpr>();
+        let (test, cons) = match (expr.test(), expr.cons()) {
+            (Some(test), Some(cons)) => (test, cons),
+            _ => return false,
+        };
+        let stmt_start = node
+            .ancestors()
+            .find(|ancestor| ast::ModuleItem::can_cast(ancestor.kind()))
+            .and_then(|stmt| first_token(&stmt));
+        let start = match first_token(node) {
+            Some(token) => token.text_range().start(),
+            None => return false,
+        };
+
+        same_line(
+            src,
+            test.syntax().trimmed_range().end(),
+            cons.syntax().trimmed_range().start(),
+        ) && stmt_start.map_or(false, |stmt_start| {
+            !same_line(src, stmt_start.text_range().start(), start)
+        })
+    }
+}
+
+/// Kinds of nodes whose children are indented one level if they start on a new line.
+fn is_continued(kind: SyntaxKind) -> bool {
+    ast::ModuleItem::can_cast(kind)
+        || matches!(
+            kind,
+            CASE_CLAUSE
+                | DEFAULT_CLAUSE
+                | CATCH_CLAUSE
+                | FINALIZER
+                | LITERAL_PROP
+                | CLASS_PROP
+                | PRIVATE_PROP
+                | FN_EXPR
+                | ARROW_EXPR
+                | METHOD
+                | GETTER
+                | SETTER
+                | CONSTRUCTOR
+                | CLASS_EXPR
+        )
+}
+
+/// The opening and closing bracket which are direct children of a node, if there are any.
+fn brackets(node: &SyntaxNode) -> Option<(SyntaxToken, Option<SyntaxToken>)> {
+    let open = node
+        .children_with_tokens()
+        .filter_map(|elem| elem.into_token())
+        .find(|token| matches!(token.kind(), L_CURLY | L_BRACK | L_PAREN))?;
+    let close_kind = match open.kind() {
+        L_CURLY => R_CURLY,
+        L_BRACK => R_BRACK,
+        _ => R_PAREN,
+    };
+    let close = node
+        .children_with_tokens()
+        .filter_map(|elem| elem.into_token())
+        .filter(|token| token.kind() == close_kind)
+        .last();
+    Some((open, close))
+}
+
+fn first_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    node.descendants_with_tokens()
+        .filter_map(|elem| elem.into_token())
+        .find(|token| !token.kind().is_trivia())
+}
+
+fn first_token_of(elem: &SyntaxElement) -> Option<SyntaxToken> {
+    match elem {
+        NodeOrToken::Node(node) => first_token(node),
+        NodeOrToken::Token(token) => Some(token.clone()),
+    }
+}
+
+/// The range of the indentation before a token if it is the first token on its line.
+fn line_indentation(src: &str, start: TextSize) -> Option<TextRange> {
+    let before = &src[..usize::from(start)];
+    let line_start = before.rfind(is_linebreak).map_or(0, |idx| idx + 1);
+    if before[line_start..].chars().all(char::is_whitespace) {
+        Some(TextRange::new((line_start as u32).into(), start))
+    } else {
+        None
+    }
+}
+
+/// The first token on the line of a token, which is the token itself if it is the first token.
+fn first_token_on_line(token: &SyntaxToken, src: &str) -> SyntaxToken {
+    let start = usize::from(token.text_range().start());
+    let line_start = src[..start].rfind(is_linebreak).map_or(0, |idx| idx + 1);
+    std::iter::successors(Some(token.clone()), |token| token.prev_token())
+        .take_while(|token| usize::from(token.text_range().start()) >= line_start)
+        .filter(|token| !token.kind().is_trivia())
+        .last()
+        .unwrap_or_else(|| token.clone())
+}
+
+fn same_line(src: &str, a: TextSize, b: TextSize) -> bool {
+    let (start, end) = (usize::from(a.min(b)), usize::from(a.max(b)));
+    !src[start..end].contains(is_linebreak)
+}
+
+fn describe_found(indentation: &str) -> String {
+    let spaces = indentation.chars().filter(|c| *c != '\t').count();
+    let tabs = indentation.chars().filter(|c| *c == '\t').count();
+    let plural = |count: usize, unit: &str| {
+        format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" })
+    };
+    match (spaces, tabs) {
+        (0, 0) => "no indentation".to_string(),
+        (_, 0) => plural(spaces, "space"),
+        (0, _) => plural(tabs, "tab"),
+        _ => format!("{} and {}", plural(spaces, "space"), plural(tabs, "tab")),
+    }
+}
+
+#[typetag::serde]
+impl Inferable for Indent {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let mut candidates = vec![];
+        for switch_case in 0..=1 {
+            for (style, size) in &[("spaces", 4), ("spaces", 2), ("tabs", 4)] {
+                candidates.push(Self {
+                    style: style.to_string(),
+                    size: *size,
+                    switch_case,
+                    ..Self::default()
+                });
+            }
+        }
+
+        let mut inferred_structs = vec![];
+        for node in nodes {
+            if matches!(node.kind(), SCRIPT | MODULE) {
+                let best = candidates.iter().min_by_key(|candidate| {
+                    let mut ctx = RuleCtx::dummy_ctx();
+                    candidate.check_root(node, &mut ctx);
+                    ctx.diagnostics.len()
+                });
+                inferred_structs.extend(best.cloned());
+            }
+        }
+        if let Some(new) = Self::merge(inferred_structs) {
+            *self = new;
+        }
+    }
+}
+
+rule_tests! {
+    Indent::default(),
+    err: {
+        "function foo() {\n  return bar;\n}",
+        /// ignore
+        "if (foo) {\n    bar();\n  }",
+        /// ignore
+        "  foo();",
+        "foo(\nbar\n);",
+        "const foo = {\n    a: 1,\n      b: 2\n};",
+        "if (foo)\nbar();",
+        "if (foo) {\n} else\nbar();",
+        "foo\n.bar();",
+        "switch (foo) {\n    case 1:\n        bar();\n}",
+        "switch (foo) {\ncase 1:\nbar();\n}",
+        "const a = foo\n    ? bar\n  : baz;",
+        "if (foo) {\n\tbar();\n}",
+        "class A {\n  foo() {}\n}",
+        "const [\na,\n] = foo;",
+        "while (foo) {\n    if (bar) {\n    baz();\n    }\n}"
+    },
+    ok: {
+        "function foo() {\n    return bar;\n}",
+        "if (foo) {\n    bar();\n} else if (baz) {\n    qux();\n} else {\n    quux();\n}",
+        "if (foo)\n    bar();\nelse\n    baz();",
+        "foo(\n    bar,\n    baz\n);",
+        "foo(bar, {\n    baz: 1\n});",
+        "foo(function () {\n    bar();\n});",
+        "const foo = {\n    a: 1,\n    b: {\n        c: 2\n    }\n};",
+        /// ignore
+        "const foo = bar\n    .baz()\n    .qux();",
+        "switch (foo) {\ncase 1:\n    bar();\n    break;\ndefault:\n    baz();\n}",
+        /// ignore
+        "const a = foo\n    ? bar\n    : baz;",
+        /// ignore
+        "const a = foo &&\n  bar;",
+        "try {\n    foo();\n} catch (e) {\n    bar();\n} finally {\n    baz();\n}",
+        "do {\n    foo();\n} while (bar);",
+        "class A {\n    foo() {\n        return 1;\n    }\n}",
+        /// ignore
+        "const a = 1,\n    b = 2;",
+        /// ignore
+        "const a =\n    foo;",
+        "const a = `\n  foo ${bar}\n`;",
+        /// ignore
+        "  // comment\nfoo();",
+        "import {\n    a,\n    b\n} from 'c';",
+        "if (\n    foo\n) {\n    bar();\n}",
+        "foo(() => {\n    bar();\n});",
+        "foo(function (\n    a\n) {\n    bar();\n});",
+        "class A\n{\n    foo() {}\n}",
+        "const a = [\n    1,\n    2,\n];",
+        "p.then(function () {\n    a();\n}, function (err) {\n    b(err);\n});",
+        "const x = [{\n    a: 1,\n}, {\n    b: 2,\n}];",
+        /// ignore
+        "if (a\n    || b\n    && c) {\n    d();\n}",
+        /// ignore
+        "\n\nfoo();\n\n    \nbar();"
+    }
+}
+
+rule_tests! {
+    tabs_valid,
+    tabs_invalid,
+    Indent { style: "tabs".to_string(), ..Indent::default() },
+    err: {
+        "if (foo) {\n    bar();\n}",
+        "if (foo) {\n\t\tbar();\n}",
+        "if (foo) {\n\t bar();\n}"
+    },
+    ok: {
+        "if (foo) {\n\tbar();\n}",
+        "function foo() {\n\tif (bar) {\n\t\treturn baz;\n\t}\n}"
+    }
+}
+
+rule_tests! {
+    options_valid,
+    options_invalid,
+    Indent {
+        size: 2,
+        switch_case: 1,
+        member_expression: 2,
+        call_expression_arguments: 2,
+        flat_ternary_expressions: true,
+        ..Indent::default()
+    },
+    err: {
+        "switch (foo) {\ncase 1:\n  bar();\n}",
+        "foo\n  .bar();",
+        "foo(\n  bar\n);",
+        "const a =\n  foo ? bar :\n    baz ? qux :\n      quux;"
+    },
+    ok: {
+        "switch (foo) {\n  case 1:\n    bar();\n}",
+        "foo\n    .bar();",
+        "foo(\n    bar\n);",
+        "const a =\n  foo ? bar :\n  baz ? qux :\n  quux;",
+        "const a = foo\n  ? bar\n  : baz;"
+    }
+}
+
+fix_tests! {
+    Indent::default(),
+    "function foo() {\n  return bar;\n}" => "function foo() {\n    return bar;\n}",
+    "if (foo) {\nbar();\n    }" => "if (foo) {\n    bar();\n}",
+    "p.then(function () {\n        a();\n    }, function (err) {\n        b(err);\n    });" => "p.then(function () {\n    a();\n}, function (err) {\n    b(err);\n});",
+    "const x = [{\na: 1,\n}, {\nb: 2,\n}];" => "const x = [{\n    a: 1,\n}, {\n    b: 2,\n}];",
+    "if (foo) {\n\tbar();\n}" => "if (foo) {\n    bar();\n}",
+    "switch (foo) {\n    case 1:\n        bar();\n}" => "switch (foo) {\ncase 1:\n    bar();\n}",
+    "const a = `\n  foo ${bar}\n`;" => "const a = `\n  foo ${bar}\n`;"
+}
+
+fix_tests! {
+    tabs_fixes,
+    Indent { style: "tabs".to_string(), ..Indent::default() },
+    "if (foo) {\n    bar();\n}" => "if (foo) {\n\tbar();\n}",
+    "function foo() {\n\tif (bar) {\n  return baz;\n\t}\n}" => "function foo() {\n\tif (bar) {\n\t\treturn baz;\n\t}\n}"
+}
@@ -0,0 +1,245 @@
+use crate::rule_prelude::*;
+use crate::Inferable;
+use std::collections::HashMap;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce consistent spacing before and after keywords.
+
+    Spaces are required before and after keywords by default, the spacing of single keywords can be
+    changed with `overrides`:
+
+    ```toml
+    [rules.errors.keyword-spacing]
+    overrides = { if = { after = false }, catch = { after = false } }
+    ```
+
+    Like in ESLint, only spacing which is optional is checked. This is spacing between keywords and brackets,
+    operators such as `!` and `-`, strings, templates, and regexes, such as in `}else` or `if(`.
+    The spacing between the parentheses of calls and `function`, `async`, and `import` is left to
+    the "space-before-function-paren" rule, and the spacing after keywords like `this` or `null`
+    which are used as values is not checked.
+
+    ## Incorrect Code Examples
+
+    ```js
+    if(foo) {}
+    ```
+
+    ```js
+    if (foo) {
+        bar();
+    }else{
+        baz();
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    if (foo) {
+        bar();
+    } else {
+        baz();
+    }
+    ```
+
+    ```js
+    let foo = this.bar;
+    ```
+    */
+    #[serde(default)]
+    KeywordSpacing,
+    style,
+    "keyword-spacing",
+    /// Require a space before keywords (true by default).
+    pub before: bool,
+    /// Require a space after keywords (true by default).
+    pub after: bool,
+    /// The spacing of single keywords, which overrides `before` and `after`.
+    pub overrides: HashMap<String, KeywordOverride>
+}
+
+/// The spacing of a single keyword, the options which are not set use the spacing of all keywords.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct KeywordOverride {
+    #[serde(default)]
+    pub before: Option<bool>,
+    #[serde(default)]
+    pub after: Option<bool>,
+}
+
+impl Default for KeywordSpacing {
+    fn default() -> Self {
+        Self {
+            before: true,
+            after: true,
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for KeywordSpacing {
+    fn check_token(&self, token: &SyntaxToken, ctx: &mut RuleCtx) -> Option<()> {
+        if !token.kind().is_keyword() {
+            return None;
+        }
+        let spacing = self.overrides.get(token.text().as_str());
+
+        if checks_before(token) {
+            let before = spacing.and_then(|x| x.before).unwrap_or(self.before);
+            util::check_adjacent_whitespace(ctx, self.name(), token, true, before);
+        }
+        if checks_after(token) {
+            let after = spacing.and_then(|x| x.after).unwrap_or(self.after);
+            util::check_adjacent_whitespace(ctx, self.name(), token, false, after);
+        }
+        None
+    }
+}
+
+/// Whether the spacing before a keyword is checked, which is the case if the token before it is a closing bracket,
+/// a string, a template, or a regex.
+fn checks_before(keyword: &SyntaxToken) -> bool {
+    let prev = std::iter::successors(keyword.prev_token(), |token| token.prev_token())
+        .find(|token| !token.kind().is_trivia());
+    prev.map_or(false, |prev| {
+        matches!(
+            prev.kind(),
+            R_PAREN | R_BRACK | R_CURLY | R_ANGLE | STRING | REGEX | BACKTICK
+        )
+    })
+}
+
+/// Whether the spacing after a keyword is checked, which is the case if the token after it is an opening bracket,
+/// a prefix operator, a string, a template, or a regex.
+fn checks_after(keyword: &SyntaxToken) -> bool {
+    if matches!(
+        keyword.kind(),
+        THIS_KW | SUPER_KW | NULL_KW | TRUE_KW | FALSE_KW
+    ) {
+        return false;
+    }
+    let next = std::iter::successors(keyword.next_token(), |token| token.next_token())
+        .find(|token| !token.kind().is_trivia());
+    next.map_or(false, |next| match next.kind() {
+        L_PAREN => !matches!(keyword.kind(), FUNCTION_KW | ASYNC_KW | IMPORT_KW),
+        L_BRACK | L_CURLY | L_ANGLE | TILDE | BANG | PLUS | PLUS2 | MINUS | MINUS2 | STRING
+        | REGEX | BACKTICK => true,
+        _ => false,
+    })
+}
+
+#[typetag::serde]
+impl Inferable for KeywordSpacing {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let keywords = nodes
+            .iter()
+            .flat_map(|node| node.children_with_tokens())
+            .filter_map(|elem| elem.into_token())
+            .filter(|token| token.kind().is_keyword())
+            .collect::<Vec<_>>();
+
+        let before = keywords
+            .iter()
+            .filter(|keyword| checks_before(keyword))
+            .filter_map(|keyword| util::adjacent_whitespace(keyword, true))
+            .map(|whitespace| util::tokens_have_whitespace(&whitespace, false))
+            .collect::<Vec<_>>();
+        if !before.is_empty() {
+            self.before = util::most_frequent(before);
+        }
+
+        let after = keywords
+            .iter()
+            .filter(|keyword| checks_after(keyword))
+            .filter_map(|keyword| util::adjacent_whitespace(keyword, false))
+            .map(|whitespace| util::tokens_have_whitespace(&whitespace, false))
+            .collect::<Vec<_>>();
+        if !after.is_empty() {
+            self.after = util::most_frequent(after);
+        }
+    }
+}
+
+rule_tests! {
+    KeywordSpacing::default(),
+    err: {
+        "if(foo) {}",
+        "if (foo) {}else {}",
+        "if (foo) {} else{}",
+        "for(;;) {}",
+        "while(foo) {}",
+        "try {} catch(e) {}",
+        "function foo() { return[1]; }",
+        "function foo() { return'bar'; }",
+        "typeof(foo);",
+        "do {}while (foo);"
+    },
+    ok: {
+        "if (foo) {} else {}",
+        "let foo = this.bar;",
+        "class A extends B { constructor() { super(foo); } }",
+        "let foo = function() {};",
+        "import('foo');",
+        "foo(function () {});",
+        "let foo = [this];",
+        "if (foo) {}\nelse {}",
+        "function foo() { return; }",
+        "foo(a, typeof b);"
+    }
+}
+
+rule_tests! {
+    override_valid,
+    override_invalid,
+    KeywordSpacing {
+        overrides: vec![(
+            "if".to_string(),
+            KeywordOverride { before: None, after: Some(false) },
+        )]
+        .into_iter()
+        .collect(),
+        ..KeywordSpacing::default()
+    },
+    err: {
+        "if (foo) {}",
+        "while(foo) {}"
+    },
+    ok: {
+        "if(foo) {}",
+        "while (foo) {}",
+        "if(foo) {} else {}"
+    }
+}
+
+fix_tests! {
+    KeywordSpacing::default(),
+    "if(foo) {}" => "if (foo) {}",
+    "if (foo) {}else{}" => "if (foo) {} else {}",
+    "try {} catch(e) {}finally {}" => "try {} catch (e) {} finally {}",
+    "function foo() { return[1]; }" => "function foo() { return [1]; }",
+    "typeof(foo);" => "typeof (foo);",
+    "do {}while (foo);" => "do {} while (foo);",
+    "if (foo) {}/* bar */else {}" => "if (foo) {}/* bar */else {}"
+}
+
+fix_tests! {
+    override_fixes,
+    KeywordSpacing {
+        before: false,
+        after: false,
+        overrides: vec![(
+            "else".to_string(),
+            KeywordOverride { before: Some(true), after: None },
+        )]
+        .into_iter()
+        .collect(),
+    },
+    "if (foo) {} else {}" => "if(foo) {} else{}",
+    "for (;;) {}" => "for(;;) {}",
+    "let x = /a/ instanceof RegExp;" => "let x = /a/ instanceof RegExp;"
+}
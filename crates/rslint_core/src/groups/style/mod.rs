@@ -5,5 +5,17 @@ use crate::group;
 group! {
     /// Rules which relate to code style and formatting.
     style,
-    block_spacing::BlockSpacing
+    block_spacing::BlockSpacing,
+    indent::Indent,
+    no_var::NoVar,
+    prefer_const::PreferConst,
+    no_useless_concat::NoUselessConcat,
+    quotes::Quotes,
+    semi::Semi,
+    comma_dangle::CommaDangle,
+    comma_spacing::CommaSpacing,
+    keyword_spacing::KeywordSpacing,
+    space_infix_ops::SpaceInfixOps,
+    space_before_function_paren::SpaceBeforeFunctionParen,
+    arrow_spacing::ArrowSpacing,
 }
@@ -0,0 +1,211 @@
+use crate::rule_prelude::*;
+use ast::{BinExpr, BinOp, Expr};
+use rslint_parser::TextRange;
+
+declare_lint! {
+    /**
+    Disallow concatenating two string literals on the same line.
+
+    Concatenating two literals with `+` is the same as writing a single literal, `"a" + "b"` can just be
+    `"ab"`. Literals which are concatenated across multiple lines are allowed, since that is a common way of
+    splitting long strings.
+
+    The literals are merged automatically, the merged literal uses the quotes of the left literal, or backticks
+    if either of the literals is a template literal.
+
+    ## Incorrect Code Examples
+
+    ```js
+    const a = "some" + "string";
+    ```
+
+    ```js
+    const b = '1' + "0";
+    ```
+
+    ```js
+    const c = foo + "a" + `b`;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const a = "somestring";
+    ```
+
+    ```js
+    const b = "a" + foo;
+    ```
+
+    ```js
+    const c = "a long string which " +
+        "spans multiple lines";
+    ```
+    */
+    #[derive(Default)]
+    NoUselessConcat,
+    style,
+    "no-useless-concat"
+}
+
+#[typetag::serde]
+impl CstRule for NoUselessConcat {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let bin = node.try_to::<BinExpr>()?;
+        if bin.op()? != BinOp::Plus {
+            return None;
+        }
+        // `a + "b" + "c"` is `(a + "b") + "c"`, so the literals are the innermost operands next to the `+`
+        let left = operand(bin.lhs()?, false);
+        let right = operand(bin.rhs()?, true);
+        if !is_string_like(&left) || !is_string_like(&right) {
+            return None;
+        }
+
+        let range = TextRange::new(
+            left.syntax().text_range().start(),
+            right.syntax().text_range().end(),
+        );
+        let between = TextRange::new(
+            left.syntax().text_range().end(),
+            right.syntax().text_range().start(),
+        );
+        let between_text = node
+            .text()
+            .slice(between - node.text_range().start())
+            .to_string();
+        if between_text.contains(&['\n', '\r', '\u{2028}', '\u{2029}'][..]) {
+            return None;
+        }
+
+        let mut err = ctx
+            .err(self.name(), "unexpected concatenation of two literals")
+            .primary(range, "these literals can be written as a single literal");
+        // do not remove any comments between the literals
+        if let Some(merged) = merge(&left, &right).filter(|_| between_text.trim() == "+") {
+            // chained concatenations such as `'a' + 'b' + 'c'` share a literal, the other one
+            // is merged when the fix is applied again
+            let fixer = ctx.extend_fix();
+            let overlaps = fixer.indels.iter().any(|indel| {
+                indel.delete.start() < range.end() && range.start() < indel.delete.end()
+            });
+            if !overlaps {
+                fixer.replace(range, merged.clone());
+            }
+            err = err.suggestion(range, "merge the literals", merged, Applicability::Always);
+        }
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// The operand directly next to a `+`, which is the leftmost operand of a concatenation on the right side,
+/// or the rightmost operand of a concatenation on the left side.
+fn operand(expr: Expr, leftmost: bool) -> Expr {
+    match expr {
+        Expr::GroupingExpr(ref group) => {
+            group.inner().map_or(expr, |inner| operand(inner, leftmost))
+        }
+        Expr::BinExpr(ref bin) if bin.op() == Some(BinOp::Plus) => {
+            let next = if leftmost { bin.lhs() } else { bin.rhs() };
+            next.map_or(expr, |next| operand(next, leftmost))
+        }
+        _ => expr,
+    }
+}
+
+fn is_string_like(expr: &Expr) -> bool {
+    match expr {
+        Expr::Literal(literal) => literal.is_string(),
+        Expr::Template(template) => template.tag().is_none(),
+        _ => false,
+    }
+}
+
+/// Merge two string or template literals into a single literal with the same value.
+fn merge(left: &Expr, right: &Expr) -> Option<String> {
+    match (left, right) {
+        (Expr::Literal(left), Expr::Literal(right)) => {
+            let left_text = left.syntax().text().to_string();
+            let right_text = right.syntax().text().to_string();
+            let quote = left_text.chars().next()?;
+            let right_quote = right_text.chars().next()?;
+            let left_inner = left.inner_string_text()?.to_string();
+            let right_inner = right.inner_string_text()?.to_string();
+            Some(format!(
+                "{}{}{}{}",
+                quote,
+                left_inner,
+                util::requote(&right_inner, right_quote, quote)?,
+                quote
+            ))
+        }
+        _ => {
+            // drop the closing backtick of the left and the opening backtick of the right literal
+            let left_part = match left {
+                Expr::Literal(literal) => format!(
+                    "`{}",
+                    util::string_to_template_text(&literal.inner_string_text()?.to_string(), true)?
+                ),
+                _ => {
+                    let text = left.syntax().text().to_string();
+                    text[..text.len() - 1].to_string()
+                }
+            };
+            let right_part = match right {
+                Expr::Literal(literal) => format!(
+                    "{}`",
+                    util::string_to_template_text(&literal.inner_string_text()?.to_string(), true)?
+                ),
+                _ => right.syntax().text().to_string()[1..].to_string(),
+            };
+            // `$` and `{` would become a substitution
+            if left_part.ends_with('$') && right_part.starts_with('{') {
+                return None;
+            }
+            Some(left_part + &right_part)
+        }
+    }
+}
+
+rule_tests! {
+    NoUselessConcat::default(),
+    err: {
+        "'a' + 'b';",
+        "'a' + \"b\";",
+        "foo + 'a' + 'b';",
+        "'a' + 'b' + 'c';",
+        "`a` + 'b';",
+        "'a' + `b`;",
+        "`a${foo}` + `b`;",
+        "(foo + 'a') + ('b');",
+        "'a' + /* comment */ 'b';",
+        "'a' + ('b' + foo);"
+    },
+    ok: {
+        "'a' + foo;",
+        "'a' + 1;",
+        "1 + 1;",
+        "foo + bar;",
+        "'a' +\n'b';",
+        "'a'\n+ 'b';",
+        "tag`a` + 'b';",
+        "'a' - 'b';"
+    }
+}
+
+fix_tests! {
+    NoUselessConcat::default(),
+    "'a' + 'b';" => "'ab';",
+    "'a' + \"b\";" => "'ab';",
+    "'a' + \"b'c\";" => "'ab\\'c';",
+    "foo + 'a' + 'b';" => "foo + 'ab';",
+    "'a' + 'b' + 'c';" => "'a' + 'bc';",
+    "`a` + 'b';" => "`ab`;",
+    "'a' + `b${foo}`;" => "`ab${foo}`;",
+    "'$' + `{foo}`;" => "'$' + `{foo}`;",
+    "'a`' + `b`;" => "`a\\`b`;",
+    "(foo + 'a') + ('b');" => "(foo + 'a') + ('b');",
+    "'a' + /* comment */ 'b';" => "'a' + /* comment */ 'b';",
+    "let a = 'a' + 'b';\nlet b = `c` + 'd';\nlet c = 'e' + \"f\";" => "let a = 'ab';\nlet b = `cd`;\nlet c = 'ef';"
+}
@@ -0,0 +1,263 @@
+use crate::rule_prelude::*;
+use crate::semantic::{Binding, BindingKind, Reference, ScopeKind, SemanticModel};
+use ast::VarDecl;
+use rslint_parser::TextSize;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Require `let` or `const` instead of `var`.
+
+    `var` declarations are scoped to the whole function they are in and can be used before they are
+    declared, which is a common source of bugs. `let` and `const` are scoped to the block they are
+    declared in and cannot be used before their declaration.
+
+    Declarations are automatically converted to `const` if they are never reassigned, and to `let` otherwise.
+    A declaration is only converted if doing so cannot change the behavior of the code, declarations are
+    not converted if they are:
+     - at the top level of a script, since they are properties of the global object
+     - redeclared, or declared with the same name as a parameter or function
+     - used outside of the block they are declared in
+     - used before they are declared, or used by a function which may be called before they are declared
+     - declared inside of a loop without a value, or used by a function created inside of the loop
+     - declared in a `case` clause or as the body of a statement such as `if (a) var b;`
+
+    ## Incorrect Code Examples
+
+    ```js
+    var foo = 5;
+    ```
+
+    ```js
+    function bar() {
+        for (var i = 0; i < 10; i++) {}
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const foo = 5;
+    ```
+
+    ```js
+    function bar() {
+        for (let i = 0; i < 10; i++) {}
+    }
+    ```
+    */
+    #[derive(Default)]
+    NoVar,
+    style,
+    "no-var"
+}
+
+#[typetag::serde]
+impl CstRule for NoVar {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let decl = node.try_to::<VarDecl>()?;
+        let var_token = decl.var_token()?;
+        let model = ctx.semantic.clone();
+
+        let mut err = ctx
+            .err(
+                self.name(),
+                "unexpected `var`, use `let` or `const` instead",
+            )
+            .primary(
+                var_token.text_range(),
+                "`var` declarations are scoped to the whole function",
+            );
+        if let Some(keyword) = replacement(&decl, &model) {
+            ctx.extend_fix().replace(var_token.text_range(), keyword);
+            err = err.suggestion(
+                var_token.text_range(),
+                &format!("use `{}` instead", keyword),
+                keyword,
+                Applicability::Always,
+            );
+        }
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// The keyword to replace `var` with, `None` if replacing it might change the behavior of the code.
+fn replacement(decl: &VarDecl, model: &SemanticModel) -> Option<&'static str> {
+    let node = decl.syntax();
+    if !matches!(
+        node.parent()?.kind(),
+        SCRIPT | MODULE | BLOCK_STMT | FOR_STMT_INIT | EXPORT_DECL
+    ) {
+        return None;
+    }
+    let in_for_in_of = matches!(node.parent()?.parent()?.kind(), FOR_IN_STMT | FOR_OF_STMT);
+    let block = model.scope_of(node)?.node.text_range();
+    let bindings = model.var_decl_bindings(decl);
+
+    for binding in &bindings {
+        let scope = model.scope(binding.scope);
+        if binding.kind != BindingKind::Var
+            || binding.declarations.len() > 1
+            || binding.name == "let"
+            || scope.kind == ScopeKind::Global
+        {
+            return None;
+        }
+        let declarator_end = binding
+            .name_node()
+            .ancestors()
+            .find(|ancestor| ancestor.kind() == DECLARATOR)?
+            .text_range()
+            .end();
+        let outside_or_before = model.references_to(binding.id).any(|reference| {
+            let range = reference.node.text_range();
+            !block.contains_range(range)
+                || (!reference.init
+                    && (range.start() < declarator_end
+                        || in_early_function(reference, &scope.node, declarator_end)))
+        });
+        if outside_or_before {
+            return None;
+        }
+    }
+
+    if let Some(loop_node) = enclosing_loop(node) {
+        // `let` creates a new binding for every iteration which starts out as `undefined`
+        let uninitialized = !in_for_in_of
+            && decl
+                .declared()
+                .any(|declarator| declarator.eq_token().is_none());
+        if uninitialized
+            || bindings
+                .iter()
+                .any(|binding| is_used_in_closure(binding, &loop_node, model))
+        {
+            return None;
+        }
+    }
+
+    let is_const = (in_for_in_of
+        || decl
+            .declared()
+            .all(|declarator| declarator.eq_token().is_some()))
+        && bindings
+            .iter()
+            .all(|binding| model.reassignments(binding.id).next().is_none());
+    Some(if is_const { "const" } else { "let" })
+}
+
+/// Whether a reference is inside of a function which may be called before the declaration ends,
+/// which is any function declaration since they are hoisted, or a function created before the declaration.
+fn in_early_function(reference: &Reference, scope: &SyntaxNode, declarator_end: TextSize) -> bool {
+    let functions = reference
+        .node
+        .ancestors()
+        .take_while(|ancestor| ancestor != scope)
+        .filter(|ancestor| util::is_function(ancestor))
+        .collect::<Vec<_>>();
+    functions.iter().any(|function| function.kind() == FN_DECL)
+        || functions
+            .last()
+            .map_or(false, |outer| outer.text_range().start() < declarator_end)
+}
+
+/// The innermost loop containing the declaration inside of the same function, including a loop
+/// whose head the declaration is in.
+fn enclosing_loop(node: &SyntaxNode) -> Option<SyntaxNode> {
+    node.ancestors()
+        .take_while(|ancestor| !util::is_function(ancestor))
+        .find(|ancestor| ancestor.is_loop())
+}
+
+/// Whether any reference to the binding is inside of a function created inside of the loop.
+fn is_used_in_closure(binding: &Binding, loop_node: &SyntaxNode, model: &SemanticModel) -> bool {
+    let loop_range = loop_node.text_range();
+    model.references_to(binding.id).any(|reference| {
+        reference
+            .node
+            .ancestors()
+            .take_while(|ancestor| loop_range.contains_range(ancestor.text_range()))
+            .any(util::is_function)
+    })
+}
+
+rule_tests! {
+    NoVar::default(),
+    err: {
+        "var foo = 5;",
+        "
+        function foo() {
+            var a = 1, b;
+            b = a;
+        }
+        ",
+        "
+        function foo() {
+            for (var i = 0; i < 10; i++) {}
+        }
+        ",
+        "
+        function foo() {
+            for (var i = 0; i < 10; i++) {
+                setTimeout(() => i);
+            }
+        }
+        ",
+        "
+        function foo() {
+            a;
+            var a = 1;
+        }
+        ",
+        "
+        function foo() {
+            if (bar) {
+                var a = 1;
+            }
+            return a;
+        }
+        ",
+        "if (a) var b = 1;",
+        "export var a = 1;",
+        "
+        function f() {
+            g();
+            var a = 1;
+            function g() {
+                return a;
+            }
+        }
+        ",
+        "
+        function f() {
+            const g = () => a;
+            g();
+            var a = 1;
+        }
+        "
+    },
+    ok: {
+        "let foo = 5;",
+        "const foo = 5;",
+        "
+        function foo() {
+            let a = 1;
+            const b = a;
+        }
+        "
+    }
+}
+
+fix_tests! {
+    NoVar::default(),
+    "function foo() {\n    var a = 1, b;\n    b = a;\n}" => "function foo() {\n    let a = 1, b;\n    b = a;\n}",
+    "function foo() {\n    var a = 1;\n    var b = a;\n}" => "function foo() {\n    const a = 1;\n    const b = a;\n}",
+    "function foo() {\n    for (var i = 0; i < 10; i++) {}\n}" => "function foo() {\n    for (let i = 0; i < 10; i++) {}\n}",
+    "function foo(items) {\n    for (var item of items) {}\n}" => "function foo(items) {\n    for (const item of items) {}\n}",
+    "function foo() {\n    a;\n    var a = 1;\n}" => "function foo() {\n    a;\n    var a = 1;\n}",
+    "function f() {\n    g();\n    var a = 1;\n    function g() { return a; }\n}" => "function f() {\n    g();\n    var a = 1;\n    function g() { return a; }\n}",
+    "function f() {\n    const g = () => a;\n    g();\n    var a = 1;\n}" => "function f() {\n    const g = () => a;\n    g();\n    var a = 1;\n}",
+    "function f() {\n    var a = 1;\n    const g = () => a;\n    g();\n}" => "function f() {\n    const a = 1;\n    const g = () => a;\n    g();\n}",
+    "function foo() {\n    for (var i = 0; i < 10; i++) {\n        setTimeout(() => i);\n    }\n}" => "function foo() {\n    for (var i = 0; i < 10; i++) {\n        setTimeout(() => i);\n    }\n}"
+}
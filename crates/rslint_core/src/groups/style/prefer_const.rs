@@ -0,0 +1,262 @@
+use crate::rule_prelude::*;
+use crate::semantic::{pattern_names, Binding, SemanticModel};
+use ast::VarDecl;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Require `const` for variables which are never reassigned.
+
+    A variable declared with `const` cannot be reassigned, which makes it clear to readers that its value
+    never changes. Variables declared with `let` which are only assigned once, when they are declared,
+    can be declared with `const` instead.
+
+    Variables which are declared without a value are reported if they are assigned exactly once afterwards
+    in the same block and not read before that, such as `let a; a = 1;`. These are never fixed, other
+    declarations are only fixed if every variable they declare can be a constant.
+
+    ## Incorrect Code Examples
+
+    ```js
+    let a = 3;
+    console.log(a);
+    ```
+
+    ```js
+    for (let item of items) {
+        console.log(item);
+    }
+    ```
+
+    ```js
+    let a;
+    a = 3;
+    console.log(a);
+    ```
+
+    ```js
+    // with "destructuring": "any", `a` is reported since it is never reassigned
+    let { a, b } = obj;
+    b = 0;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const a = 3;
+    console.log(a);
+    ```
+
+    ```js
+    let a = 3;
+    a += 1;
+    ```
+
+    ```js
+    for (let i = 0; i < 10; i++) {}
+    ```
+
+    ```js
+    let a;
+    if (cond) {
+        a = 0;
+    }
+    ```
+    */
+    #[serde(default)]
+    PreferConst,
+    style,
+    "prefer-const",
+    /// How to treat destructuring declarations where only some of the variables are never reassigned,
+    /// either "any" (default) to report each of those variables, or "all" to only report the declaration
+    /// if none of its variables are reassigned.
+    pub destructuring: String
+}
+
+impl Default for PreferConst {
+    fn default() -> Self {
+        Self {
+            destructuring: "any".to_string(),
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for PreferConst {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let decl = node.try_to::<VarDecl>()?;
+        let let_token = decl.let_token()?;
+        let model = ctx.semantic.clone();
+
+        let mut reported = vec![];
+        let mut all_const = true;
+        for declarator in decl.declared() {
+            let pattern = declarator.pattern()?;
+            let mut names = vec![];
+            pattern_names(pattern.syntax(), &mut names);
+            let bindings = names
+                .iter()
+                .filter_map(|name| model.declared_binding(name))
+                .collect::<Vec<_>>();
+            let constants = bindings
+                .iter()
+                .copied()
+                .filter(|binding| {
+                    is_constant(binding, &model) || assignment_once(binding, &model).is_some()
+                })
+                .collect::<Vec<_>>();
+
+            all_const &= bindings.iter().all(|binding| is_constant(binding, &model));
+            let is_destructuring =
+                matches!(pattern.syntax().kind(), ARRAY_PATTERN | OBJECT_PATTERN);
+            if is_destructuring && self.destructuring == "all" && constants.len() != bindings.len()
+            {
+                continue;
+            }
+            reported.extend(constants);
+        }
+
+        if all_const && !reported.is_empty() {
+            ctx.extend_fix().replace(let_token.text_range(), "const");
+        }
+        for binding in reported {
+            let err = ctx.err(
+                self.name(),
+                format!(
+                    "`{}` is never reassigned, use `const` instead",
+                    binding.name
+                ),
+            );
+            let mut err = if let Some(assignment) = assignment_once(binding, &model) {
+                err.primary(
+                    assignment.text_range(),
+                    format!("`{}` is only assigned a value here", binding.name),
+                )
+                .secondary(binding.range(), "but it is declared without a value here")
+            } else {
+                err.primary(
+                    binding.range(),
+                    format!("`{}` is only assigned a value here", binding.name),
+                )
+            };
+            if all_const {
+                err = err.suggestion(
+                    let_token.text_range(),
+                    "declare it with `const`",
+                    "const",
+                    Applicability::Always,
+                );
+            }
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+/// Whether a binding is assigned a value when it is declared and never reassigned after.
+fn is_constant(binding: &Binding, model: &SemanticModel) -> bool {
+    model.writes(binding.id).any(|write| write.init)
+        && model.reassignments(binding.id).next().is_none()
+}
+
+/// The assignment of a binding which is declared without a value and assigned exactly once after, such as
+/// `let a; a = 1;`. The assignment must be in the same block as the declaration and come before any reads.
+fn assignment_once(binding: &Binding, model: &SemanticModel) -> Option<SyntaxNode> {
+    let mut writes = model.writes(binding.id);
+    let write = writes.next().filter(|write| !write.init)?;
+    if writes.next().is_some() || write.node.kind() != NAME_REF {
+        return None;
+    }
+    let assignment = write.node.parent().filter(|parent| {
+        parent.kind() == ASSIGN_EXPR
+            && parent.first_child().as_ref() == Some(&write.node)
+            && parent.children_with_tokens().any(|elem| elem.kind() == EQ)
+    })?;
+    let stmt = assignment
+        .parent()
+        .filter(|parent| parent.kind() == EXPR_STMT)?;
+    let read_before = model
+        .reads(binding.id)
+        .any(|read| read.node.text_range().start() < assignment.text_range().end());
+
+    if stmt.parent() == binding.decl.parent() && !read_before {
+        Some(assignment)
+    } else {
+        None
+    }
+}
+
+rule_tests! {
+    PreferConst::default(),
+    err: {
+        "let a = 3; console.log(a);",
+        "let a = 3;",
+        "for (let item of items) {}",
+        "for (let key in obj) {}",
+        "let [a, b] = arr; b = 1;",
+        "let { a, b } = obj; b = 0;",
+        "
+        let a = 1;
+        function foo() {
+            return a;
+        }
+        ",
+        "let a = 1, b; b = 2;",
+        "let a; a = 0;",
+        "let a; a = 1; use(a);",
+        "
+        function foo() {
+            let a;
+            a = 1;
+            return a;
+        }
+        "
+    },
+    ok: {
+        "const a = 3;",
+        "let a = 3; a += 1;",
+        "let a; use(a); a = 1;",
+        "let a; a = a + 1;",
+        "let a; a = 1; a = 2;",
+        "let a; { a = 1; }",
+        "let a; [a] = arr;",
+        "let a; a += 1;",
+        "let a; foo(a = 1);",
+        "let a;",
+        "for (let i = 0; i < 10; i++) {}",
+        "let [a, b] = arr; [a, b] = [b, a];",
+        "
+        let a = 1;
+        function foo() {
+            a = 2;
+        }
+        ",
+        "var a = 1;"
+    }
+}
+
+rule_tests! {
+    destructuring_all_valid,
+    destructuring_all_invalid,
+    PreferConst {
+        destructuring: "all".to_string(),
+    },
+    err: {
+        "let { a, b } = obj;",
+        "let a = 1, { b, c } = obj; c = 2;"
+    },
+    ok: {
+        "let { a, b } = obj; b = 0;",
+        "let [a, b] = arr; b = 1;"
+    }
+}
+
+fix_tests! {
+    PreferConst::default(),
+    "let a = 3; let b = a;" => "const a = 3; const b = a;",
+    "for (let item of items) {}" => "for (const item of items) {}",
+    "let [a, b] = arr;" => "const [a, b] = arr;",
+    "let { a, b } = obj; b = 0;" => "let { a, b } = obj; b = 0;",
+    "let a = 1, b; b = 2;" => "let a = 1, b; b = 2;",
+    "let a; a = 1;" => "let a; a = 1;"
+}
@@ -0,0 +1,335 @@
+use crate::rule_prelude::*;
+use crate::Inferable;
+use ast::{Literal, Template};
+use rslint_parser::TextRange;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce the consistent use of either double quotes, single quotes, or backticks for strings.
+
+    Template literals without substitutions, linebreaks, or a tag are also reported unless `allowTemplateLiterals`
+    is enabled. The quotes are changed automatically, quotes in the string are escaped and escaped quotes which
+    no longer have to be escaped are unescaped.
+
+    Strings which can not be template literals, such as directives like `"use strict"`, property keys, and
+    the sources of imports and exports, are not reported when using backticks.
+
+    ## Incorrect Code Examples
+
+    ```js
+    let foo = 'bar';
+    ```
+
+    ```js
+    let foo = `bar`;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let foo = "bar";
+    ```
+
+    ```js
+    let foo = `bar ${baz}`;
+    ```
+
+    ```js
+    let foo = tag`bar`;
+    ```
+    */
+    #[serde(default)]
+    Quotes,
+    style,
+    "quotes",
+    /// The quotes to use, either "double" (default), "single", or "backtick".
+    pub style: String,
+    /// Allow strings to use other quotes if they contain the quotes which would otherwise have to be escaped,
+    /// such as `'a "b" c'` when using double quotes (false by default).
+    pub avoid_escape: bool,
+    /// Allow template literals without substitutions when not using backticks (false by default).
+    pub allow_template_literals: bool
+}
+
+impl Default for Quotes {
+    fn default() -> Self {
+        Self {
+            style: "double".to_string(),
+            avoid_escape: false,
+            allow_template_literals: false,
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for Quotes {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let template = node.try_to::<Template>()?;
+        if self.quote() == '`' || self.allow_template_literals {
+            return None;
+        }
+        let text = node.text().to_string();
+        if template.tag().is_some()
+            || template.elements().next().is_some()
+            || parseutil::contains_js_linebreak(&text)
+        {
+            return None;
+        }
+        self.check(node.text_range(), &text, ctx)
+    }
+
+    fn check_token(&self, token: &SyntaxToken, ctx: &mut RuleCtx) -> Option<()> {
+        if token.kind() != STRING {
+            return None;
+        }
+        if self.quote() == '`' && !can_be_template(token) {
+            return None;
+        }
+        self.check(token.text_range(), token.text(), ctx)
+    }
+}
+
+impl Quotes {
+    fn quote(&self) -> char {
+        match self.style.as_str() {
+            "single" => '\'',
+            "backtick" => '`',
+            _ => '"',
+        }
+    }
+
+    /// Check a string or template literal with its quotes.
+    fn check(&self, range: TextRange, text: &str, ctx: &mut RuleCtx) -> Option<()> {
+        let from = text.chars().next()?;
+        let to = self.quote();
+        let inner = &text[from.len_utf8()..text.len() - from.len_utf8()];
+        if from == to || (self.avoid_escape && inner.contains(to)) {
+            return None;
+        }
+
+        let mut err = ctx
+            .err(self.name(), format!("strings must use {}", describe(to)))
+            .primary(range, format!("this string uses {}", describe(from)));
+        if let Some(requoted) = util::requote(inner, from, to) {
+            let replacement = format!("{}{}{}", to, requoted, to);
+            ctx.extend_fix().replace(range, replacement.clone());
+            err = err.suggestion(
+                range,
+                &format!("use {}", describe(to)),
+                replacement,
+                Applicability::Always,
+            );
+        }
+        ctx.add_err(err);
+        None
+    }
+}
+
+fn describe(quote: char) -> &'static str {
+    match quote {
+        '\'' => "single quotes",
+        '`' => "backticks",
+        _ => "double quotes",
+    }
+}
+
+/// Whether a string could be a template literal, which is not the case for directives, property keys,
+/// the names of TypeScript signatures and enum members, and strings which are not expressions such as the
+/// sources of imports and exports or the names of `declare module` declarations.
+fn can_be_template(token: &SyntaxToken) -> bool {
+    let literal = token.parent();
+    if literal.kind() != LITERAL {
+        return false;
+    }
+    let parent = match literal.parent() {
+        Some(parent) => parent,
+        None => return false,
+    };
+    match parent.kind() {
+        METHOD | GETTER | SETTER | TS_PROPERTY_SIGNATURE | TS_METHOD_SIGNATURE | TS_INDEX_SIGNATURE
+        | TS_ENUM_MEMBER | SPECIFIER => false,
+        LITERAL_PROP | KEY_VALUE_PATTERN | CLASS_PROP => parent.first_child() != Some(literal),
+        EXPR_STMT => !is_directive(&parent),
+        _ => true,
+    }
+}
+
+/// Whether an expression statement is a directive, which is a string at the start of a script, module,
+/// or function body.
+fn is_directive(stmt: &SyntaxNode) -> bool {
+    let body = match stmt.parent() {
+        Some(body) => body,
+        None => return false,
+    };
+    let is_body = match body.kind() {
+        SCRIPT | MODULE => true,
+        BLOCK_STMT => body.parent().map_or(false, util::is_function),
+        _ => false,
+    };
+    is_body
+        && body
+            .children()
+            .take_while(|child| child != stmt)
+            .all(|child| {
+                child.kind() == EXPR_STMT
+                    && child
+                        .first_child()
+                        .and_then(|expr| expr.try_to::<Literal>())
+                        .map_or(false, |literal| literal.is_string())
+            })
+}
+
+#[typetag::serde]
+impl Inferable for Quotes {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let styles = nodes
+            .iter()
+            .filter_map(|node| node.try_to::<Literal>())
+            .filter(|literal| literal.is_string())
+            .filter_map(|literal| match literal.token().text().chars().next() {
+                Some('\'') => Some("single"),
+                Some('"') => Some("double"),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if !styles.is_empty() {
+            self.style = util::most_frequent(styles).to_string();
+        }
+    }
+}
+
+rule_tests! {
+    Quotes::default(),
+    err: {
+        "let foo = 'bar';",
+        "let foo = `bar`;",
+        "let foo = 'a \"b\" c';",
+        "let foo = 'a \\' b';",
+        "foo('bar', \"baz\");",
+        "'use strict';",
+        "import foo from 'foo';",
+        "export * from 'foo';",
+        "let foo = { 'a': 1 };"
+    },
+    ok: {
+        "let foo = \"bar\";",
+        "let foo = \"a 'b' c\";",
+        "let foo = `bar ${baz}`;",
+        "let foo = tag`bar`;",
+        "let foo = `a\nb`;",
+        "import foo from \"foo\";",
+        "let foo = 5;"
+    }
+}
+
+rule_tests! {
+    single_valid,
+    single_invalid,
+    Quotes { style: "single".to_string(), ..Quotes::default() },
+    err: {
+        "let foo = \"bar\";",
+        "let foo = `bar`;",
+        "let foo = \"a 'b' c\";"
+    },
+    ok: {
+        "let foo = 'bar';",
+        "let foo = 'a \"b\" c';"
+    }
+}
+
+rule_tests! {
+    backtick_valid,
+    backtick_invalid,
+    Quotes { style: "backtick".to_string(), ..Quotes::default() },
+    err: {
+        "let foo = 'bar';",
+        "let foo = \"bar\";",
+        "foo({ [\"a\"]: 'b' });",
+        "function foo() { bar(); 'baz'; }"
+    },
+    ok: {
+        "let foo = `bar`;",
+        "'use strict';",
+        "function foo() { 'use strict'; \"use asm\"; }",
+        "import foo from 'foo';",
+        "export { foo } from 'foo';",
+        "let foo = { 'a': 1, b: `c` };",
+        "class A { 'a'() {} get 'b'() {} 'c' = `d`; }",
+        "let { 'a': b } = c;"
+    }
+}
+
+rule_tests! {
+    avoid_escape_valid,
+    avoid_escape_invalid,
+    Quotes { avoid_escape: true, allow_template_literals: true, ..Quotes::default() },
+    err: {
+        "let foo = 'bar';",
+        "let foo = 'a \\'b\\' c';"
+    },
+    ok: {
+        "let foo = 'a \"b\" c';",
+        "let foo = `bar`;",
+        "let foo = `a \"b\" c`;"
+    }
+}
+
+fix_tests! {
+    Quotes::default(),
+    "let foo = 'bar';" => "let foo = \"bar\";",
+    "foo('bar', 'baz');" => "foo(\"bar\", \"baz\");",
+    "let foo = `bar`;" => "let foo = \"bar\";",
+    "let foo = 'a \"b\" c';" => "let foo = \"a \\\"b\\\" c\";",
+    "let foo = 'a \\' b';" => "let foo = \"a ' b\";",
+    "let foo = 'a \\\\' + 'b';" => "let foo = \"a \\\\\" + \"b\";",
+    "let foo = 'a \\\" b';" => "let foo = \"a \\\" b\";",
+    "let foo = 'a\\nb \\u0027';" => "let foo = \"a\\nb \\u0027\";",
+    "let foo = `a \\` \" b`;" => "let foo = \"a ` \\\" b\";",
+    "let foo = `a \\${b}`;" => "let foo = \"a \\${b}\";",
+    "let foo = `bar ${baz}`;" => "let foo = `bar ${baz}`;"
+}
+
+fix_tests! {
+    backtick_fixes,
+    Quotes { style: "backtick".to_string(), ..Quotes::default() },
+    "let foo = 'bar';" => "let foo = `bar`;",
+    "let foo = 'a ` b';" => "let foo = `a \\` b`;",
+    "let foo = '${a}';" => "let foo = `\\${a}`;",
+    "let foo = '$a {b}';" => "let foo = `$a {b}`;",
+    "let foo = 'a \\' \\\" b';" => "let foo = `a ' \\\" b`;",
+    "'use strict'; let foo = { 'a': 'b' };" => "'use strict'; let foo = { 'a': `b` };"
+}
+
+rule_tests! {
+    typescript,
+    backtick_typescript_valid,
+    backtick_typescript_invalid,
+    Quotes { style: "backtick".to_string(), ..Quotes::default() },
+    err: {
+        "let foo: string = 'bar';"
+    },
+    ok: {
+        "interface I { 'a': string; 'b'(): void; }",
+        "type T = { 'a': number, ['b']: string };",
+        "enum E { 'a' = 1 }",
+        "declare module 'foo' {}",
+        "type T = import('foo').Bar;"
+    }
+}
+
+fix_tests! {
+    typescript,
+    backtick_typescript_fixes,
+    Quotes { style: "backtick".to_string(), ..Quotes::default() },
+    "interface I { 'a': string; }\nlet foo = 'bar';" => "interface I { 'a': string; }\nlet foo = `bar`;",
+    "type T = { 'a': number };" => "type T = { 'a': number };",
+    "declare module 'foo' {}\ntype T = import('bar').Baz;" => "declare module 'foo' {}\ntype T = import('bar').Baz;"
+}
+
+fix_tests! {
+    single_fixes,
+    Quotes { style: "single".to_string(), ..Quotes::default() },
+    "let foo = \"a 'b' c\";" => "let foo = 'a \\'b\\' c';",
+    "let foo = \"a \\\" b\";" => "let foo = 'a \" b';"
+}
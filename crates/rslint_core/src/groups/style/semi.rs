@@ -0,0 +1,293 @@
+use crate::rule_prelude::*;
+use crate::util::StyleExt;
+use crate::Inferable;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Require or disallow semicolons at the end of statements.
+
+    JavaScript inserts semicolons automatically at the end of a line if the next line can not continue the
+    statement (ASI). However, a line beginning with `(`, `[`, `` ` ``, `+`, `-`, or `/` continues the statement
+    on the previous line, which is why code without semicolons must start such lines with a semicolon.
+
+    When disallowing semicolons, a semicolon before a line beginning with one of these characters is moved
+    to the start of that line instead of being removed. Semicolons which separate statements on the same line
+    are required and therefore allowed.
+
+    ## Always
+
+    ### Incorrect code examples
+
+    ```js
+    let foo = 5
+    foo()
+    ```
+
+    ### Correct code examples
+
+    ```js
+    let foo = 5;
+    foo();
+    ```
+
+    ## Never
+
+    ### Incorrect code examples
+
+    ```js
+    let foo = 5;
+    foo();
+    ```
+
+    ```js
+    foo();
+    [1, 2].forEach(bar)
+    ```
+
+    ### Correct code examples
+
+    ```js
+    let foo = 5
+    foo()
+    ```
+
+    ```js
+    foo()
+    ;[1, 2].forEach(bar)
+    ```
+
+    ```js
+    let foo = 5; foo()
+    ```
+    */
+    #[serde(default)]
+    #[derive(rslint_macros::Mergeable)]
+    Semi,
+    style,
+    "semi",
+    /// Whether to require semicolons, either "always" (default) or "never".
+    pub style: String
+}
+
+impl Default for Semi {
+    fn default() -> Self {
+        Self {
+            style: "always".to_string(),
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for Semi {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !is_terminated(node) {
+            return None;
+        }
+
+        let never = self.style == "never";
+        match semicolon(node) {
+            None if !never => {
+                let last = last_token(node)?;
+                let err = ctx
+                    .err(self.name(), "missing semicolon")
+                    .primary(last.text_range(), "a semicolon is expected after this");
+                ctx.add_err(err);
+                ctx.extend_fix().insert_after(last.text_range(), ";");
+                None
+            }
+            Some(semi) if never => {
+                let next = next_token(&semi);
+                let removable = next.as_ref().map_or(true, |next| {
+                    next.kind() == R_CURLY || has_linebreak_after(&semi)
+                });
+                if !removable {
+                    return None;
+                }
+
+                match next.filter(|next| next.kind() != R_CURLY && is_asi_hazard(next)) {
+                    Some(next) => {
+                        let err = ctx
+                            .err(
+                                self.name(),
+                                "semicolons should only be used at the start of lines",
+                            )
+                            .primary(
+                                semi.text_range(),
+                                "this semicolon should be moved to the start of the next line...",
+                            )
+                            .secondary(
+                                next.text_range(),
+                                format!(
+                                    "...because this `{}` would otherwise continue the statement",
+                                    next.text()
+                                ),
+                            );
+                        ctx.add_err(err);
+                        ctx.extend_fix()
+                            .delete(semi.text_range())
+                            .insert_before(next.text_range(), ";");
+                    }
+                    None => {
+                        let err = ctx
+                            .err(self.name(), "unnecessary semicolon")
+                            .primary(semi.text_range(), "this semicolon can be removed");
+                        ctx.add_err(err);
+                        ctx.extend_fix().delete(semi.text_range());
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Whether a node is a statement or class property which is terminated by a semicolon.
+fn is_terminated(node: &SyntaxNode) -> bool {
+    match node.kind() {
+        EXPR_STMT | DO_WHILE_STMT | RETURN_STMT | THROW_STMT | BREAK_STMT | CONTINUE_STMT
+        | DEBUGGER_STMT | IMPORT_DECL | EXPORT_NAMED | EXPORT_WILDCARD | EXPORT_DEFAULT_EXPR
+        | CLASS_PROP | PRIVATE_PROP => true,
+        VAR_DECL => node
+            .parent()
+            .map_or(true, |parent| parent.kind() != FOR_STMT_INIT),
+        _ => false,
+    }
+}
+
+fn semicolon(node: &SyntaxNode) -> Option<SyntaxToken> {
+    node.children_with_tokens()
+        .filter_map(|elem| elem.into_token())
+        .find(|token| token.kind() == SEMICOLON)
+}
+
+fn last_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    std::iter::successors(node.last_token(), |token| token.prev_token())
+        .take_while(|token| token.text_range().start() >= node.text_range().start())
+        .find(|token| !token.kind().is_trivia())
+}
+
+fn next_token(token: &SyntaxToken) -> Option<SyntaxToken> {
+    std::iter::successors(token.next_token(), |token| token.next_token())
+        .find(|token| !token.kind().is_trivia())
+        .filter(|token| token.kind() != EOF)
+}
+
+fn has_linebreak_after(token: &SyntaxToken) -> bool {
+    token
+        .trailing_trivia(false)
+        .iter()
+        .any(|trivia| parseutil::contains_js_linebreak(trivia.text().as_str()))
+}
+
+/// Whether a token at the start of a line would continue the statement on the previous line
+/// if it is not terminated by a semicolon. `*` is included for generator methods after class properties.
+fn is_asi_hazard(token: &SyntaxToken) -> bool {
+    !matches!(token.kind(), PLUS2 | MINUS2)
+        && token
+            .text()
+            .starts_with(&['(', '[', '`', '+', '-', '/', '*'][..])
+}
+
+#[typetag::serde]
+impl Inferable for Semi {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let styles = nodes
+            .iter()
+            .filter(|node| is_terminated(node))
+            .filter_map(|node| match semicolon(node) {
+                // semicolons separating statements on the same line are used by both styles
+                Some(semi) if next_token(&semi).is_some() && !has_linebreak_after(&semi) => None,
+                Some(_) => Some("always"),
+                None => Some("never"),
+            })
+            .collect::<Vec<_>>();
+        if !styles.is_empty() {
+            self.style = util::most_frequent(styles).to_string();
+        }
+    }
+}
+
+rule_tests! {
+    Semi::default(),
+    err: {
+        "let foo = 5",
+        "foo()",
+        "foo()\nbar();",
+        "function foo() { return 5 }",
+        "do {} while (foo)",
+        "for (;;) { break }",
+        "throw foo",
+        "debugger",
+        "class A { a = 5\n #b }",
+        "import foo from 'bar'",
+        "export * from 'foo'",
+        "export default foo"
+    },
+    ok: {
+        "let foo = 5;",
+        "foo();",
+        "for (let i = 0; i < 5; i++) {}",
+        "for (const foo of bar) {}",
+        "function foo() { return 5; }",
+        "class A { a = 5; #b; foo() {} }",
+        "if (foo) {}",
+        ";"
+    }
+}
+
+rule_tests! {
+    never_valid,
+    never_invalid,
+    Semi { style: "never".to_string() },
+    err: {
+        "let foo = 5;",
+        "foo();\nbar()",
+        "function foo() { return 5; }",
+        "foo(); // bar\nbaz()",
+        "foo();\n(bar)()",
+        "foo();\n[1, 2].forEach(bar)",
+        "foo();\n`bar`",
+        "foo();\n-bar",
+        "foo();\n/bar/.test(baz)",
+        "class A { a = 5;\n *b() {} }"
+    },
+    ok: {
+        "let foo = 5",
+        "foo(); bar()",
+        "foo()\n;(bar)()",
+        "foo()\n;[1, 2].forEach(bar)",
+        "for (let i = 0; i < 5; i++) {}",
+        "do foo(); while (bar)",
+        "if (foo) bar(); else baz()",
+        "foo\n++bar",
+        ";"
+    }
+}
+
+fix_tests! {
+    Semi::default(),
+    "let foo = 5" => "let foo = 5;",
+    "foo()\nbar()" => "foo();\nbar();",
+    "function foo() { return 5 }" => "function foo() { return 5; }",
+    "foo() // bar\nbaz()" => "foo(); // bar\nbaz();",
+    "do {} while (foo)" => "do {} while (foo);"
+}
+
+fix_tests! {
+    never_fixes,
+    Semi { style: "never".to_string() },
+    "let foo = 5;" => "let foo = 5",
+    "foo();\nbar();" => "foo()\nbar()",
+    "function foo() { return 5; }" => "function foo() { return 5 }",
+    "foo(); // bar\nbaz()" => "foo() // bar\nbaz()",
+    "foo();\n(bar)()" => "foo()\n;(bar)()",
+    "foo();\n[1, 2].forEach(bar)" => "foo()\n;[1, 2].forEach(bar)",
+    "foo();\n`bar`" => "foo()\n;`bar`",
+    "foo();\n-bar" => "foo()\n;-bar",
+    "foo();\n/bar/.test(baz)" => "foo()\n;/bar/.test(baz)",
+    "foo();\n(bar)();\n[baz]" => "foo()\n;(bar)()\n;[baz]",
+    "foo();\n++bar" => "foo()\n++bar",
+    "foo(); bar()" => "foo(); bar()"
+}
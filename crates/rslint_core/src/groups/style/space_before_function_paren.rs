@@ -0,0 +1,221 @@
+use crate::rule_prelude::*;
+use crate::Inferable;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce consistent spacing before the parentheses of function parameters.
+
+    Anonymous functions, named functions (including methods), and async arrow functions can be
+    configured on their own with one of these values:
+
+    - `"always"` requires a space before the parentheses.
+    - `"never"` disallows spaces before the parentheses.
+    - `"ignore"` does not check the spacing.
+
+    Parentheses at the start of a line and parentheses after comments are not checked.
+
+    ## Incorrect Code Examples
+
+    ```js
+    function foo() {}
+    ```
+
+    ```js
+    let foo = async(a) => a;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function foo () {}
+    ```
+
+    ```js
+    let foo = {
+        bar () {}
+    };
+    ```
+
+    ```js
+    let foo = async (a) => a;
+    ```
+    */
+    #[serde(default)]
+    #[derive(rslint_macros::Mergeable)]
+    SpaceBeforeFunctionParen,
+    style,
+    "space-before-function-paren",
+    /// The spacing of anonymous functions such as `function () {}`, "always" by default.
+    pub anonymous: String,
+    /// The spacing of named functions and methods such as `function foo () {}`, "always" by default.
+    pub named: String,
+    /// The spacing of async arrow functions such as `async () => {}`, "always" by default.
+    pub async_arrow: String
+}
+
+impl Default for SpaceBeforeFunctionParen {
+    fn default() -> Self {
+        Self {
+            anonymous: "always".to_string(),
+            named: "always".to_string(),
+            async_arrow: "always".to_string(),
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for SpaceBeforeFunctionParen {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let (kind, paren) = function_paren(node)?;
+        let expected = match self.style(kind) {
+            "always" => true,
+            "never" => false,
+            _ => return None,
+        };
+        util::check_adjacent_whitespace(ctx, self.name(), &paren, true, expected)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FunctionKind {
+    Anonymous,
+    Named,
+    AsyncArrow,
+}
+
+impl SpaceBeforeFunctionParen {
+    fn style(&self, kind: FunctionKind) -> &str {
+        match kind {
+            FunctionKind::Anonymous => &self.anonymous,
+            FunctionKind::Named => &self.named,
+            FunctionKind::AsyncArrow => &self.async_arrow,
+        }
+    }
+}
+
+/// The kind of a function and the opening parenthesis of its parameters.
+fn function_paren(node: &SyntaxNode) -> Option<(FunctionKind, SyntaxToken)> {
+    let has_name = node.children().any(|child| child.kind() == NAME);
+    let kind = match node.kind() {
+        FN_DECL | FN_EXPR if has_name => FunctionKind::Named,
+        FN_DECL | FN_EXPR => FunctionKind::Anonymous,
+        METHOD | GETTER | SETTER | CONSTRUCTOR => FunctionKind::Named,
+        ARROW_EXPR
+            if node
+                .children_with_tokens()
+                .any(|elem| elem.kind() == ASYNC_KW) =>
+        {
+            FunctionKind::AsyncArrow
+        }
+        _ => return None,
+    };
+    let paren = node
+        .children()
+        .find(|child| child.kind() == PARAMETER_LIST)?
+        .first_token()
+        .filter(|token| token.kind() == L_PAREN)?;
+    Some((kind, paren))
+}
+
+#[typetag::serde]
+impl Inferable for SpaceBeforeFunctionParen {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let mut anonymous = vec![];
+        let mut named = vec![];
+        let mut async_arrow = vec![];
+        for node in nodes {
+            let (kind, paren) = match function_paren(node) {
+                Some(function) => function,
+                None => continue,
+            };
+            let whitespace = match util::adjacent_whitespace(&paren, true) {
+                Some(whitespace) => whitespace,
+                None => continue,
+            };
+            let style = if util::tokens_have_whitespace(&whitespace, false) {
+                "always"
+            } else {
+                "never"
+            };
+            match kind {
+                FunctionKind::Anonymous => anonymous.push(style),
+                FunctionKind::Named => named.push(style),
+                FunctionKind::AsyncArrow => async_arrow.push(style),
+            }
+        }
+
+        if !anonymous.is_empty() {
+            self.anonymous = util::most_frequent(anonymous).to_string();
+        }
+        if !named.is_empty() {
+            self.named = util::most_frequent(named).to_string();
+        }
+        if !async_arrow.is_empty() {
+            self.async_arrow = util::most_frequent(async_arrow).to_string();
+        }
+    }
+}
+
+rule_tests! {
+    SpaceBeforeFunctionParen::default(),
+    err: {
+        "function foo() {}",
+        "let foo = function() {};",
+        "let foo = function*() {};",
+        "let foo = async(a) => a;",
+        "let foo = { bar() {} };",
+        "class A { constructor() {} get foo() {} }"
+    },
+    ok: {
+        "function foo () {}",
+        "let foo = function () {};",
+        "let foo = async (a) => a;",
+        "let foo = async a => a;",
+        "let foo = (a) => a;",
+        "let foo = { bar () {} };",
+        "function foo/* bar */() {}"
+    }
+}
+
+rule_tests! {
+    never_valid,
+    never_invalid,
+    SpaceBeforeFunctionParen {
+        anonymous: "never".to_string(),
+        named: "never".to_string(),
+        async_arrow: "ignore".to_string(),
+    },
+    err: {
+        "function foo () {}",
+        "let foo = function () {};",
+        "class A { foo () {} }"
+    },
+    ok: {
+        "function foo() {}",
+        "let foo = function() {};",
+        "let foo = async (a) => a;",
+        "let foo = async(a) => a;"
+    }
+}
+
+fix_tests! {
+    SpaceBeforeFunctionParen::default(),
+    "function foo() {}" => "function foo () {}",
+    "let foo = function() {};" => "let foo = function () {};",
+    "let foo = async(a) => a;" => "let foo = async (a) => a;",
+    "class A { constructor() {} get foo() {} }" => "class A { constructor () {} get foo () {} }",
+    "function foo/* bar */() {}" => "function foo/* bar */() {}"
+}
+
+fix_tests! {
+    never_fixes,
+    SpaceBeforeFunctionParen {
+        anonymous: "never".to_string(),
+        named: "never".to_string(),
+        async_arrow: "ignore".to_string(),
+    },
+    "function foo () {}" => "function foo() {}",
+    "let foo = function  () {};" => "let foo = function() {};",
+    "let foo = async (a) => a;" => "let foo = async (a) => a;"
+}
@@ -0,0 +1,207 @@
+use crate::rule_prelude::*;
+use crate::Inferable;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce consistent spacing around infix operators.
+
+    Infix operators are binary and logical operators, assignment operators, the `=` of declarations,
+    default values, and class properties, and the `?` and `:` of conditional expressions.
+    Spaces are required on both sides by default. Operators at the start or end of a line and
+    operators next to comments are not checked.
+
+    Spaces are never removed if that would join an operator with the token next to it, such as in `a - -b`.
+
+    ## Incorrect Code Examples
+
+    ```js
+    let foo=1;
+    ```
+
+    ```js
+    let foo = a+b;
+    ```
+
+    ```js
+    let foo = a ?b : c;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let foo = 1;
+    ```
+
+    ```js
+    let foo = a + b;
+    ```
+
+    ```js
+    let foo = a
+        ? b
+        : c;
+    ```
+    */
+    #[serde(default)]
+    #[derive(rslint_macros::Mergeable)]
+    SpaceInfixOps,
+    style,
+    "space-infix-ops",
+    /// Require a space before operators (true by default).
+    pub before: bool,
+    /// Require a space after operators (true by default).
+    pub after: bool,
+    /// Allow `|0` without spaces, which is used as a hint for 32 bit integers such as in `a|0` (false by default).
+    pub int32_hint: bool
+}
+
+impl Default for SpaceInfixOps {
+    fn default() -> Self {
+        Self {
+            before: true,
+            after: true,
+            int32_hint: false,
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for SpaceInfixOps {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        for op in operators(node) {
+            if self.int32_hint && is_int32_hint(node, &op) {
+                continue;
+            }
+            util::check_adjacent_whitespace(ctx, self.name(), &op, true, self.before);
+            util::check_adjacent_whitespace(ctx, self.name(), &op, false, self.after);
+        }
+        None
+    }
+}
+
+/// The infix operators of a node.
+fn operators(node: &SyntaxNode) -> Vec<SyntaxToken> {
+    let tokens = node
+        .children_with_tokens()
+        .filter_map(|elem| elem.into_token())
+        .filter(|token| !token.kind().is_trivia());
+    match node.kind() {
+        BIN_EXPR | ASSIGN_EXPR => tokens.take(1).collect(),
+        COND_EXPR => tokens
+            .filter(|token| matches!(token.kind(), QUESTION | COLON))
+            .collect(),
+        DECLARATOR | ASSIGN_PATTERN | CLASS_PROP | PRIVATE_PROP => {
+            tokens.filter(|token| token.kind() == EQ).collect()
+        }
+        _ => vec![],
+    }
+}
+
+fn is_int32_hint(node: &SyntaxNode, op: &SyntaxToken) -> bool {
+    op.kind() == PIPE
+        && node
+            .last_child()
+            .map_or(false, |rhs| rhs.kind() == LITERAL && rhs.text() == "0")
+}
+
+#[typetag::serde]
+impl Inferable for SpaceInfixOps {
+    fn infer(&mut self, nodes: &[SyntaxNode]) {
+        let ops = nodes.iter().flat_map(operators).collect::<Vec<_>>();
+        let spacing = |before: bool| {
+            ops.iter()
+                .filter_map(|op| util::adjacent_whitespace(op, before))
+                .map(|whitespace| util::tokens_have_whitespace(&whitespace, false))
+                .collect::<Vec<_>>()
+        };
+
+        let before = spacing(true);
+        if !before.is_empty() {
+            self.before = util::most_frequent(before);
+        }
+        let after = spacing(false);
+        if !after.is_empty() {
+            self.after = util::most_frequent(after);
+        }
+    }
+}
+
+rule_tests! {
+    SpaceInfixOps::default(),
+    err: {
+        "let foo=1;",
+        "let foo =1;",
+        "foo = a+b;",
+        "foo = a|0;",
+        "foo+=1;",
+        "foo = a ?b : c;",
+        "foo = a ? b:c;",
+        "function foo(a=1) {}",
+        "let { a=1 } = foo;",
+        "foo = a&&b;"
+    },
+    ok: {
+        "let foo = 1;",
+        "foo = a + b;",
+        "foo = a ? b : c;",
+        "foo = a\n  ? b\n  : c;",
+        "foo = a /* bar */+ b;",
+        "foo = -a;",
+        "foo++;",
+        "let foo;",
+        "function foo(a = 1) {}",
+        "foo = a in b;"
+    }
+}
+
+rule_tests! {
+    int32_hint_valid,
+    int32_hint_invalid,
+    SpaceInfixOps { int32_hint: true, ..SpaceInfixOps::default() },
+    err: {
+        "foo = a|1;",
+        "foo = a&0;"
+    },
+    ok: {
+        "foo = a|0;",
+        "foo = a | 0;"
+    }
+}
+
+rule_tests! {
+    never_valid,
+    never_invalid,
+    SpaceInfixOps { before: false, after: false, int32_hint: false },
+    err: {
+        "foo = a + b;",
+        "let foo = 1;"
+    },
+    ok: {
+        "foo=a+b;",
+        "foo=a- -b;",
+        "foo=a+ +b;",
+        "foo=a in b;"
+    }
+}
+
+fix_tests! {
+    SpaceInfixOps::default(),
+    "let foo=1;" => "let foo = 1;",
+    "foo = a+b*c;" => "foo = a + b * c;",
+    "foo+=1;" => "foo += 1;",
+    "foo = a?b:c;" => "foo = a ? b : c;",
+    "function foo(a=1) {}" => "function foo(a = 1) {}",
+    "foo = a /* bar */+b;" => "foo = a /* bar */+ b;"
+}
+
+fix_tests! {
+    never_fixes,
+    SpaceInfixOps { before: false, after: false, int32_hint: false },
+    "foo = a + b;" => "foo=a+b;",
+    "foo = a - -b;" => "foo=a- -b;",
+    "foo = a + +b;" => "foo=a+ +b;",
+    "foo = a in b;" => "foo=a in b;",
+    "foo = a instanceof B;" => "foo=a instanceof B;",
+    "x = /a/ instanceof R;" => "x=/a/ instanceof R;"
+}
@@ -31,9 +31,12 @@ mod store;
 mod testing;
 
 pub mod autofix;
+pub mod cfg;
 pub mod directives;
+pub mod globals;
 pub mod groups;
 pub mod rule_prelude;
+pub mod semantic;
 pub mod util;
 
 pub use self::{
@@ -41,6 +44,7 @@ pub use self::{
     store::CstRuleStore,
 };
 pub use rslint_errors::{Diagnostic, Severity, Span};
+pub use semantic::SemanticModel;
 
 pub use crate::directives::{
     apply_top_level_directives, skip_node, Directive, DirectiveError, DirectiveErrorKind,
@@ -154,6 +158,14 @@ pub(crate) fn lint_file_inner(
     );
 
     let src: Arc<str> = Arc::from(node.to_string());
+    let semantic = {
+        let span = tracing::info_span!("building semantic model");
+        let _gaurd = span.enter();
+        Arc::new(SemanticModel::with_globals(
+            &node,
+            Arc::clone(&new_store.globals),
+        ))
+    };
 
     let span = tracing::info_span!("running rules");
     let _gaurd = span.enter();
@@ -164,13 +176,14 @@ pub(crate) fn lint_file_inner(
         .map(|rule| {
             (
                 rule.name(),
-                run_rule(
+                run_rule_with_semantics(
                     &**rule,
                     file_id,
                     node.clone(),
                     verbose,
                     &directives,
                     src.clone(),
+                    semantic.clone(),
                 ),
             )
         })
@@ -199,6 +212,24 @@ pub fn run_rule(
     verbose: bool,
     directives: &[Directive],
     src: Arc<str>,
+) -> RuleResult {
+    let semantic = Arc::new(SemanticModel::new(&root));
+    run_rule_with_semantics(rule, file_id, root, verbose, directives, src, semantic)
+}
+
+/// Run a single rule on an entire parsed file with an already built [`SemanticModel`].
+/// This should be preferred over [`run_rule`] when running multiple rules on the same file.
+///
+/// # Panics
+/// Panics if `root`'s kind is not `SCRIPT` or `MODULE`
+pub fn run_rule_with_semantics(
+    rule: &dyn CstRule,
+    file_id: usize,
+    root: SyntaxNode,
+    verbose: bool,
+    directives: &[Directive],
+    src: Arc<str>,
+    semantic: Arc<SemanticModel>,
 ) -> RuleResult {
     let span = tracing::info_span!("run rule", rule = rule.name());
     let _gaurd = span.enter();
@@ -211,6 +242,8 @@ pub fn run_rule(
         diagnostics: vec![],
         fixer: None,
         src,
+        semantic,
+        cfgs: HashMap::new(),
     };
 
     rule.check_root(&root, &mut ctx);
@@ -252,7 +285,9 @@ pub fn get_group_rules_by_name(group_name: &str) -> Option<Vec<Box<dyn CstRule>>
     use groups::*;
 
     Some(match group_name {
+        "complexity" => complexity(),
         "errors" => errors(),
+        "regex" => regex(),
         "style" => style(),
         _ => return None,
     })
@@ -289,7 +324,24 @@ macro_rules! trait_obj_helper {
 /// Get all of the built in rules which can have their options inferred using multiple syntax nodes
 /// see [`Inferable`] for more information.
 pub fn get_inferable_rules() -> Vec<Box<dyn Inferable>> {
+    use groups::complexity::*;
     use groups::style::*;
 
-    trait_obj_helper![BlockSpacing]
+    trait_obj_helper![
+        BlockSpacing,
+        Indent,
+        Quotes,
+        Semi,
+        CommaDangle,
+        CommaSpacing,
+        KeywordSpacing,
+        SpaceInfixOps,
+        SpaceBeforeFunctionParen,
+        ArrowSpacing,
+        Complexity,
+        MaxDepth,
+        MaxParams,
+        MaxNestedCallbacks,
+        MaxStatements,
+    ]
 }
@@ -4,6 +4,8 @@
 #![allow(unused_variables, unused_imports)]
 
 use crate::autofix::Fixer;
+use crate::cfg::ControlFlowGraph;
+use crate::semantic::SemanticModel;
 use crate::Diagnostic;
 use dyn_clone::DynClone;
 use rslint_errors::Severity;
@@ -11,6 +13,7 @@ use rslint_parser::{SyntaxNode, SyntaxNodeExt, SyntaxToken};
 use rslint_text_edit::apply_indels;
 use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::marker::{Send, Sync};
 use std::ops::{Deref, DerefMut, Drop};
@@ -125,6 +128,10 @@ pub struct RuleCtx {
     pub diagnostics: Vec<Diagnostic>,
     pub fixer: Option<Fixer>,
     pub src: Arc<str>,
+    /// The scopes, bindings, and references of the file, this is built once and shared by all rules.
+    pub semantic: Arc<SemanticModel>,
+    /// Control flow graphs which have been built by the rule so far, see [`RuleCtx::cfg`].
+    pub cfgs: HashMap<SyntaxNode, Arc<ControlFlowGraph>>,
 }
 
 impl RuleCtx {
@@ -144,6 +151,15 @@ impl RuleCtx {
         self.fixer.as_mut().unwrap()
     }
 
+    /// Get the fixer of this context, making a new one if there is none.
+    ///
+    /// Unlike [`RuleCtx::fix`] this keeps the changes of previous fixes, which allows a rule
+    /// to fix all of its diagnostics in a single run, the changes must not overlap however.
+    pub fn extend_fix(&mut self) -> &mut Fixer {
+        let src = self.src.clone();
+        self.fixer.get_or_insert_with(|| Fixer::new(src))
+    }
+
     /// Create a context which is used to simply run a rule without needing to know about
     /// the resulting fixer, therefore the ctx's source is not a valid source
     pub(crate) fn dummy_ctx() -> Self {
@@ -153,7 +169,22 @@ impl RuleCtx {
             diagnostics: vec![],
             fixer: None,
             src: Arc::from(String::new()),
+            semantic: Arc::new(SemanticModel::default()),
+            cfgs: HashMap::new(),
+        }
+    }
+
+    /// Get the control flow graph of a function, script, or module, the graph is only built
+    /// the first time it is requested. Returns `None` if the node is not one of those.
+    ///
+    /// Use [`cfg::owner`](crate::cfg::owner) to get the node whose graph contains a statement.
+    pub fn cfg(&mut self, node: &SyntaxNode) -> Option<Arc<ControlFlowGraph>> {
+        if let Some(cfg) = self.cfgs.get(node) {
+            return Some(cfg.clone());
         }
+        let cfg = Arc::new(ControlFlowGraph::new(node)?);
+        self.cfgs.insert(node.to_owned(), cfg.clone());
+        Some(cfg)
     }
 }
 
@@ -358,7 +389,12 @@ macro_rules! __declare_lint_inner {
 
             #[cfg(feature = "schema")]
             fn schema(&self) -> Option<schemars::schema::RootSchema> {
-                Some(schemars::schema_for!($name))
+                // the schema is embedded in the config schema, which does not include the definitions
+                // of the rule schema, so nested option types must be inlined
+                let gen = schemars::gen::SchemaSettings::draft07()
+                    .with(|settings| settings.inline_subschemas = true)
+                    .into_generator();
+                Some(gen.into_root_schema_for::<$name>())
             }
         }
     };
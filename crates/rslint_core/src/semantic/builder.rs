@@ -0,0 +1,641 @@
+//! The tree walker which collects scopes, bindings, and references into a [`SemanticModel`].
+
+use super::*;
+use rslint_parser::{ast, AstNode, SyntaxKind::*, SyntaxNodeExt, T};
+
+pub(super) struct SemanticBuilder {
+    model: SemanticModel,
+    root: SyntaxNode,
+    stack: Vec<ScopeId>,
+    /// References made by `export { a }` and `export default a` which mark their bindings as exported.
+    exports: Vec<ReferenceId>,
+}
+
+impl SemanticBuilder {
+    pub(super) fn new(root: &SyntaxNode, globals: Arc<Globals>) -> Self {
+        Self {
+            model: SemanticModel {
+                globals,
+                ..SemanticModel::default()
+            },
+            root: root.to_owned(),
+            stack: vec![],
+            exports: vec![],
+        }
+    }
+
+    pub(super) fn build(mut self) -> SemanticModel {
+        let root = self.root.clone();
+        let kind = if root.kind() == MODULE {
+            ScopeKind::Module
+        } else {
+            ScopeKind::Global
+        };
+        self.push_scope(kind, &root);
+        self.visit_children(&root);
+        self.pop_scope();
+        self.resolve();
+        self.collect_global_comments();
+        self.model
+    }
+
+    /// Collect globals declared by `/* global foo, bar: writable */` (or `/* globals */`) comments.
+    fn collect_global_comments(&mut self) {
+        let comments = self
+            .root
+            .descendants_with_tokens()
+            .filter_map(|elem| elem.into_token())
+            .filter(|token| token.kind() == COMMENT && token.text().starts_with("/*"));
+
+        for comment in comments {
+            let text = comment
+                .text()
+                .trim_start_matches("/*")
+                .trim_end_matches("*/");
+            let mut words = text.trim_start().splitn(2, char::is_whitespace);
+            let rest = match (words.next(), words.next()) {
+                (Some("global"), Some(rest)) | (Some("globals"), Some(rest)) => rest,
+                _ => continue,
+            };
+            for entry in rest.split(',') {
+                let mut parts = entry.splitn(2, ':').map(str::trim);
+                let name = match parts.next() {
+                    Some(name) if !name.is_empty() => name,
+                    _ => continue,
+                };
+                let value = match parts.next() {
+                    Some("writable") | Some("writeable") | Some("true") => GlobalValue::Writable,
+                    Some("off") => GlobalValue::Off,
+                    _ => GlobalValue::Readonly,
+                };
+                self.model.file_globals.insert(name.into(), value);
+            }
+        }
+    }
+
+    fn cur(&self) -> ScopeId {
+        *self.stack.last().expect("semantic builder has no scope")
+    }
+
+    fn push_scope(&mut self, kind: ScopeKind, node: &SyntaxNode) -> ScopeId {
+        let id = self.model.scopes.len();
+        let parent = self.stack.last().copied();
+        self.model.scopes.push(Scope {
+            id,
+            kind,
+            node: node.to_owned(),
+            parent,
+            children: vec![],
+            bindings: vec![],
+            references: vec![],
+            names: HashMap::new(),
+        });
+        if let Some(parent) = parent {
+            self.model.scopes[parent].children.push(id);
+        }
+        self.model.node_scopes.insert(node.to_owned(), id);
+        self.stack.push(id);
+        id
+    }
+
+    fn pop_scope(&mut self) {
+        self.stack.pop();
+    }
+
+    fn declare(&mut self, name: &SyntaxNode, kind: BindingKind, decl: &SyntaxNode) -> BindingId {
+        let scope = if kind == BindingKind::Var {
+            *self
+                .stack
+                .iter()
+                .rev()
+                .find(|id| self.model.scopes[**id].is_var_scope())
+                .expect("no var scope")
+        } else {
+            self.cur()
+        };
+        let text = name_text(name);
+
+        if let Some(existing) = self.model.scopes[scope].get(&text) {
+            self.model.bindings[existing]
+                .declarations
+                .push(name.to_owned());
+            self.model.name_bindings.insert(name.to_owned(), existing);
+            return existing;
+        }
+
+        let id = self.model.bindings.len();
+        self.model.bindings.push(Binding {
+            id,
+            name: text.clone(),
+            kind,
+            scope,
+            declarations: vec![name.to_owned()],
+            decl: decl.to_owned(),
+            references: vec![],
+            exported: false,
+        });
+        let scope = &mut self.model.scopes[scope];
+        scope.bindings.push(id);
+        scope.names.insert(text, id);
+        self.model.name_bindings.insert(name.to_owned(), id);
+        id
+    }
+
+    fn reference(&mut self, node: &SyntaxNode, kind: ReferenceKind, init: bool) -> ReferenceId {
+        let id = self.model.references.len();
+        let scope = self.cur();
+        self.model.references.push(Reference {
+            id,
+            name: name_text(node),
+            node: node.to_owned(),
+            scope,
+            kind,
+            binding: None,
+            init,
+        });
+        self.model.scopes[scope].references.push(id);
+        self.model.name_references.insert(node.to_owned(), id);
+        id
+    }
+
+    fn resolve(&mut self) {
+        for id in 0..self.model.references.len() {
+            let reference = &self.model.references[id];
+            match self
+                .model
+                .lookup(reference.scope, &reference.name)
+                .map(|b| b.id)
+            {
+                Some(binding) => {
+                    self.model.references[id].binding = Some(binding);
+                    self.model.bindings[binding].references.push(id);
+                }
+                None if reference.name == "arguments" && self.has_arguments(reference.scope) => {}
+                // types such as interfaces and type aliases are not bindings
+                None if reference.node.kind() == TS_TYPE_NAME => {}
+                None => self.model.unresolved.push(id),
+            }
+        }
+
+        for id in std::mem::take(&mut self.exports) {
+            if let Some(binding) = self.model.references[id].binding {
+                self.model.bindings[binding].exported = true;
+            }
+        }
+    }
+
+    /// Whether a scope is inside of a non-arrow function which implicitly declares `arguments`.
+    fn has_arguments(&self, scope: ScopeId) -> bool {
+        let mut cur = Some(scope);
+        while let Some(id) = cur {
+            let scope = &self.model.scopes[id];
+            if scope.kind == ScopeKind::Function && scope.node.kind() != ARROW_EXPR {
+                return true;
+            }
+            cur = scope.parent;
+        }
+        false
+    }
+
+    fn visit_children(&mut self, node: &SyntaxNode) {
+        for child in node.children() {
+            self.visit(&child);
+        }
+    }
+
+    fn visit(&mut self, node: &SyntaxNode) {
+        match node.kind() {
+            // enums and namespaces are values, but their bodies are not visited
+            TS_ENUM | TS_NAMESPACE_DECL | TS_MODULE_DECL => {
+                if let Some(name) = node.child_with_kind(NAME) {
+                    let kind = if node.kind() == TS_ENUM {
+                        BindingKind::Enum
+                    } else {
+                        BindingKind::Namespace
+                    };
+                    self.declare(&name, kind, node);
+                }
+                self.visit_type(node);
+            }
+            kind if is_type_only(kind) => self.visit_type(node),
+            NAME_REF => {
+                if !matches!(
+                    node.parent().map(|p| p.kind()),
+                    Some(BREAK_STMT) | Some(CONTINUE_STMT)
+                ) {
+                    self.reference(node, ReferenceKind::Read, false);
+                }
+            }
+            // shorthand props such as `{ a }` read `a`
+            IDENT_PROP => {
+                if let Some(name) = node.child_with_kind(NAME) {
+                    self.reference(&name, ReferenceKind::Read, false);
+                }
+            }
+            FN_DECL => {
+                if let Some(name) = node.child_with_kind(NAME) {
+                    self.declare(&name, BindingKind::Function, node);
+                }
+                self.visit_function(node, None);
+            }
+            FN_EXPR => {
+                let name = node.child_with_kind(NAME);
+                self.visit_function(node, name);
+            }
+            ARROW_EXPR | METHOD | GETTER | SETTER | CONSTRUCTOR => self.visit_function(node, None),
+            CLASS_DECL => {
+                if let Some(name) = node.child_with_kind(NAME) {
+                    self.declare(&name, BindingKind::Class, node);
+                }
+                self.push_scope(ScopeKind::Class, node);
+                self.visit_children(node);
+                self.pop_scope();
+            }
+            CLASS_EXPR => {
+                self.push_scope(ScopeKind::Class, node);
+                if let Some(name) = node.child_with_kind(NAME) {
+                    self.declare(&name, BindingKind::Class, node);
+                }
+                self.visit_children(node);
+                self.pop_scope();
+            }
+            BLOCK_STMT => {
+                self.push_scope(ScopeKind::Block, node);
+                self.visit_children(node);
+                self.pop_scope();
+            }
+            FOR_STMT | FOR_IN_STMT | FOR_OF_STMT => {
+                self.push_scope(ScopeKind::Block, node);
+                for child in node.children() {
+                    if child.kind() == FOR_STMT_INIT && node.kind() != FOR_STMT {
+                        self.visit_for_in_of_head(&child);
+                    } else {
+                        self.visit(&child);
+                    }
+                }
+                self.pop_scope();
+            }
+            SWITCH_STMT => {
+                let mut pushed = false;
+                for child in node.children() {
+                    if (child.kind() == CASE_CLAUSE || child.kind() == DEFAULT_CLAUSE) && !pushed {
+                        self.push_scope(ScopeKind::Block, node);
+                        pushed = true;
+                    }
+                    self.visit(&child);
+                }
+                if pushed {
+                    self.pop_scope();
+                }
+            }
+            CATCH_CLAUSE => {
+                self.push_scope(ScopeKind::Catch, node);
+                for child in node.children() {
+                    if child.kind() == BLOCK_STMT {
+                        self.visit(&child);
+                    } else {
+                        self.declare_pattern(&child, BindingKind::CatchParam, node, &mut vec![]);
+                    }
+                }
+                self.pop_scope();
+            }
+            VAR_DECL => self.visit_var_decl(node, false),
+            IMPORT_DECL => self.visit_import(node),
+            EXPORT_DECL | EXPORT_DEFAULT_DECL => {
+                let scope = self.cur();
+                let start = self.model.bindings.len();
+                self.visit_children(node);
+                for binding in &mut self.model.bindings[start..] {
+                    if binding.scope == scope {
+                        binding.exported = true;
+                    }
+                }
+            }
+            EXPORT_NAMED => {
+                if node.token_with_kind(T![from]).is_some() {
+                    return;
+                }
+                for specifier in node.children().filter(|c| c.kind() == SPECIFIER) {
+                    if let Some(name) = specifier.child_with_kind(NAME) {
+                        let id = self.reference(&name, ReferenceKind::Read, false);
+                        self.exports.push(id);
+                    }
+                }
+            }
+            EXPORT_DEFAULT_EXPR => {
+                for child in node.children() {
+                    if child.kind() == NAME_REF {
+                        let id = self.reference(&child, ReferenceKind::Read, false);
+                        self.exports.push(id);
+                    } else {
+                        self.visit(&child);
+                    }
+                }
+            }
+            ASSIGN_EXPR => {
+                let kind = if node.token_with_kind(T![=]).is_some() {
+                    ReferenceKind::Write
+                } else {
+                    ReferenceKind::ReadWrite
+                };
+                let mut children = node.children();
+                if let Some(lhs) = children.next() {
+                    self.visit_target(&lhs, kind);
+                }
+                for child in children {
+                    self.visit(&child);
+                }
+            }
+            UNARY_EXPR if node.to::<ast::UnaryExpr>().is_update() => {
+                for child in node.children() {
+                    self.visit_target(&child, ReferenceKind::ReadWrite);
+                }
+            }
+            _ => self.visit_children(node),
+        }
+    }
+
+    /// Record the names referenced by a type, such as `Foo` in `Foo.Bar<T>` or `typeof foo`,
+    /// as reads so imports which are only used as types are counted as used.
+    fn visit_type(&mut self, node: &SyntaxNode) {
+        for name in node
+            .descendants()
+            .filter(|n| n.kind() == TS_TYPE_NAME && is_entity_name_start(n))
+        {
+            self.reference(&name, ReferenceKind::Read, false);
+        }
+    }
+
+    /// Visit any function-like node, `name` is the name of a function expression,
+    /// which is only visible inside of the function itself.
+    fn visit_function(&mut self, node: &SyntaxNode, name: Option<SyntaxNode>) {
+        // computed keys and decorators are evaluated outside of the function
+        for child in node.children() {
+            if !matches!(
+                child.kind(),
+                NAME | PARAMETER_LIST | BLOCK_STMT | CONSTRUCTOR_PARAMETERS
+            ) && child.text_range().end() <= params_start(node)
+            {
+                self.visit(&child);
+            }
+        }
+
+        self.push_scope(ScopeKind::Function, node);
+        if let Some(name) = name {
+            self.declare(&name, BindingKind::Function, node);
+        }
+
+        let mut seen_params = false;
+        for child in node.children() {
+            match child.kind() {
+                PARAMETER_LIST | CONSTRUCTOR_PARAMETERS => {
+                    seen_params = true;
+                    for param in child.children() {
+                        self.declare_param(&param, &child);
+                    }
+                }
+                // single param arrow functions such as `a => a`
+                NAME if node.kind() == ARROW_EXPR => {
+                    seen_params = true;
+                    self.declare(&child, BindingKind::Param, node);
+                }
+                // the function's body does not create another scope
+                BLOCK_STMT => self.visit_children(&child),
+                _ if seen_params => self.visit(&child),
+                _ => {}
+            }
+        }
+        self.pop_scope();
+    }
+
+    fn declare_param(&mut self, param: &SyntaxNode, list: &SyntaxNode) {
+        if param.kind() == TS_CONSTRUCTOR_PARAM {
+            for child in param.children() {
+                self.declare_param(&child, list);
+            }
+        } else if param.kind() == TS_DECORATOR {
+            self.visit(param);
+        } else {
+            self.declare_pattern(param, BindingKind::Param, list, &mut vec![]);
+        }
+    }
+
+    fn visit_var_decl(&mut self, node: &SyntaxNode, for_in_of: bool) {
+        let decl = node.to::<ast::VarDecl>();
+        let kind = if decl.is_const() {
+            BindingKind::Const
+        } else if decl.is_let() {
+            BindingKind::Let
+        } else {
+            BindingKind::Var
+        };
+
+        for declarator in node.children().filter(|c| c.kind() == DECLARATOR) {
+            let mut names = vec![];
+            let has_init = declarator.token_with_kind(T![=]).is_some();
+            for (idx, child) in declarator.children().enumerate() {
+                if idx == 0 {
+                    self.declare_pattern(&child, kind, node, &mut names);
+                } else {
+                    self.visit(&child);
+                }
+            }
+            if has_init || for_in_of {
+                for name in names {
+                    self.reference(&name, ReferenceKind::Write, true);
+                }
+            }
+        }
+    }
+
+    fn visit_for_in_of_head(&mut self, init: &SyntaxNode) {
+        for child in init.children() {
+            if child.kind() == VAR_DECL {
+                self.visit_var_decl(&child, true);
+            } else {
+                self.visit_target(&child, ReferenceKind::Write);
+            }
+        }
+    }
+
+    fn visit_import(&mut self, node: &SyntaxNode) {
+        for child in node.children() {
+            match child.kind() {
+                NAME => {
+                    self.declare(&child, BindingKind::Import, node);
+                }
+                NAMED_IMPORTS => {
+                    for specifier in child.children().filter(|c| c.kind() == SPECIFIER) {
+                        // `a as b` declares `b`
+                        if let Some(name) = specifier.children().filter(|c| c.kind() == NAME).last()
+                        {
+                            self.declare(&name, BindingKind::Import, node);
+                        }
+                    }
+                }
+                WILDCARD_IMPORT => {
+                    if let Some(name) = child.child_with_kind(NAME) {
+                        self.declare(&name, BindingKind::Import, node);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Declare all of the names inside of a binding pattern, default values and computed keys
+    /// are visited as expressions. Declared name nodes are pushed to `names`.
+    fn declare_pattern(
+        &mut self,
+        node: &SyntaxNode,
+        kind: BindingKind,
+        decl: &SyntaxNode,
+        names: &mut Vec<SyntaxNode>,
+    ) {
+        match node.kind() {
+            NAME => {
+                self.declare(node, kind, decl);
+                names.push(node.to_owned());
+            }
+            SINGLE_PATTERN => {
+                let name = node
+                    .child_with_kind(NAME)
+                    .unwrap_or_else(|| node.to_owned());
+                self.declare(&name, kind, decl);
+                names.push(name);
+                // type annotations such as `a: Foo`
+                for child in node.children().filter(|c| is_type_only(c.kind())) {
+                    self.visit_type(&child);
+                }
+            }
+            ASSIGN_PATTERN => {
+                let eq = node
+                    .token_with_kind(T![=])
+                    .map_or(node.text_range().end(), |t| t.text_range().start());
+                for child in node.children() {
+                    if child.text_range().start() < eq {
+                        self.declare_pattern(&child, kind, decl, names);
+                    } else {
+                        self.visit(&child);
+                    }
+                }
+            }
+            REST_PATTERN | ARRAY_PATTERN | OBJECT_PATTERN => {
+                for child in node.children() {
+                    self.declare_pattern(&child, kind, decl, names);
+                }
+            }
+            KEY_VALUE_PATTERN => {
+                let value = node.to::<ast::KeyValuePattern>().value();
+                for child in node.children() {
+                    if value.as_ref().map(|v| v.syntax()) == Some(&child) {
+                        self.declare_pattern(&child, kind, decl, names);
+                    } else if child.kind() == COMPUTED_PROPERTY_NAME {
+                        self.visit(&child);
+                    }
+                }
+            }
+            kind if is_type_only(kind) => self.visit_type(node),
+            _ => self.visit(node),
+        }
+    }
+
+    /// Visit the target of an assignment, update expression, or for-in/of loop.
+    fn visit_target(&mut self, node: &SyntaxNode, kind: ReferenceKind) {
+        match node.kind() {
+            NAME_REF | NAME => {
+                self.reference(node, kind, false);
+            }
+            SINGLE_PATTERN => {
+                let name = node
+                    .child_with_kind(NAME)
+                    .unwrap_or_else(|| node.to_owned());
+                self.reference(&name, kind, false);
+            }
+            IDENT_PROP => {
+                if let Some(name) = node.child_with_kind(NAME) {
+                    self.reference(&name, kind, false);
+                }
+            }
+            GROUPING_EXPR | ARRAY_EXPR | ARRAY_PATTERN | OBJECT_EXPR | OBJECT_PATTERN
+            | SPREAD_ELEMENT | SPREAD_PROP | REST_PATTERN | TS_NON_NULL | TS_ASSERTION => {
+                for child in node.children() {
+                    self.visit_target(&child, kind);
+                }
+            }
+            // defaults in destructuring assignments such as `[a = 1] = []`
+            ASSIGN_EXPR | ASSIGN_PATTERN => {
+                let eq = node
+                    .token_with_kind(T![=])
+                    .map_or(node.text_range().end(), |t| t.text_range().start());
+                for child in node.children() {
+                    if child.text_range().start() < eq {
+                        self.visit_target(&child, kind);
+                    } else {
+                        self.visit(&child);
+                    }
+                }
+            }
+            LITERAL_PROP | KEY_VALUE_PATTERN => {
+                let colon = node
+                    .token_with_kind(T![:])
+                    .map_or(node.text_range().start(), |t| t.text_range().start());
+                for child in node.children() {
+                    if child.text_range().start() > colon {
+                        self.visit_target(&child, kind);
+                    } else if child.kind() == COMPUTED_PROPERTY_NAME {
+                        self.visit(&child);
+                    }
+                }
+            }
+            kind if is_type_only(kind) => self.visit_type(node),
+            _ => self.visit(node),
+        }
+    }
+}
+
+fn params_start(node: &SyntaxNode) -> rslint_parser::TextSize {
+    node.children()
+        .find(|c| {
+            matches!(c.kind(), PARAMETER_LIST | CONSTRUCTOR_PARAMETERS)
+                || (node.kind() == ARROW_EXPR && c.kind() == NAME)
+        })
+        .map_or(node.text_range().end(), |c| c.text_range().start())
+}
+
+/// The text of a name node, this is the first non-trivia token to avoid including
+/// things like type annotations in `SINGLE_PATTERN`s.
+fn name_text(node: &SyntaxNode) -> SmolStr {
+    node.children_with_tokens()
+        .filter_map(|elem| elem.into_token())
+        .find(|tok| !tok.kind().is_trivia())
+        .map(|tok| tok.text().clone())
+        .unwrap_or_else(|| node.trimmed_text().to_string().into())
+}
+
+/// Whether a `TS_TYPE_NAME` is the first name of a type reference, type query,
+/// heritage clause, or import alias, the rest of a qualified name are members.
+fn is_entity_name_start(name: &SyntaxNode) -> bool {
+    let mut node = name.clone();
+    while let Some(parent) = node.parent() {
+        match parent.kind() {
+            TS_QUALIFIED_PATH if parent.first_child().as_ref() == Some(&node) => node = parent,
+            TS_TYPE_REF | TS_TYPE_QUERY | TS_EXPR_WITH_TYPE_ARGS | TS_IMPORT_EQUALS_DECL => {
+                return true
+            }
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// Whether the node only describes types, which never contain value references.
+fn is_type_only(kind: SyntaxKind) -> bool {
+    (TS_ANY..=TS_TYPE_PARAM).contains(&kind)
+        || (TS_ENUM..=TS_MODULE_DECL).contains(&kind)
+        || (TS_CALL_SIGNATURE_DECL..=TS_OBJECT_TYPE).contains(&kind)
+        || matches!(
+            kind,
+            TS_IMPORT_EQUALS_DECL | TS_NAMESPACE_EXPORT_DECL | TS_INFER
+        )
+}
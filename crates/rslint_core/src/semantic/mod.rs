@@ -0,0 +1,498 @@
+//! Scope analysis and name resolution for a single file.
+//!
+//! The [`SemanticModel`] is built once per file before any rules are run and is
+//! shared by every rule through [`RuleCtx::semantic`](crate::RuleCtx::semantic).
+//! It records every scope created by the program, every binding declared in those scopes,
+//! and every reference to a name along with the binding it resolves to (if any).
+//!
+//! Bindings and references are keyed by their name nodes, a binding's name node is
+//! the `NAME` node of its declaration (or the `SINGLE_PATTERN` for patterns which have no `NAME`),
+//! a reference's node is the `NAME_REF` (or `NAME` for shorthand props and destructuring targets).
+//!
+//! References which do not resolve to a binding may still refer to a global, the model knows about the
+//! [`Globals`] it was built with as well as any globals declared in the file with `/* global foo */` comments.
+
+mod builder;
+
+use crate::globals::{GlobalValue, Globals};
+use rslint_parser::{ast, AstNode, SmolStr, SyntaxKind, SyntaxNode, TextRange};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The index of a scope inside of a [`SemanticModel`].
+pub type ScopeId = usize;
+/// The index of a binding inside of a [`SemanticModel`].
+pub type BindingId = usize;
+/// The index of a reference inside of a [`SemanticModel`].
+pub type ReferenceId = usize;
+
+/// The kind of syntax which created a scope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScopeKind {
+    /// The top level scope of a script.
+    Global,
+    /// The top level scope of a module.
+    Module,
+    /// A function, arrow function, method, getter, setter, or constructor.
+    Function,
+    /// The scope holding a class' name and body.
+    Class,
+    /// A block statement, a for loop, or a switch statement's body.
+    Block,
+    /// A catch clause holding the caught error.
+    Catch,
+}
+
+/// A single lexical scope.
+#[derive(Debug, Clone)]
+pub struct Scope {
+    pub id: ScopeId,
+    pub kind: ScopeKind,
+    /// The node which created this scope.
+    pub node: SyntaxNode,
+    pub parent: Option<ScopeId>,
+    pub children: Vec<ScopeId>,
+    /// All of the bindings declared directly in this scope.
+    pub bindings: Vec<BindingId>,
+    /// All of the references which occur directly in this scope.
+    pub references: Vec<ReferenceId>,
+    names: HashMap<SmolStr, BindingId>,
+}
+
+impl Scope {
+    /// Get a binding declared directly in this scope by its name.
+    pub fn get(&self, name: &str) -> Option<BindingId> {
+        self.names.get(name).copied()
+    }
+
+    /// Whether this scope is the boundary for `var` declarations.
+    pub fn is_var_scope(&self) -> bool {
+        matches!(
+            self.kind,
+            ScopeKind::Global | ScopeKind::Module | ScopeKind::Function
+        )
+    }
+}
+
+/// The way a binding was declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BindingKind {
+    Var,
+    Let,
+    Const,
+    Function,
+    Class,
+    Import,
+    Param,
+    CatchParam,
+    /// A TypeScript `enum` declaration.
+    Enum,
+    /// A TypeScript `namespace` or `module` declaration.
+    Namespace,
+}
+
+impl BindingKind {
+    /// Whether the binding is block scoped (`let`, `const`, or `class`).
+    pub fn is_lexical(self) -> bool {
+        matches!(
+            self,
+            BindingKind::Let | BindingKind::Const | BindingKind::Class
+        )
+    }
+}
+
+/// A single name declared in a scope.
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub id: BindingId,
+    pub name: SmolStr,
+    pub kind: BindingKind,
+    pub scope: ScopeId,
+    /// Every name node which declares this binding, redeclarations (e.g. `var a; var a;`)
+    /// are merged into a single binding.
+    pub declarations: Vec<SyntaxNode>,
+    /// The node for the first declaration such as a `VAR_DECL`, `FN_DECL`, `CLASS_DECL`,
+    /// `IMPORT_DECL`, `PARAMETER_LIST`, `CATCH_CLAUSE`, `TS_ENUM`, `TS_NAMESPACE_DECL`, or `TS_MODULE_DECL`.
+    pub decl: SyntaxNode,
+    /// All of the references which resolve to this binding.
+    pub references: Vec<ReferenceId>,
+    /// Whether the binding is exported from the module.
+    pub exported: bool,
+}
+
+impl Binding {
+    /// The name node of the first declaration.
+    pub fn name_node(&self) -> &SyntaxNode {
+        &self.declarations[0]
+    }
+
+    /// The range of the first declaration's name.
+    pub fn range(&self) -> TextRange {
+        self.name_node().text_range()
+    }
+}
+
+/// How a reference uses its name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReferenceKind {
+    Read,
+    Write,
+    /// Compound assignments and update expressions such as `a += 1` or `a++`.
+    ReadWrite,
+}
+
+/// A single usage of a name.
+#[derive(Debug, Clone)]
+pub struct Reference {
+    pub id: ReferenceId,
+    pub name: SmolStr,
+    pub node: SyntaxNode,
+    /// The scope the reference occurs in.
+    pub scope: ScopeId,
+    pub kind: ReferenceKind,
+    /// The binding the reference resolves to, this is `None` for globals and undeclared variables.
+    pub binding: Option<BindingId>,
+    /// Whether this reference is the initialization of a declaration such as `let a = 5`.
+    pub init: bool,
+}
+
+impl Reference {
+    pub fn is_read(&self) -> bool {
+        matches!(self.kind, ReferenceKind::Read | ReferenceKind::ReadWrite)
+    }
+
+    pub fn is_write(&self) -> bool {
+        matches!(self.kind, ReferenceKind::Write | ReferenceKind::ReadWrite)
+    }
+
+    /// Whether the reference is the direct operand of `typeof`, e.g. `typeof foo`.
+    pub fn is_typeof(&self) -> bool {
+        self.node
+            .ancestors()
+            .skip(1)
+            .find(|node| node.kind() != SyntaxKind::GROUPING_EXPR)
+            .and_then(ast::UnaryExpr::cast)
+            .and_then(|expr| expr.op())
+            == Some(ast::UnaryOp::Typeof)
+    }
+}
+
+/// Scopes, bindings, and references of a single file.
+#[derive(Debug, Clone, Default)]
+pub struct SemanticModel {
+    scopes: Vec<Scope>,
+    bindings: Vec<Binding>,
+    references: Vec<Reference>,
+    node_scopes: HashMap<SyntaxNode, ScopeId>,
+    name_bindings: HashMap<SyntaxNode, BindingId>,
+    name_references: HashMap<SyntaxNode, ReferenceId>,
+    unresolved: Vec<ReferenceId>,
+    globals: Arc<Globals>,
+    /// Globals declared by `/* global */` comments, these take precedence over `globals`.
+    file_globals: HashMap<SmolStr, GlobalValue>,
+}
+
+impl SemanticModel {
+    /// Build the semantic model for a `SCRIPT` or `MODULE` node with the default globals.
+    pub fn new(root: &SyntaxNode) -> Self {
+        Self::with_globals(root, Default::default())
+    }
+
+    /// Build the semantic model for a `SCRIPT` or `MODULE` node which may use a set of globals.
+    pub fn with_globals(root: &SyntaxNode, globals: Arc<Globals>) -> Self {
+        builder::SemanticBuilder::new(root, globals).build()
+    }
+
+    /// The top level scope, this is `None` only for a default (empty) model.
+    pub fn root_scope(&self) -> Option<&Scope> {
+        self.scopes.first()
+    }
+
+    pub fn scope(&self, id: ScopeId) -> &Scope {
+        &self.scopes[id]
+    }
+
+    pub fn scopes(&self) -> &[Scope] {
+        &self.scopes
+    }
+
+    pub fn binding(&self, id: BindingId) -> &Binding {
+        &self.bindings[id]
+    }
+
+    pub fn bindings(&self) -> &[Binding] {
+        &self.bindings
+    }
+
+    pub fn reference(&self, id: ReferenceId) -> &Reference {
+        &self.references[id]
+    }
+
+    pub fn references(&self) -> &[Reference] {
+        &self.references
+    }
+
+    /// The scope created by this exact node, if it creates one.
+    pub fn scope_created_by(&self, node: &SyntaxNode) -> Option<&Scope> {
+        self.node_scopes.get(node).map(|id| &self.scopes[*id])
+    }
+
+    /// The innermost scope which contains this node.
+    pub fn scope_of(&self, node: &SyntaxNode) -> Option<&Scope> {
+        node.ancestors()
+            .find_map(|ancestor| self.scope_created_by(&ancestor))
+            .or_else(|| self.root_scope())
+    }
+
+    /// The binding declared by a name node (a `NAME` or a `SINGLE_PATTERN`).
+    pub fn declared_binding(&self, name: &SyntaxNode) -> Option<&Binding> {
+        self.name_bindings.get(name).map(|id| &self.bindings[*id])
+    }
+
+    /// The reference made by a `NAME_REF` or `NAME` node.
+    pub fn reference_at(&self, node: &SyntaxNode) -> Option<&Reference> {
+        self.name_references
+            .get(node)
+            .map(|id| &self.references[*id])
+    }
+
+    /// Resolve a name node to its binding, this works for both declarations and references.
+    pub fn resolve(&self, node: &SyntaxNode) -> Option<&Binding> {
+        self.declared_binding(node).or_else(|| {
+            self.reference_at(node)
+                .and_then(|reference| reference.binding)
+                .map(|id| &self.bindings[id])
+        })
+    }
+
+    /// Find a binding by name starting at a scope and walking up the scope chain.
+    pub fn lookup(&self, scope: ScopeId, name: &str) -> Option<&Binding> {
+        let mut cur = Some(scope);
+        while let Some(id) = cur {
+            let scope = &self.scopes[id];
+            if let Some(binding) = scope.get(name) {
+                return Some(&self.bindings[binding]);
+            }
+            cur = scope.parent;
+        }
+        None
+    }
+
+    /// All of the bindings declared inside of a node, including nested declarations.
+    pub fn declarations_in<'a>(&'a self, node: &SyntaxNode) -> impl Iterator<Item = &'a Binding> {
+        let range = node.text_range();
+        self.bindings
+            .iter()
+            .filter(move |binding| range.contains_range(binding.range()))
+    }
+
+    /// All of the references which occur inside of a node, including nested references.
+    pub fn references_in<'a>(&'a self, node: &SyntaxNode) -> impl Iterator<Item = &'a Reference> {
+        let range = node.text_range();
+        self.references
+            .iter()
+            .filter(move |reference| range.contains_range(reference.node.text_range()))
+    }
+
+    /// All of the references which resolve to a binding.
+    pub fn references_to(&self, binding: BindingId) -> impl Iterator<Item = &Reference> {
+        self.bindings[binding]
+            .references
+            .iter()
+            .map(move |id| &self.references[*id])
+    }
+
+    /// All of the references which read the value of a binding.
+    pub fn reads(&self, binding: BindingId) -> impl Iterator<Item = &Reference> {
+        self.references_to(binding).filter(|r| r.is_read())
+    }
+
+    /// All of the references which write to a binding, this includes initializers.
+    pub fn writes(&self, binding: BindingId) -> impl Iterator<Item = &Reference> {
+        self.references_to(binding).filter(|r| r.is_write())
+    }
+
+    /// All of the references which write to a binding other than its initialization,
+    /// such as `a = 1`, `a++`, or `[a] = foo`.
+    pub fn reassignments(&self, binding: BindingId) -> impl Iterator<Item = &Reference> {
+        self.writes(binding).filter(|r| !r.init)
+    }
+
+    /// The bindings declared by the patterns of a variable declaration in order, bindings declared
+    /// inside of initializers or default values, such as the parameters of a function, are not included.
+    pub fn var_decl_bindings(&self, decl: &ast::VarDecl) -> Vec<&Binding> {
+        let mut names = vec![];
+        for pattern in decl
+            .declared()
+            .filter_map(|declarator| declarator.pattern())
+        {
+            pattern_names(pattern.syntax(), &mut names);
+        }
+        names
+            .iter()
+            .filter_map(|name| self.declared_binding(name))
+            .collect()
+    }
+
+    /// All of the references which could not be resolved to any binding in the file.
+    /// The implicit `arguments` binding of functions is not included.
+    pub fn unresolved_references(&self) -> impl Iterator<Item = &Reference> {
+        self.unresolved.iter().map(move |id| &self.references[*id])
+    }
+
+    /// Get a global available to the file by name, either from the model's globals or
+    /// from a `/* global */` comment in the file. This does not consider bindings which shadow the global.
+    pub fn global(&self, name: &str) -> Option<GlobalValue> {
+        match self.file_globals.get(name) {
+            Some(GlobalValue::Off) => None,
+            Some(value) => Some(*value),
+            None => self.globals.get(name),
+        }
+    }
+
+    /// The globals the model was built with, this does not include globals declared in the file.
+    pub fn globals(&self) -> &Globals {
+        &self.globals
+    }
+
+    /// Globals declared in the file with `/* global foo, bar: writable */` comments.
+    pub fn file_globals(&self) -> impl Iterator<Item = (&str, GlobalValue)> {
+        self.file_globals
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+    }
+}
+
+/// Collect the name nodes declared by a binding pattern, this mirrors how the builder declares patterns.
+pub(crate) fn pattern_names(node: &SyntaxNode, names: &mut Vec<SyntaxNode>) {
+    use rslint_parser::{SyntaxKind::*, SyntaxNodeExt};
+
+    match node.kind() {
+        NAME => names.push(node.to_owned()),
+        SINGLE_PATTERN => names.push(
+            node.child_with_kind(NAME)
+                .unwrap_or_else(|| node.to_owned()),
+        ),
+        ASSIGN_PATTERN => {
+            if let Some(target) = node.to::<ast::AssignPattern>().key() {
+                pattern_names(target.syntax(), names);
+            }
+        }
+        REST_PATTERN | ARRAY_PATTERN | OBJECT_PATTERN => {
+            for child in node.children() {
+                pattern_names(&child, names);
+            }
+        }
+        KEY_VALUE_PATTERN => {
+            if let Some(value) = node.to::<ast::KeyValuePattern>().value() {
+                pattern_names(value.syntax(), names);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rslint_parser::{parse_module, parse_text};
+
+    fn binding<'a>(model: &'a SemanticModel, name: &str) -> &'a Binding {
+        model
+            .bindings()
+            .iter()
+            .find(|b| b.name == name)
+            .unwrap_or_else(|| panic!("no binding named `{}`", name))
+    }
+
+    #[test]
+    fn var_hoists_to_function_scope() {
+        let root = parse_text("function foo() { { var a = 1; let b; } a; }", 0).syntax();
+        let model = SemanticModel::new(&root);
+        let a = binding(&model, "a");
+        let b = binding(&model, "b");
+        assert_eq!(model.scope(a.scope).kind, ScopeKind::Function);
+        assert_eq!(model.scope(b.scope).kind, ScopeKind::Block);
+        assert_eq!(model.reads(a.id).count(), 1);
+        assert_eq!(model.writes(a.id).count(), 1);
+        assert!(model.writes(a.id).all(|r| r.init));
+    }
+
+    #[test]
+    fn resolves_to_innermost_binding() {
+        let root = parse_text("let a = 1; { let a = 2; a; } a;", 0).syntax();
+        let model = SemanticModel::new(&root);
+        let bindings = model
+            .bindings()
+            .iter()
+            .filter(|b| b.name == "a")
+            .collect::<Vec<_>>();
+        assert_eq!(bindings.len(), 2);
+        assert_eq!(model.reads(bindings[0].id).count(), 1);
+        assert_eq!(model.reads(bindings[1].id).count(), 1);
+    }
+
+    #[test]
+    fn writes_and_read_writes() {
+        let root = parse_text("let a, b, c; a = 1; b += 1; c++; [a, { b }] = [];", 0).syntax();
+        let model = SemanticModel::new(&root);
+        assert_eq!(model.writes(binding(&model, "a").id).count(), 2);
+        assert_eq!(model.reads(binding(&model, "a").id).count(), 0);
+        assert_eq!(model.reads(binding(&model, "b").id).count(), 1);
+        assert_eq!(model.writes(binding(&model, "b").id).count(), 2);
+        let c = model.references_to(binding(&model, "c").id).next().unwrap();
+        assert_eq!(c.kind, ReferenceKind::ReadWrite);
+    }
+
+    #[test]
+    fn unresolved_and_arguments() {
+        let root = parse_text(
+            "function f() { arguments; } () => arguments; foo; typeof bar;",
+            0,
+        )
+        .syntax();
+        let model = SemanticModel::new(&root);
+        let unresolved = model
+            .unresolved_references()
+            .map(|r| r.name.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(unresolved, vec!["arguments", "foo", "bar"]);
+        assert!(model.unresolved_references().last().unwrap().is_typeof());
+    }
+
+    #[test]
+    fn params_classes_and_catch() {
+        let root = parse_text(
+            "class A { m(x, { y = x }) { return A; } } try {} catch ({ e }) { e; }",
+            0,
+        )
+        .syntax();
+        let model = SemanticModel::new(&root);
+        assert_eq!(binding(&model, "x").kind, BindingKind::Param);
+        assert_eq!(binding(&model, "y").kind, BindingKind::Param);
+        assert_eq!(model.reads(binding(&model, "x").id).count(), 1);
+        assert_eq!(model.reads(binding(&model, "A").id).count(), 1);
+        let e = binding(&model, "e");
+        assert_eq!(e.kind, BindingKind::CatchParam);
+        assert_eq!(model.scope(e.scope).kind, ScopeKind::Catch);
+        assert_eq!(model.reads(e.id).count(), 1);
+    }
+
+    #[test]
+    fn imports_and_exports() {
+        let root = parse_module(
+            "import a, { b as c } from 'x'; import * as d from 'y'; const e = 1; export { e }; export function f() {}",
+            0,
+        )
+        .syntax();
+        let model = SemanticModel::new(&root);
+        assert_eq!(model.root_scope().unwrap().kind, ScopeKind::Module);
+        for name in &["a", "c", "d"] {
+            assert_eq!(binding(&model, name).kind, BindingKind::Import);
+        }
+        assert!(model.bindings().iter().all(|b| b.name != "b"));
+        assert!(binding(&model, "e").exported);
+        assert!(binding(&model, "f").exported);
+        assert!(!binding(&model, "a").exported);
+    }
+}
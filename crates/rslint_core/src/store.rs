@@ -1,12 +1,16 @@
 //! A rule store, which houses rule groups as well as individual rules.
 
+use crate::globals::Globals;
 use crate::groups::*;
 use crate::CstRule;
+use std::sync::Arc;
 
 /// A utility structure for housing CST rules for a linting run.
 #[derive(Debug, Default, Clone)]
 pub struct CstRuleStore {
     pub rules: Vec<Box<dyn CstRule>>,
+    /// The globals available to every file linted with this store.
+    pub globals: Arc<Globals>,
 }
 
 impl CstRuleStore {
@@ -16,7 +20,9 @@ impl CstRuleStore {
 
     /// All built in rules from every group.
     pub fn builtins(mut self) -> Self {
+        self.rules.extend(complexity());
         self.rules.extend(errors());
+        self.rules.extend(regex());
         self.rules.extend(style());
         self
     }
@@ -26,6 +32,12 @@ impl CstRuleStore {
         self.rules.extend(rules);
     }
 
+    /// Set the globals available to every file linted with this store.
+    pub fn with_globals(mut self, globals: Globals) -> Self {
+        self.globals = Arc::new(globals);
+        self
+    }
+
     /// Get a rule using its rule name from this store.
     ///
     /// # Examples
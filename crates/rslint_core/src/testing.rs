@@ -1,4 +1,5 @@
-//! Macros for easily making rule tests which also generate documentation examples.
+//! Macros for easily making rule tests which also generate documentation examples,
+//! and tests for the autofixes of rules.
 
 /// A macro for generating linter rule tests.
 ///
@@ -6,9 +7,48 @@
 /// in user facing docs. You can use a `/// ignore` doc
 /// on a code expr to make docgen ignore it for user facing docs.
 ///
-/// test code is run as modules, not scripts.
+/// test code is run as modules, not scripts. Tests starting with `typescript,` or `script,`
+/// followed by the test names are run as TypeScript or as scripts instead.
 #[macro_export]
 macro_rules! rule_tests {
+    (
+    script,
+    $ok_name:ident,
+    $err_name:ident,
+    $rule:expr,
+    err: {
+        $(
+            $(#[$err_meta:meta])*
+            $code:literal
+        ),* $(,)?
+    },
+    ok: {
+        $(
+            $(#[$ok_meta:meta])*
+            $ok_code:literal
+        ),* $(,)?
+    } $(,)?) => {
+        rule_tests!(@syntax rslint_parser::Syntax::default(), $ok_name, $err_name, $rule, err: { $($code),* }, ok: { $($ok_code),* });
+    };
+    (
+    typescript,
+    $ok_name:ident,
+    $err_name:ident,
+    $rule:expr,
+    err: {
+        $(
+            $(#[$err_meta:meta])*
+            $code:literal
+        ),* $(,)?
+    },
+    ok: {
+        $(
+            $(#[$ok_meta:meta])*
+            $ok_code:literal
+        ),* $(,)?
+    } $(,)?) => {
+        rule_tests!(@syntax rslint_parser::Syntax::default().typescript(), $ok_name, $err_name, $rule, err: { $($code),* }, ok: { $($ok_code),* });
+    };
     ($rule:expr,
     err: {
         $(
@@ -48,10 +88,19 @@ macro_rules! rule_tests {
             $ok_code:literal
         ),* $(,)?
     } $(,)?) => {
+        rule_tests!(@syntax rslint_parser::Syntax::default().module(), $ok_name, $err_name, $rule, err: { $($code),* }, ok: { $($ok_code),* });
+    };
+    (
+    @syntax $syntax:expr,
+    $ok_name:ident,
+    $err_name:ident,
+    $rule:expr,
+    err: { $($code:literal),* },
+    ok: { $($ok_code:literal),* }) => {
         #[test]
         fn $err_name() {
             $(
-                let res = rslint_parser::parse_module($code, 0);
+                let res = rslint_parser::parse_with_syntax($code, 0, $syntax);
                 let errs = $crate::run_rule(&$rule, 0, res.syntax(), true, &[], std::sync::Arc::from($code.to_string()));
                 if errs.diagnostics.is_empty() {
                     panic!("\nExpected:\n```\n{}\n```\nto fail linting, but instead it passed (with {} parsing errors)", $code, res.errors().len());
@@ -62,7 +111,7 @@ macro_rules! rule_tests {
         #[test]
         fn $ok_name() {
             $(
-                let res = rslint_parser::parse_module($ok_code, 0);
+                let res = rslint_parser::parse_with_syntax($ok_code, 0, $syntax);
                 let errs = $crate::run_rule(&$rule, 0, res.syntax(), true, &[], std::sync::Arc::from($ok_code.to_string()));
 
                 if !errs.diagnostics.is_empty() {
@@ -72,3 +121,48 @@ macro_rules! rule_tests {
         }
     };
 }
+
+/// A macro for generating tests for the autofix of a rule.
+///
+/// Each case is the source code and the code it is expected to be fixed to, the fix of the rule is
+/// applied a single time. Code which should not be changed by the fix has itself as the expected code.
+/// The fixed code must parse without errors.
+///
+/// test code is run as modules, not scripts. Tests starting with `typescript,` or `script,`
+/// followed by the test name are run as TypeScript or as scripts instead.
+#[macro_export]
+macro_rules! fix_tests {
+    (script, $name:ident, $rule:expr, $($code:literal => $fixed:literal),* $(,)?) => {
+        fix_tests!(@syntax rslint_parser::Syntax::default(), $name, $rule, $($code => $fixed),*);
+    };
+    (typescript, $name:ident, $rule:expr, $($code:literal => $fixed:literal),* $(,)?) => {
+        fix_tests!(@syntax rslint_parser::Syntax::default().typescript(), $name, $rule, $($code => $fixed),*);
+    };
+    ($rule:expr, $($code:literal => $fixed:literal),* $(,)?) => {
+        fix_tests!(fixes, $rule, $($code => $fixed),*);
+    };
+    ($name:ident, $rule:expr, $($code:literal => $fixed:literal),* $(,)?) => {
+        fix_tests!(@syntax rslint_parser::Syntax::default().module(), $name, $rule, $($code => $fixed),*);
+    };
+    (@syntax $syntax:expr, $name:ident, $rule:expr, $($code:literal => $fixed:literal),*) => {
+        #[test]
+        fn $name() {
+            $(
+                let res = rslint_parser::parse_with_syntax($code, 0, $syntax);
+                let result = $crate::run_rule(&$rule, 0, res.syntax(), true, &[], std::sync::Arc::from($code.to_string()));
+                let fixed = result.fix().unwrap_or_else(|| $code.to_string());
+                if fixed != $fixed {
+                    panic!("\nExpected:\n```\n{}\n```\nto be fixed to:\n```\n{}\n```\nbut instead it was fixed to:\n```\n{}\n```", $code, $fixed, fixed);
+                }
+                let errors = rslint_parser::parse_with_syntax(&fixed, 0, $syntax)
+                    .errors()
+                    .iter()
+                    .filter(|err| err.severity == $crate::Severity::Error)
+                    .count();
+                if errors != 0 {
+                    panic!("\nExpected the fix of:\n```\n{}\n```\nto parse, but it has {} parsing errors", $code, errors);
+                }
+            )*
+        }
+    };
+}
@@ -1,9 +1,13 @@
 //! General utilities to make linting easier.
 
 mod const_exprs;
+mod regex_pattern;
+mod selector;
 mod style;
 
 pub use const_exprs::*;
+pub use regex_pattern::*;
+pub use selector::*;
 pub use style::*;
 
 use crate::rule_prelude::*;
@@ -248,3 +252,209 @@ pub fn outer_function(node: impl Borrow<SyntaxNode>) -> Option<SyntaxNode> {
         .skip(1)
         .find(|ancestor| matches!(ancestor.kind(), ARROW_EXPR | FN_DECL | FN_EXPR))
 }
+
+/// Whether a node is strict mode code, which is any code in a module or a class, or inside of a script
+/// or function whose body starts with a `"use strict"` directive.
+pub fn is_strict(node: impl Borrow<SyntaxNode>) -> bool {
+    node.borrow()
+        .ancestors()
+        .any(|ancestor| match ancestor.kind() {
+            MODULE | CLASS_DECL | CLASS_EXPR => true,
+            SCRIPT => has_use_strict(&ancestor),
+            FN_DECL | FN_EXPR | ARROW_EXPR | METHOD | GETTER | SETTER => ancestor
+                .child_with_kind(BLOCK_STMT)
+                .map_or(false, |body| has_use_strict(&body)),
+            _ => false,
+        })
+}
+
+/// Whether the directives at the start of a script or function body include `"use strict"`.
+fn has_use_strict(body: &SyntaxNode) -> bool {
+    body.children()
+        .map(|stmt| match stmt.try_to::<ExprStmt>()?.expr()? {
+            Expr::Literal(literal) => literal.inner_string_text(),
+            _ => None,
+        })
+        .take_while(Option::is_some)
+        .any(|directive| directive.map_or(false, |text| text == "use strict"))
+}
+
+/// Convert the text of a string literal without its quotes into the text of a template literal
+/// with the same value, escaping backticks and, if `escape_dollars` is true, `$` so `${` does not start a substitution.
+///
+/// Returns `None` if the string contains escapes which are not allowed in templates, such as `\01` or `\8`.
+pub fn string_to_template_text(text: &str, escape_dollars: bool) -> Option<std::string::String> {
+    let mut result = std::string::String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = chars.next()?;
+                let is_octal = match escaped {
+                    '0' => chars.peek().map_or(false, |next| next.is_ascii_digit()),
+                    '1'..='9' => true,
+                    _ => false,
+                };
+                if is_octal {
+                    return None;
+                }
+                result.push(c);
+                result.push(escaped);
+            }
+            '`' => result.push_str("\\`"),
+            '$' if escape_dollars => result.push_str("\\$"),
+            _ => result.push(c),
+        }
+    }
+    Some(result)
+}
+
+/// Change the quotes of the text of a string or template literal without its quotes, `from` and `to` are
+/// either `'`, `"`, or `` ` ``. The new quotes are escaped and escaped old quotes are unescaped, other escapes
+/// and line continuations are kept as they are. `$` is escaped if it would start a substitution in a template.
+///
+/// Returns `None` if the text can not be quoted with the new quotes, which is the case for templates
+/// containing linebreaks which are not line continuations, and for strings containing escapes which are not allowed
+/// in templates, such as `\01` or `\8`.
+pub fn requote(text: &str, from: char, to: char) -> Option<std::string::String> {
+    let mut result = std::string::String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = chars.next()?;
+                let is_octal = match escaped {
+                    '0' => chars.peek().map_or(false, |next| next.is_ascii_digit()),
+                    '1'..='9' => true,
+                    _ => false,
+                };
+                if to == '`' && is_octal {
+                    return None;
+                }
+                if escaped == from && from != to {
+                    result.push(escaped);
+                    continue;
+                }
+                result.push(c);
+                result.push(escaped);
+                // `\r\n` is a single line continuation
+                if escaped == '\r' && chars.peek() == Some(&'\n') {
+                    result.push(chars.next()?);
+                }
+            }
+            _ if c == to => {
+                result.push('\\');
+                result.push(c);
+            }
+            '$' if to == '`' && chars.peek() == Some(&'{') => result.push_str("\\$"),
+            _ if rslint_lexer::is_linebreak(c) && to != '`' => return None,
+            _ => result.push(c),
+        }
+    }
+    Some(result)
+}
+
+/// The statically known name of a property key such as `a`, `"a"`, `1`, or `["a"]`.
+/// Returns `None` for computed keys which are not literals, e.g. `[a]`.
+pub fn static_prop_name(key: &SyntaxNode) -> Option<std::string::String> {
+    match key.kind() {
+        NAME | PRIVATE_NAME => Some(key.text().to_string()),
+        LITERAL => {
+            let literal = key.to::<Literal>();
+            if literal.is_string() {
+                Some(literal.inner_string_text()?.to_string())
+            } else {
+                literal.as_number().map(|num| num.to_string())
+            }
+        }
+        COMPUTED_PROPERTY_NAME => static_prop_name(key.child_with_ast::<Literal>()?.syntax()),
+        _ => None,
+    }
+}
+
+/// Whether a node is a function of any kind, including arrow functions, methods, accessors, and constructors.
+pub fn is_function(node: impl Borrow<SyntaxNode>) -> bool {
+    matches!(
+        node.borrow().kind(),
+        FN_DECL | FN_EXPR | ARROW_EXPR | METHOD | GETTER | SETTER | CONSTRUCTOR
+    )
+}
+
+/// A description of a function such as "function `foo`" or "arrow function", and the range of its name.
+/// This is used by rules which report a function as a whole to label the function without labeling its body.
+///
+/// Anonymous functions are described by the variable or property they are assigned to, e.g. `const foo = () => {}`
+/// is "arrow function `foo`". The range of functions without any name is their head, from their start up to
+/// the end of the parameters, or the end of the arrow for arrow functions.
+pub fn function_name(node: &SyntaxNode) -> Option<(std::string::String, TextRange)> {
+    let kind = match node.kind() {
+        FN_DECL | FN_EXPR => "function",
+        ARROW_EXPR => "arrow function",
+        METHOD => "method",
+        GETTER => "getter",
+        SETTER => "setter",
+        CONSTRUCTOR => "constructor",
+        _ => return None,
+    };
+    let is_key = |child: &SyntaxNode| {
+        matches!(
+            child.kind(),
+            NAME | LITERAL | COMPUTED_PROPERTY_NAME | PRIVATE_NAME
+        )
+    };
+
+    let name = match node.kind() {
+        FN_DECL | FN_EXPR => node.child_with_kind(NAME),
+        ARROW_EXPR => None,
+        CONSTRUCTOR => {
+            let name = node.child_with_kind(NAME)?;
+            return Some((kind.to_string(), name.trimmed_range()));
+        }
+        // the key is the last name before the parameters, since `get` and `set` are also parsed as names
+        _ => node
+            .children()
+            .take_while(|child| child.kind() != PARAMETER_LIST)
+            .filter(is_key)
+            .last(),
+    }
+    .or_else(|| {
+        let parent = node.parent()?;
+        match parent.kind() {
+            DECLARATOR => parent
+                .child_with_kind(SINGLE_PATTERN)
+                .and_then(|pattern| pattern.child_with_kind(NAME)),
+            LITERAL_PROP | CLASS_PROP | PRIVATE_PROP => parent
+                .first_child()
+                .filter(|key| key != node && is_key(key)),
+            _ => None,
+        }
+    });
+
+    match name {
+        Some(name) => Some((
+            format!("{} `{}`", kind, name.trimmed_text()),
+            name.trimmed_range(),
+        )),
+        None => {
+            let end = match node.kind() {
+                ARROW_EXPR => node.token_with_kind(FAT_ARROW)?.text_range().end(),
+                _ => node.child_with_kind(PARAMETER_LIST)?.text_range().end(),
+            };
+            Some((
+                kind.to_string(),
+                TextRange::new(node.trimmed_range().start(), end),
+            ))
+        }
+    }
+}
+
+/// The value at a percentile of a list of values such as the 95th percentile, using the nearest rank method.
+/// Returns `None` if there are no values.
+pub fn percentile(mut values: Vec<usize>, percentile: f64) -> Option<usize> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+    let rank = (percentile / 100.0 * values.len() as f64).ceil() as usize;
+    Some(values[rank.saturating_sub(1).min(values.len() - 1)])
+}
@@ -0,0 +1,217 @@
+//! Utilities for rules which inspect the patterns of regexes.
+
+use crate::rule_prelude::*;
+use crate::semantic::SemanticModel;
+use rslint_parser::regex::{parse_regex, Regex};
+use rslint_parser::TextRange;
+use std::ops::Range;
+use SyntaxKind::*;
+
+/// The parsed pattern of a regex literal such as `/a+/g`, or of the string literals passed to
+/// the `RegExp` constructor, such as `new RegExp("a+", "g")`.
+#[derive(Debug, Clone)]
+pub struct RegexPattern {
+    pub regex: Regex,
+    /// The regex as a regex literal, the ranges of the AST are relative to this.
+    /// For `RegExp` calls this is the value of the strings wrapped in slashes.
+    pub source: String,
+    /// The regex literal or the `RegExp` call.
+    pub node: SyntaxNode,
+    /// The offset in the file of every byte of `source`, with an extra entry for the end.
+    offsets: Vec<usize>,
+}
+
+impl RegexPattern {
+    /// Whether this pattern is a regex literal and not a `RegExp` call.
+    pub fn is_literal(&self) -> bool {
+        self.node.kind() == LITERAL
+    }
+
+    /// Map a range of the regex AST to its range in the file.
+    pub fn text_range(&self, range: Range<usize>) -> TextRange {
+        TextRange::new(
+            (self.offsets[range.start] as u32).into(),
+            (self.offsets[range.end] as u32).into(),
+        )
+    }
+
+    /// The text of a range of the regex AST.
+    pub fn text(&self, range: Range<usize>) -> &str {
+        &self.source[range]
+    }
+
+    /// Whether a range of the regex AST is written in the file exactly as it is in the pattern,
+    /// which is not the case for escapes in strings. Fixes can only replace verbatim ranges.
+    pub fn is_verbatim(&self, range: Range<usize>) -> bool {
+        self.offsets[range.start..=range.end]
+            .windows(2)
+            .all(|pair| pair[1] == pair[0] + 1)
+    }
+}
+
+/// Get the pattern of a regex literal or of a call to the global `RegExp` with string literal arguments.
+///
+/// `None` is returned for any other node, and for patterns which have errors.
+pub fn regex_pattern(node: &SyntaxNode, model: &SemanticModel) -> Option<RegexPattern> {
+    let (source, offsets) = match node.kind() {
+        LITERAL => {
+            let token = node.to::<ast::Literal>().token();
+            if token.kind() != REGEX {
+                return None;
+            }
+            let start = usize::from(token.text_range().start());
+            let source = token.text().to_string();
+            let offsets = (start..=start + source.len()).collect();
+            (source, offsets)
+        }
+        CALL_EXPR | NEW_EXPR => {
+            let (callee, args) = match node.try_to::<ast::CallExpr>() {
+                Some(call) => (call.callee()?, call.arguments()?),
+                None => {
+                    let new = node.to::<ast::NewExpr>();
+                    (new.object()?, new.arguments()?)
+                }
+            };
+            let callee = callee.syntax();
+            if callee.kind() != NAME_REF
+                || callee.text() != "RegExp"
+                || model.resolve(callee).is_some()
+            {
+                return None;
+            }
+            regexp_call_source(args)?
+        }
+        _ => return None,
+    };
+
+    let (regex, errors) = parse_regex(&source, 0, 0)?;
+    if !errors.is_empty() {
+        return None;
+    }
+    Some(RegexPattern {
+        regex,
+        source,
+        node: node.clone(),
+        offsets,
+    })
+}
+
+/// Build a regex literal out of the pattern and flags strings of a `RegExp` call.
+fn regexp_call_source(args: ast::ArgList) -> Option<(String, Vec<usize>)> {
+    let mut args = args.args().map(|arg| match arg {
+        ast::Expr::Literal(literal) if literal.is_string() => Some(literal),
+        _ => None,
+    });
+    let pattern = args.next()??;
+    let flags = match args.next() {
+        Some(flags) => Some(flags?),
+        None => None,
+    };
+
+    let mut source = String::new();
+    let mut offsets = vec![];
+    let pattern_range = pattern.syntax().text_range();
+    push_char(&mut source, &mut offsets, '/', pattern_range.start().into());
+    cook_string(&pattern, &mut source, &mut offsets)?;
+    push_char(
+        &mut source,
+        &mut offsets,
+        '/',
+        usize::from(pattern_range.end()) - 1,
+    );
+    if let Some(flags) = flags {
+        cook_string(&flags, &mut source, &mut offsets)?;
+    }
+    offsets.push(offsets.last().map_or(0, |last| last + 1));
+    Some((source, offsets))
+}
+
+fn push_char(source: &mut String, offsets: &mut Vec<usize>, c: char, offset: usize) {
+    source.push(c);
+    offsets.extend(std::iter::repeat(offset).take(c.len_utf8()));
+}
+
+/// Append the value of a string literal, the bytes of an escape are all mapped to the start of the escape.
+/// `None` is returned if the string contains a lone surrogate since it cannot be represented.
+fn cook_string(
+    literal: &ast::Literal,
+    source: &mut String,
+    offsets: &mut Vec<usize>,
+) -> Option<()> {
+    let text = literal.syntax().text().to_string();
+    let raw = &text[1..text.len() - 1];
+    let start = usize::from(literal.syntax().text_range().start()) + 1;
+    let hex = |digits: &str| u32::from_str_radix(digits, 16).ok();
+
+    let mut idx = 0;
+    while let Some(c) = raw[idx..].chars().next() {
+        if c != '\\' {
+            push_char(source, offsets, c, start + idx);
+            idx += c.len_utf8();
+            continue;
+        }
+        let escape_start = idx;
+        let escaped = raw[idx + 1..].chars().next()?;
+        idx += 1 + escaped.len_utf8();
+        let value = match escaped {
+            'b' => 0x08,
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            // line continuations
+            '\r' | '\n' | '\u{2028}' | '\u{2029}' => {
+                if escaped == '\r' && raw[idx..].starts_with('\n') {
+                    idx += 1;
+                }
+                continue;
+            }
+            '0'..='7' => {
+                // legacy octal escapes are at most `\377`
+                let max_len = if escaped <= '3' { 3 } else { 2 };
+                let len = raw[escape_start + 1..]
+                    .bytes()
+                    .take(max_len)
+                    .take_while(|b| (b'0'..=b'7').contains(b))
+                    .count();
+                idx = escape_start + 1 + len;
+                u32::from_str_radix(&raw[escape_start + 1..idx], 8).ok()?
+            }
+            'x' => {
+                let value = hex(raw.get(idx..idx + 2)?)?;
+                idx += 2;
+                value
+            }
+            'u' if raw[idx..].starts_with('{') => {
+                let end = idx + raw[idx..].find('}')?;
+                let value = hex(&raw[idx + 1..end])?;
+                idx = end + 1;
+                value
+            }
+            'u' => {
+                let lead = hex(raw.get(idx..idx + 4)?)?;
+                idx += 4;
+                if (0xD800..=0xDBFF).contains(&lead) {
+                    let trail = raw
+                        .get(idx..idx + 6)
+                        .filter(|escape| escape.starts_with("\\u"))
+                        .and_then(|escape| hex(&escape[2..]))
+                        .filter(|trail| (0xDC00..=0xDFFF).contains(trail))?;
+                    idx += 6;
+                    0x10000 + ((lead - 0xD800) << 10) + (trail - 0xDC00)
+                } else {
+                    lead
+                }
+            }
+            c => c as u32,
+        };
+        push_char(
+            source,
+            offsets,
+            std::char::from_u32(value)?,
+            start + escape_start,
+        );
+    }
+    Some(())
+}
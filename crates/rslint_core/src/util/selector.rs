@@ -0,0 +1,602 @@
+//! A query language for matching syntax nodes, similar to CSS selectors but using `SyntaxKind` names.
+
+use crate::rule_prelude::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A selector which matches syntax nodes by their kind, the field they are in, and their text.
+///
+/// The syntax is similar to CSS selectors:
+///
+/// - `CALL_EXPR` matches nodes of a kind, `*` matches nodes of any kind.
+/// - `A B` matches `B` nodes which are descendants of `A` nodes, `A > B` matches `B` nodes which are children of `A` nodes.
+/// - `callee` matches nodes which are the field of their parent named by the field's accessor, such as
+///   the callee of a `CALL_EXPR`. A field can be followed by a kind, `callee NAME_REF` matches a `NAME_REF`
+///   which is the callee of its parent.
+/// - `[text="eval"]` matches nodes whose text without leading and trailing whitespace is `eval`. The other
+///   operators are `!=`, `^=` (starts with), `$=` (ends with), and `*=` (contains). The value can also be
+///   a regex such as `[text=/^set/]`, which can be used with `=` and `!=`.
+/// - `:has(A)` matches nodes which have a descendant matching `A`, `:has(> A)` matches nodes which have a child
+///   matching `A`. `:not(A)` matches nodes which do not match `A`.
+/// - `A, B` matches nodes which match either `A` or `B`.
+///
+/// For example `CALL_EXPR > callee NAME_REF[text="eval"]` matches the name of calls to `eval`.
+#[derive(Debug, Clone)]
+pub struct Selector {
+    source: String,
+    alternatives: Vec<Complex>,
+}
+
+/// An error for an invalid selector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorError {
+    pub message: String,
+    /// The offset of the error in the selector.
+    pub offset: usize,
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+/// Steps joined by combinators, e.g. `A > B C`.
+#[derive(Debug, Clone)]
+struct Complex {
+    steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+/// A single part of a selector such as `callee NAME_REF[text="eval"]`.
+#[derive(Debug, Clone)]
+struct Step {
+    /// How this step relates to the step before it, for the first step of a selector inside of `:has`
+    /// this is how it relates to the node being checked.
+    combinator: Combinator,
+    field: Option<String>,
+    /// The kind of the node, `None` for `*` or for steps with only a field.
+    kind: Option<SyntaxKind>,
+    filters: Vec<Filter>,
+}
+
+#[derive(Debug, Clone)]
+enum Filter {
+    Text(TextOp, TextValue),
+    Has(Vec<Complex>),
+    Not(Vec<Complex>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextOp {
+    Eq,
+    NotEq,
+    StartsWith,
+    EndsWith,
+    Contains,
+}
+
+#[derive(Debug, Clone)]
+enum TextValue {
+    String(String),
+    Regex(Regex),
+}
+
+impl Selector {
+    /// Parse a selector, see [`Selector`] for the syntax.
+    pub fn parse(source: &str) -> Result<Self, SelectorError> {
+        let mut parser = Parser { source, offset: 0 };
+        let alternatives = parser.list(false)?;
+        if let Some(c) = parser.peek() {
+            return Err(parser.err(format!("unexpected `{}`", c)));
+        }
+        Ok(Self {
+            source: source.to_string(),
+            alternatives,
+        })
+    }
+
+    /// The text this selector was parsed from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether a node matches this selector.
+    pub fn matches(&self, node: &SyntaxNode) -> bool {
+        self.alternatives
+            .iter()
+            .any(|complex| complex.matches(node, None))
+    }
+}
+
+impl Serialize for Selector {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for Selector {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        Self::parse(&source).map_err(|err| {
+            serde::de::Error::custom(format!("invalid selector `{}`: {}", source, err))
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Selector {
+    fn schema_name() -> String {
+        "Selector".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+impl Complex {
+    /// Whether a node matches this selector, `scope` is the node checked by `:has` which the
+    /// matched nodes must be inside of.
+    fn matches(&self, node: &SyntaxNode, scope: Option<&SyntaxNode>) -> bool {
+        matches_steps(&self.steps, node, scope)
+    }
+}
+
+/// Whether the last step matches a node and the steps before it match its ancestors.
+fn matches_steps(steps: &[Step], node: &SyntaxNode, scope: Option<&SyntaxNode>) -> bool {
+    let (step, rest) = match steps.split_last() {
+        Some(split) => split,
+        None => return false,
+    };
+    if !step.matches(node) {
+        return false;
+    }
+
+    let mut ancestors = node
+        .ancestors()
+        .skip(1)
+        .take_while(|ancestor| Some(ancestor) != scope);
+    if rest.is_empty() {
+        return match (scope, step.combinator) {
+            (None, _) => true,
+            (Some(scope), Combinator::Child) => node.parent().as_ref() == Some(scope),
+            (Some(scope), Combinator::Descendant) => {
+                node != scope && node.ancestors().any(|ancestor| &ancestor == scope)
+            }
+        };
+    }
+    match step.combinator {
+        Combinator::Child => ancestors
+            .next()
+            .map_or(false, |parent| matches_steps(rest, &parent, scope)),
+        Combinator::Descendant => ancestors.any(|ancestor| matches_steps(rest, &ancestor, scope)),
+    }
+}
+
+impl Step {
+    fn matches(&self, node: &SyntaxNode) -> bool {
+        if self.kind.map_or(false, |kind| kind != node.kind()) {
+            return false;
+        }
+        if let Some(field) = &self.field {
+            let in_field = node
+                .parent()
+                .and_then(|parent| ast::node_field(&parent, field))
+                .map_or(false, |nodes| nodes.contains(node));
+            if !in_field {
+                return false;
+            }
+        }
+        self.filters.iter().all(|filter| filter.matches(node))
+    }
+}
+
+impl Filter {
+    fn matches(&self, node: &SyntaxNode) -> bool {
+        match self {
+            Filter::Text(op, value) => {
+                let text = node.trimmed_text().to_string();
+                match (op, value) {
+                    (TextOp::Eq, TextValue::String(value)) => text == *value,
+                    (TextOp::NotEq, TextValue::String(value)) => text != *value,
+                    (TextOp::StartsWith, TextValue::String(value)) => text.starts_with(value),
+                    (TextOp::EndsWith, TextValue::String(value)) => text.ends_with(value),
+                    (TextOp::Contains, TextValue::String(value)) => text.contains(value),
+                    (TextOp::NotEq, TextValue::Regex(regex)) => !regex.is_match(&text),
+                    (_, TextValue::Regex(regex)) => regex.is_match(&text),
+                }
+            }
+            Filter::Has(alternatives) => node.descendants().skip(1).any(|descendant| {
+                alternatives
+                    .iter()
+                    .any(|complex| complex.matches(&descendant, Some(node)))
+            }),
+            Filter::Not(alternatives) => !alternatives
+                .iter()
+                .any(|complex| complex.matches(node, None)),
+        }
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+impl Parser<'_> {
+    fn err(&self, message: impl Into<String>) -> SelectorError {
+        SelectorError {
+            message: message.into(),
+            offset: self.offset,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.offset..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), SelectorError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.err(format!("expected `{}`", c)))
+        }
+    }
+
+    /// Skip whitespace and return whether there was any.
+    fn whitespace(&mut self) -> bool {
+        let start = self.offset;
+        while self.peek().map_or(false, char::is_whitespace) {
+            self.bump();
+        }
+        self.offset != start
+    }
+
+    fn ident(&mut self) -> &str {
+        let start = self.offset;
+        while self
+            .peek()
+            .map_or(false, |c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.bump();
+        }
+        &self.source[start..self.offset]
+    }
+
+    /// Selectors separated by commas, `relative` selectors are the ones inside of `:has` which may start with `>`.
+    fn list(&mut self, relative: bool) -> Result<Vec<Complex>, SelectorError> {
+        let mut alternatives = vec![];
+        loop {
+            self.whitespace();
+            alternatives.push(self.complex(relative)?);
+            self.whitespace();
+            if !self.eat(',') {
+                return Ok(alternatives);
+            }
+        }
+    }
+
+    fn complex(&mut self, relative: bool) -> Result<Complex, SelectorError> {
+        let mut combinator = Combinator::Descendant;
+        if relative && self.eat('>') {
+            combinator = Combinator::Child;
+            self.whitespace();
+        }
+        let mut steps = vec![self.step(combinator)?];
+
+        loop {
+            let had_whitespace = self.whitespace();
+            match self.peek() {
+                None | Some(',') | Some(')') => break,
+                Some('>') => {
+                    self.bump();
+                    self.whitespace();
+                    combinator = Combinator::Child;
+                }
+                _ if had_whitespace => combinator = Combinator::Descendant,
+                Some(c) => return Err(self.err(format!("unexpected `{}`", c))),
+            }
+            steps.push(self.step(combinator)?);
+        }
+        Ok(Complex { steps })
+    }
+
+    fn step(&mut self, combinator: Combinator) -> Result<Step, SelectorError> {
+        let mut step = Step {
+            combinator,
+            field: None,
+            kind: None,
+            filters: vec![],
+        };
+
+        if self.peek().map_or(false, |c| c.is_ascii_lowercase()) {
+            step.field = Some(self.ident().to_string());
+            // a field followed by a kind or filters is a single step, e.g. `callee NAME_REF`
+            let before_whitespace = self.offset;
+            self.whitespace();
+            if !self.peek().map_or(false, |c| {
+                c.is_ascii_uppercase() || matches!(c, '*' | '[' | ':')
+            }) {
+                self.offset = before_whitespace;
+                return Ok(step);
+            }
+        }
+
+        match self.peek() {
+            Some('*') => {
+                self.bump();
+            }
+            Some(c) if c.is_ascii_uppercase() => {
+                let start = self.offset;
+                let name = self.ident().to_string();
+                step.kind = Some(kind_from_name(&name).ok_or_else(|| SelectorError {
+                    message: format!("unknown syntax kind `{}`", name),
+                    offset: start,
+                })?);
+            }
+            Some('[') | Some(':') => {}
+            Some(c) => return Err(self.err(format!("unexpected `{}`", c))),
+            None => return Err(self.err("expected a selector")),
+        }
+
+        loop {
+            match self.peek() {
+                Some('[') => {
+                    self.bump();
+                    step.filters.push(self.attribute()?);
+                }
+                Some(':') => {
+                    self.bump();
+                    let start = self.offset;
+                    let filter = match self.ident() {
+                        "has" => {
+                            self.expect('(')?;
+                            Filter::Has(self.list(true)?)
+                        }
+                        "not" => {
+                            self.expect('(')?;
+                            Filter::Not(self.list(false)?)
+                        }
+                        name => {
+                            return Err(SelectorError {
+                                message: format!("unknown pseudo class `:{}`", name),
+                                offset: start,
+                            })
+                        }
+                    };
+                    self.expect(')')?;
+                    step.filters.push(filter);
+                }
+                _ => return Ok(step),
+            }
+        }
+    }
+
+    /// An attribute after its `[`, such as `text="eval"]`.
+    fn attribute(&mut self) -> Result<Filter, SelectorError> {
+        self.whitespace();
+        let start = self.offset;
+        let name = self.ident();
+        if name != "text" {
+            return Err(SelectorError {
+                message: format!("unknown attribute `{}`, expected `text`", name),
+                offset: start,
+            });
+        }
+        self.whitespace();
+
+        let op = match self.bump() {
+            Some('=') => TextOp::Eq,
+            Some(c @ '!') | Some(c @ '^') | Some(c @ '$') | Some(c @ '*') => {
+                self.expect('=')?;
+                match c {
+                    '!' => TextOp::NotEq,
+                    '^' => TextOp::StartsWith,
+                    '$' => TextOp::EndsWith,
+                    _ => TextOp::Contains,
+                }
+            }
+            _ => return Err(self.err("expected an operator such as `=`")),
+        };
+        self.whitespace();
+
+        let value = match self.peek() {
+            Some(quote @ '"') | Some(quote @ '\'') => {
+                self.bump();
+                TextValue::String(self.string(quote)?)
+            }
+            Some('/') => {
+                if !matches!(op, TextOp::Eq | TextOp::NotEq) {
+                    return Err(self.err("regexes can only be used with `=` and `!=`"));
+                }
+                self.bump();
+                TextValue::Regex(self.regex()?)
+            }
+            _ => return Err(self.err("expected a string or a regex")),
+        };
+        self.whitespace();
+        self.expect(']')?;
+        Ok(Filter::Text(op, value))
+    }
+
+    /// The rest of a string after its opening quote.
+    fn string(&mut self, quote: char) -> Result<String, SelectorError> {
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                Some('\\') => match self.bump() {
+                    Some(c) => value.push(c),
+                    None => return Err(self.err("unterminated string")),
+                },
+                Some(c) if c == quote => return Ok(value),
+                Some(c) => value.push(c),
+                None => return Err(self.err("unterminated string")),
+            }
+        }
+    }
+
+    /// The rest of a regex after its opening slash, only the `i` flag is allowed.
+    fn regex(&mut self) -> Result<Regex, SelectorError> {
+        let start = self.offset;
+        let mut pattern = String::new();
+        loop {
+            match self.bump() {
+                Some('\\') => match self.bump() {
+                    Some('/') => pattern.push('/'),
+                    Some(c) => {
+                        pattern.push('\\');
+                        pattern.push(c);
+                    }
+                    None => return Err(self.err("unterminated regex")),
+                },
+                Some('/') => break,
+                Some(c) => pattern.push(c),
+                None => return Err(self.err("unterminated regex")),
+            }
+        }
+        if self.eat('i') {
+            pattern.insert_str(0, "(?i)");
+        }
+        Regex::new(&pattern).map_err(|err| SelectorError {
+            message: format!("invalid regex: {}", err),
+            offset: start,
+        })
+    }
+}
+
+/// Get a syntax kind from its name, such as `CALL_EXPR`.
+fn kind_from_name(name: &str) -> Option<SyntaxKind> {
+    (0..SyntaxKind::__LAST as u16)
+        .map(SyntaxKind::from)
+        .find(|kind| format!("{:?}", kind) == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rslint_parser::parse_module;
+
+    fn matches(selector: &str, code: &str) -> Vec<String> {
+        let selector = Selector::parse(selector).unwrap();
+        parse_module(code, 0)
+            .syntax()
+            .descendants()
+            .filter(|node| selector.matches(node))
+            .map(|node| node.trimmed_text().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn kinds_and_combinators() {
+        assert_eq!(matches("NAME_REF", "a + b;"), vec!["a", "b"]);
+        assert_eq!(matches("EXPR_STMT > *", "a;"), vec!["a"]);
+        assert_eq!(matches("CALL_EXPR > NAME_REF", "a(b);"), vec!["a"]);
+        assert_eq!(matches("CALL_EXPR NAME_REF", "a(b);"), vec!["a", "b"]);
+        assert_eq!(
+            matches("EXPR_STMT > CALL_EXPR ARG_LIST > NAME_REF", "a(b, c(d));"),
+            vec!["b", "d"]
+        );
+        assert_eq!(
+            matches("EXPR_STMT > CALL_EXPR > ARG_LIST > NAME_REF", "a(b, c(d));"),
+            vec!["b"]
+        );
+        assert_eq!(
+            matches("DOT_EXPR, BRACKET_EXPR", "a.b; c[d];"),
+            vec!["a.b", "c[d]"]
+        );
+    }
+
+    #[test]
+    fn fields() {
+        assert_eq!(
+            matches(
+                "CALL_EXPR > callee NAME_REF[text=\"eval\"]",
+                "eval(a); a(eval);"
+            ),
+            vec!["eval"]
+        );
+        assert_eq!(matches("BIN_EXPR > rhs", "a + b;"), vec!["b"]);
+        assert_eq!(matches("callee", "a.b(c);"), vec!["a.b"]);
+        assert_eq!(matches("ARG_LIST > args", "a(b, c);"), vec!["b", "c"]);
+        assert_eq!(
+            matches("IF_STMT > alt IF_STMT", "if (a) {} else if (b) {}"),
+            vec!["if (b) {}"]
+        );
+        assert!(matches("CALL_EXPR > unknown", "a(b);").is_empty());
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(matches("NAME_REF[text!='a']", "a + b;"), vec!["b"]);
+        assert_eq!(matches("NAME_REF[text^='fo']", "foo + bar;"), vec!["foo"]);
+        assert_eq!(matches("NAME_REF[text$='ar']", "foo + bar;"), vec!["bar"]);
+        assert_eq!(matches("NAME_REF[text*='o']", "foo + bar;"), vec!["foo"]);
+        assert_eq!(matches("NAME_REF[text=/^B/i]", "foo + bar;"), vec!["bar"]);
+        assert_eq!(
+            matches("LITERAL[text=\"'a\\\"'\"]", "'a\"';"),
+            vec!["'a\"'"]
+        );
+    }
+
+    #[test]
+    fn pseudo_classes() {
+        assert_eq!(
+            matches(
+                "FN_DECL:has(AWAIT_EXPR)",
+                "async function a() { await b; } function c() {}"
+            ),
+            vec!["async function a() { await b; }"]
+        );
+        assert_eq!(
+            matches("CALL_EXPR:has(> ARG_LIST > LITERAL)", "a(1); b(c(1));"),
+            vec!["a(1)", "c(1)"]
+        );
+        assert_eq!(
+            matches("CALL_EXPR:has(CALL_EXPR)", "a(b()); c();"),
+            vec!["a(b())"]
+        );
+        assert_eq!(
+            matches("NAME_REF:not([text='a'], [text='b'])", "a + b + c;"),
+            vec!["c"]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Selector::parse("FOO").unwrap_err().message,
+            "unknown syntax kind `FOO`"
+        );
+        assert_eq!(Selector::parse("NAME[text=a]").unwrap_err().offset, 10);
+        assert!(Selector::parse("NAME[kind='a']").is_err());
+        assert!(Selector::parse("NAME:first").is_err());
+        assert!(Selector::parse("NAME >").is_err());
+        assert!(Selector::parse("CALL_EXPR:has(NAME").is_err());
+        assert!(Selector::parse("NAME[text^=/a/]").is_err());
+        assert!(Selector::parse("").is_err());
+    }
+}
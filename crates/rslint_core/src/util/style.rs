@@ -127,3 +127,112 @@ pub fn tokens_have_whitespace(tokens: &[SyntaxToken], include_linebreaks: bool)
     }
     false
 }
+
+/// Get the whitespace directly before or after a token, which spacing rules check.
+///
+/// This is `None` if the token is at the start or end of a line or file, or if a comment is directly
+/// next to the token, since spacing rules do not check whitespace there.
+pub fn adjacent_whitespace(token: &SyntaxToken, before: bool) -> Option<Vec<SyntaxToken>> {
+    let adjacent = step(token, before)?;
+    let neighbor = iter::successors(Some(adjacent.clone()), |token| step(token, before))
+        .find(|token| !token.kind().is_trivia())?;
+    if adjacent.kind() == COMMENT || neighbor.kind() == EOF {
+        return None;
+    }
+
+    let whitespace = if before {
+        token.leading_whitespace(false)
+    } else {
+        token.trailing_whitespace(false)
+    };
+    if whitespace
+        .iter()
+        .any(|ws| parseutil::contains_js_linebreak(ws.text().as_str()))
+    {
+        None
+    } else {
+        Some(whitespace)
+    }
+}
+
+/// Check if the whitespace directly before or after a token is `expected`, and add a diagnostic and a fix
+/// for the rule if it is not. Whitespace which [`adjacent_whitespace`] does not return is not checked.
+///
+/// The fix only inserts or removes whitespace, and whitespace is not removed if that would join
+/// the tokens around it into different tokens, such as in `a - -b`.
+pub fn check_adjacent_whitespace(
+    ctx: &mut RuleCtx,
+    rule: &str,
+    token: &SyntaxToken,
+    before: bool,
+    expected: bool,
+) -> Option<()> {
+    let whitespace = adjacent_whitespace(token, before)?;
+    if tokens_have_whitespace(&whitespace, false) == expected {
+        return None;
+    }
+
+    let side = if before { "before" } else { "after" };
+    if expected {
+        let err = ctx
+            .err(
+                rule,
+                format!("expected a space {} `{}`", side, token.text()),
+            )
+            .primary(token, "");
+        ctx.add_err(err);
+        let fixer = ctx.extend_fix();
+        if before {
+            fixer.insert_before(token, " ");
+        } else {
+            fixer.insert_after(token, " ");
+        }
+    } else {
+        let neighbor = iter::successors(step(token, before), |token| step(token, before))
+            .find(|token| token.kind() != WHITESPACE)?;
+        let (left, right) = if before {
+            (&neighbor, token)
+        } else {
+            (token, &neighbor)
+        };
+        if !can_join(left, right) {
+            return None;
+        }
+
+        let err = ctx
+            .err(
+                rule,
+                format!("unexpected space {} `{}`", side, token.text()),
+            )
+            .primary(util::token_list_range(&whitespace), "");
+        ctx.add_err(err);
+        ctx.extend_fix().delete_multiple(whitespace);
+    }
+    None
+}
+
+fn step(token: &SyntaxToken, before: bool) -> Option<SyntaxToken> {
+    if before {
+        token.prev_token()
+    } else {
+        token.next_token()
+    }
+}
+
+/// Whether two tokens can be next to each other without whitespace between them
+/// and still be lexed as the same tokens.
+fn can_join(left: &SyntaxToken, right: &SyntaxToken) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || matches!(c, '_' | '$' | '\\');
+    let is_operator = |c: char| c.is_ascii_punctuation() && !"()[]{}\"'`;,".contains(c);
+    match (left.text().chars().last(), right.text().chars().next()) {
+        // a word after a regex would be lexed as its flags
+        (Some(_), Some(r)) if left.kind() == REGEX && is_word(r) => false,
+        // a regex can follow any operator except for `/` which would start a comment
+        (Some(l), Some(_)) if right.kind() == REGEX => l != '/',
+        (Some(l), Some(r)) => {
+            let both = |f: &dyn Fn(char) -> bool| f(l) && f(r);
+            !both(&is_word) && !both(&is_operator)
+        }
+        _ => false,
+    }
+}
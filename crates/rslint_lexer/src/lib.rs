@@ -70,11 +70,13 @@ const UNICODE_SPACES: [char; 16] = [
     '\u{2006}', '\u{2007}', '\u{2008}', '\u{2009}', '\u{200A}', '\u{202F}', '\u{205F}', '\u{3000}',
 ];
 
-fn is_id_start(c: char) -> bool {
+/// Check if a char has the `ID_Start` property, this does not include `$` and `_`
+pub fn is_id_start(c: char) -> bool {
     ID_Start(c)
 }
 
-fn is_id_continue(c: char) -> bool {
+/// Check if a char has the `ID_Continue` property, this does not include `$`, ZWNJ, and ZWJ
+pub fn is_id_continue(c: char) -> bool {
     ID_Continue(c)
 }
 
@@ -541,6 +543,15 @@ impl<'src> Lexer<'src> {
         }
     }
 
+    /// Whether the current byte is a numeric separator (`_`) between two digits, e.g. `1_000`.
+    #[inline]
+    fn is_separator(&self, is_digit: fn(&u8) -> bool) -> bool {
+        self.bytes.get(self.cur) == Some(&b'_')
+            && self.cur > 0
+            && is_digit(&self.bytes[self.cur - 1])
+            && self.bytes.get(self.cur + 1).map_or(false, is_digit)
+    }
+
     #[inline]
     fn maybe_bigint(&mut self) {
         if let Some(b'n') = self.bytes.get(self.cur) {
@@ -579,6 +590,10 @@ impl<'src> Lexer<'src> {
             Some(b'n') => {
                 self.cur += 2;
             }
+            // separators are not allowed after a leading zero
+            Some(b'_') => {
+                self.next();
+            }
             Some(b'.') => {
                 self.cur += 1;
                 self.read_float();
@@ -607,8 +622,8 @@ impl<'src> Lexer<'src> {
     #[inline]
     fn read_hexnumber(&mut self) {
         unwind_loop! {
-            if let Some(b) = self.next_bounded() {
-                if !(*b as char).is_ascii_hexdigit() {
+            if let Some(b) = self.next_bounded().copied() {
+                if !b.is_ascii_hexdigit() && !self.is_separator(u8::is_ascii_hexdigit) {
                     return;
                 }
             } else {
@@ -622,8 +637,9 @@ impl<'src> Lexer<'src> {
     #[inline]
     fn read_number(&mut self) {
         unwind_loop! {
-            match self.next_bounded() {
+            match self.next_bounded().copied() {
                 Some(b'0'..=b'9') => {},
+                Some(b'_') if self.is_separator(u8::is_ascii_digit) => {},
                 Some(b'.') => {
                     return self.read_float();
                 },
@@ -655,10 +671,11 @@ impl<'src> Lexer<'src> {
     #[inline]
     fn read_float(&mut self) {
         unwind_loop! {
-            match self.next_bounded() {
+            match self.next_bounded().copied() {
                 // LLVM has a hard time optimizing inclusive patterns, perhaps we should check if it makes llvm sad,
                 // and optimize this into a lookup table
                 Some(b'0'..=b'9') => {},
+                Some(b'_') if self.is_separator(u8::is_ascii_digit) => {},
                 Some(b'e') | Some(b'E') => {
                     // At least one digit is required
                     match self.bytes.get(self.cur + 1) {
@@ -686,10 +703,10 @@ impl<'src> Lexer<'src> {
         }
 
         unwind_loop! {
-            if let Some(b'0'..=b'9') = self.next() {
-
-            } else {
-                return;
+            match self.next().copied() {
+                Some(b'0'..=b'9') => {},
+                Some(b'_') if self.is_separator(u8::is_ascii_digit) => {},
+                _ => return,
             }
         }
     }
@@ -697,10 +714,10 @@ impl<'src> Lexer<'src> {
     #[inline]
     fn read_bindigits(&mut self) {
         unwind_loop! {
-            if let Some(b'0') | Some(b'1') = self.next() {
-
-            } else {
-                return
+            match self.next().copied() {
+                Some(b'0') | Some(b'1') => {},
+                Some(b'_') if self.is_separator(|b| *b == b'0' || *b == b'1') => {},
+                _ => return,
             }
         }
     }
@@ -708,25 +725,51 @@ impl<'src> Lexer<'src> {
     #[inline]
     fn read_octaldigits(&mut self) {
         unwind_loop! {
-            if let Some(b'0'..=b'7') = self.next() {
-
-            } else {
-                return
+            match self.next().copied() {
+                Some(b'0'..=b'7') => {},
+                Some(b'_') if self.is_separator(|b| (b'0'..=b'7').contains(b)) => {},
+                _ => return,
             }
         }
     }
 
+    /// The position of a separator which ended a number because it is not between two digits,
+    /// such as in `1__0`, `1_`, `0_1`, or `0x_1`.
+    fn invalid_separator(&self, start: usize, err_start: usize) -> Option<usize> {
+        let after_prefix = err_start == start + 1
+            && self.bytes[start] == b'0'
+            && matches!(
+                self.bytes[err_start],
+                b'x' | b'X' | b'b' | b'B' | b'o' | b'O'
+            );
+        if self.bytes.get(err_start) == Some(&b'_') {
+            Some(err_start)
+        } else if after_prefix && self.bytes.get(err_start + 1) == Some(&b'_') {
+            Some(err_start + 1)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn verify_number_end(&mut self, start: usize) -> LexerReturn {
         let err_start = self.cur;
         if self.cur < self.bytes.len() && self.cur_is_ident_start() {
+            let separator = self.invalid_separator(start, err_start);
             self.consume_ident();
-            let err = Diagnostic::error(
-                self.file_id,
-                "",
-                "numbers cannot be followed by identifiers directly after",
-            )
-            .primary(err_start..self.cur, "an identifier cannot appear here");
+            let err = if let Some(separator) = separator {
+                Diagnostic::error(self.file_id, "", "invalid numeric separator").primary(
+                    separator..separator + 1,
+                    "separators are only allowed between two digits",
+                )
+            } else {
+                Diagnostic::error(
+                    self.file_id,
+                    "",
+                    "numbers cannot be followed by identifiers directly after",
+                )
+                .primary(err_start..self.cur, "an identifier cannot appear here")
+            };
 
             (
                 Token::new(SyntaxKind::ERROR_TOKEN, self.cur - start),
@@ -3,6 +3,7 @@
 
 use crate::Lexer;
 use quickcheck_macros::quickcheck;
+use rslint_syntax::SyntaxKind;
 use std::sync::mpsc::channel;
 use std::thread;
 use std::time::Duration;
@@ -743,6 +744,70 @@ fn number_complex() {
     }
 }
 
+#[test]
+fn number_separators() {
+    assert_lex! {
+        "1_000_000 1.0_5e1_0",
+        NUMBER:9,
+        WHITESPACE:1,
+        NUMBER:9
+    }
+
+    assert_lex! {
+        "0xFF_FFn 0b1_0 0o7_7",
+        NUMBER:8,
+        WHITESPACE:1,
+        NUMBER:5,
+        WHITESPACE:1,
+        NUMBER:5
+    }
+}
+
+#[test]
+fn number_separators_err() {
+    assert_lex! {
+        "1__0",
+        ERROR_TOKEN:4
+    }
+
+    assert_lex! {
+        "1_ 0_1 0x_1",
+        ERROR_TOKEN:2,
+        WHITESPACE:1,
+        ERROR_TOKEN:3,
+        WHITESPACE:1,
+        ERROR_TOKEN:4
+    }
+}
+
+#[test]
+fn number_separators_err_position() {
+    for (src, separator) in &[
+        ("1__0", 1),
+        ("1_", 1),
+        ("0_1", 1),
+        ("0x_1", 2),
+        ("1.0_e5", 3),
+    ] {
+        let (token, err) = Lexer::from_str(src, 0).collect::<Vec<_>>().remove(0);
+        assert_eq!(token.kind, SyntaxKind::ERROR_TOKEN);
+        let err = err.expect("expected a diagnostic");
+        assert_eq!(err.title, "invalid numeric separator");
+        assert_eq!(
+            err.primary.unwrap().span.range,
+            *separator..separator + 1,
+            "wrong separator position for `{}`",
+            src
+        );
+    }
+
+    let (_, err) = Lexer::from_str("1_0a", 0).collect::<Vec<_>>().remove(0);
+    assert_eq!(
+        err.unwrap().title,
+        "numbers cannot be followed by identifiers directly after"
+    );
+}
+
 #[test]
 fn dot_number_disambiguation() {
     assert_lex! {
@@ -6,7 +6,8 @@ use crate::core::{
 };
 use rayon::prelude::*;
 use rslint_core::{
-    apply_top_level_directives, directives::DirectiveResult, run_rule, DirectiveParser,
+    apply_top_level_directives, directives::DirectiveResult, run_rule_with_semantics,
+    DirectiveParser, SemanticModel,
 };
 use rslint_errors::{lsp::convert_to_lsp_diagnostic, Diagnostic as RslintDiagnostic};
 use rslint_parser::SyntaxNode;
@@ -59,6 +60,10 @@ pub async fn publish_diagnostics(session: &Session, uri: Url) -> anyhow::Result<
 
     let verbose = false;
     let src = Arc::from(document.text.clone());
+    let semantic = Arc::new(SemanticModel::with_globals(
+        &SyntaxNode::new_root(document.parse.green()),
+        Arc::clone(&new_store.globals),
+    ));
     let rule_results: HashMap<&str, rslint_core::RuleResult> = new_store
         .rules
         .par_iter()
@@ -66,13 +71,14 @@ pub async fn publish_diagnostics(session: &Session, uri: Url) -> anyhow::Result<
             let root = SyntaxNode::new_root(document.parse.green());
             (
                 rule.name(),
-                run_rule(
+                run_rule_with_semantics(
                     &**rule,
                     file_id,
                     root,
                     verbose,
                     &directives,
                     Arc::clone(&src),
+                    Arc::clone(&semantic),
                 ),
             )
         })
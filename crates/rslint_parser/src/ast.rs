@@ -84,6 +84,36 @@ impl<N: AstNode> Iterator for AstChildren<N> {
     }
 }
 
+/// Get the nodes in a field of a node by the name of the field's accessor, such as `callee` for a `CALL_EXPR`
+/// or `lhs` for a `BIN_EXPR`. This allows getting fields of nodes whose kind is only known at runtime,
+/// such as in user defined queries. Fields which are many nodes, such as `args` of an `ARG_LIST`, return every node.
+///
+/// Returns `None` if the kind of node does not have a field with that name, fields which are tokens are not included.
+pub fn node_field(node: &SyntaxNode, field: &str) -> Option<Vec<SyntaxNode>> {
+    use SyntaxKind::*;
+
+    // accessors which are not generated
+    let nodes = match (node.kind(), field) {
+        (BRACKET_EXPR, "object") => support::nodes(node.to::<BracketExpr>().object()),
+        (BRACKET_EXPR, "prop") => support::nodes(node.to::<BracketExpr>().prop()),
+        (COND_EXPR, "test") => support::nodes(node.to::<CondExpr>().test()),
+        (COND_EXPR, "cons") => support::nodes(node.to::<CondExpr>().cons()),
+        (COND_EXPR, "alt") => support::nodes(node.to::<CondExpr>().alt()),
+        (LITERAL_PROP, "key") => support::nodes(node.to::<LiteralProp>().key()),
+        (LITERAL_PROP, "value") => support::nodes(node.to::<LiteralProp>().value()),
+        (BIN_EXPR, "lhs") => support::nodes(node.to::<BinExpr>().lhs()),
+        (BIN_EXPR, "rhs") => support::nodes(node.to::<BinExpr>().rhs()),
+        (KEY_VALUE_PATTERN, "value") => support::nodes(node.to::<KeyValuePattern>().value()),
+        (ASSIGN_EXPR, "lhs") => support::nodes(node.to::<AssignExpr>().lhs()),
+        (ASSIGN_EXPR, "rhs") => support::nodes(node.to::<AssignExpr>().rhs()),
+        (ARROW_EXPR, "body") => support::nodes(node.to::<ArrowExpr>().body()),
+        (IF_STMT, "cons") => support::nodes(node.to::<IfStmt>().cons()),
+        (IF_STMT, "alt") => support::nodes(node.to::<IfStmt>().alt()),
+        _ => return generated::nodes::generated_field(node, field),
+    };
+    Some(nodes)
+}
+
 mod support {
     use super::{AstChildren, AstNode, SyntaxKind, SyntaxNode, SyntaxToken};
 
@@ -95,6 +125,10 @@ mod support {
         AstChildren::new(parent)
     }
 
+    pub(super) fn nodes<N: AstNode>(items: impl IntoIterator<Item = N>) -> Vec<SyntaxNode> {
+        items.into_iter().map(|it| it.syntax().clone()).collect()
+    }
+
     pub(super) fn token(parent: &SyntaxNode, kind: SyntaxKind) -> Option<SyntaxToken> {
         parent
             .children_with_tokens()
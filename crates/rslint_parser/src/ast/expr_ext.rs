@@ -316,6 +316,7 @@ pub enum AssignOp {
     AddAssign,
     SubtractAssign,
     TimesAssign,
+    DivideAssign,
     RemainderAssign,
     ExponentAssign,
     LeftShiftAssign,
@@ -340,6 +341,7 @@ impl AssignExpr {
                     T![+=] => AssignOp::AddAssign,
                     T![-=] => AssignOp::SubtractAssign,
                     T![*=] => AssignOp::TimesAssign,
+                    T![/=] => AssignOp::DivideAssign,
                     T![%=] => AssignOp::RemainderAssign,
                     T![**=] => AssignOp::ExponentAssign,
                     T![>>=] => AssignOp::LeftShiftAssign,
@@ -811,6 +813,9 @@ macro_rules! op {
     (*=) => {
         $crate::ast::AssignOp::TimesAssign
     };
+    (/=) => {
+        $crate::ast::AssignOp::DivideAssign
+    };
     (%=) => {
         $crate::ast::AssignOp::RemainderAssign
     };
@@ -5447,3 +5447,357 @@ impl std::fmt::Display for PrivatePropAccess {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+#[doc = r" Get the nodes in a field of a node using the field's generated accessor, e.g. `callee` for a `CALL_EXPR`."]
+#[doc = r" Returns `None` if the kind of node has no generated accessor with that name, fields which are tokens"]
+#[doc = r" are not included."]
+pub(crate) fn generated_field(node: &SyntaxNode, field: &str) -> Option<Vec<SyntaxNode>> {
+    let nodes = match (node.kind(), field) {
+        (TS_LITERAL, "lit") => support::nodes(TsLiteral::cast(node.clone())?.lit()),
+        (TS_TEMPLATE, "elements") => support::nodes(TsTemplate::cast(node.clone())?.elements()),
+        (TS_TEMPLATE_ELEMENT, "ty") => support::nodes(TsTemplateElement::cast(node.clone())?.ty()),
+        (TS_PREDICATE, "lhs") => support::nodes(TsPredicate::cast(node.clone())?.lhs()),
+        (TS_PREDICATE, "rhs") => support::nodes(TsPredicate::cast(node.clone())?.rhs()),
+        (TS_TUPLE, "elements") => support::nodes(TsTuple::cast(node.clone())?.elements()),
+        (TS_TUPLE_ELEMENT, "ty") => support::nodes(TsTupleElement::cast(node.clone())?.ty()),
+        (TS_PAREN, "ty") => support::nodes(TsParen::cast(node.clone())?.ty()),
+        (TS_TYPE_REF, "name") => support::nodes(TsTypeRef::cast(node.clone())?.name()),
+        (TS_TYPE_REF, "type_args") => support::nodes(TsTypeRef::cast(node.clone())?.type_args()),
+        (TS_QUALIFIED_PATH, "lhs") => support::nodes(TsQualifiedPath::cast(node.clone())?.lhs()),
+        (TS_QUALIFIED_PATH, "rhs") => support::nodes(TsQualifiedPath::cast(node.clone())?.rhs()),
+        (TS_MAPPED_TYPE, "readonly_modifier") => {
+            support::nodes(TsMappedType::cast(node.clone())?.readonly_modifier())
+        }
+        (TS_MAPPED_TYPE, "param") => support::nodes(TsMappedType::cast(node.clone())?.param()),
+        (TS_MAPPED_TYPE, "ty") => support::nodes(TsMappedType::cast(node.clone())?.ty()),
+        (TS_MAPPED_TYPE_PARAM, "name") => {
+            support::nodes(TsMappedTypeParam::cast(node.clone())?.name())
+        }
+        (TS_MAPPED_TYPE_PARAM, "ty") => support::nodes(TsMappedTypeParam::cast(node.clone())?.ty()),
+        (TS_TYPE_QUERY, "expr") => support::nodes(TsTypeQuery::cast(node.clone())?.expr()),
+        (TS_IMPORT, "qualifier") => support::nodes(TsImport::cast(node.clone())?.qualifier()),
+        (TS_IMPORT, "type_args") => support::nodes(TsImport::cast(node.clone())?.type_args()),
+        (TS_TYPE_ARGS, "args") => support::nodes(TsTypeArgs::cast(node.clone())?.args()),
+        (TS_ARRAY, "ty") => support::nodes(TsArray::cast(node.clone())?.ty()),
+        (TS_INDEXED_ARRAY, "ty") => support::nodes(TsIndexedArray::cast(node.clone())?.ty()),
+        (TS_TYPE_OPERATOR, "ty") => support::nodes(TsTypeOperator::cast(node.clone())?.ty()),
+        (TS_INTERSECTION, "types") => support::nodes(TsIntersection::cast(node.clone())?.types()),
+        (TS_UNION, "types") => support::nodes(TsUnion::cast(node.clone())?.types()),
+        (TS_TYPE_PARAMS, "params") => support::nodes(TsTypeParams::cast(node.clone())?.params()),
+        (TS_FN_TYPE, "params") => support::nodes(TsFnType::cast(node.clone())?.params()),
+        (TS_FN_TYPE, "return_type") => support::nodes(TsFnType::cast(node.clone())?.return_type()),
+        (TS_CONSTRUCTOR_TYPE, "params") => {
+            support::nodes(TsConstructorType::cast(node.clone())?.params())
+        }
+        (TS_CONSTRUCTOR_TYPE, "return_type") => {
+            support::nodes(TsConstructorType::cast(node.clone())?.return_type())
+        }
+        (TS_EXTENDS, "ty") => support::nodes(TsExtends::cast(node.clone())?.ty()),
+        (TS_CONDITIONAL_TYPE, "ty") => support::nodes(TsConditionalType::cast(node.clone())?.ty()),
+        (TS_CONDITIONAL_TYPE, "extends") => {
+            support::nodes(TsConditionalType::cast(node.clone())?.extends())
+        }
+        (TS_CONSTRAINT, "ty") => support::nodes(TsConstraint::cast(node.clone())?.ty()),
+        (TS_TYPE_PARAM, "constraint") => {
+            support::nodes(TsTypeParam::cast(node.clone())?.constraint())
+        }
+        (TS_TYPE_PARAM, "default") => support::nodes(TsTypeParam::cast(node.clone())?.default()),
+        (TS_DEFAULT, "ty") => support::nodes(TsDefault::cast(node.clone())?.ty()),
+        (TS_NON_NULL, "target") => support::nodes(TsNonNull::cast(node.clone())?.target()),
+        (TS_ASSERTION, "expr") => support::nodes(TsAssertion::cast(node.clone())?.expr()),
+        (TS_ASSERTION, "ty") => support::nodes(TsAssertion::cast(node.clone())?.ty()),
+        (TS_TYPE_ALIAS_DECL, "type_params") => {
+            support::nodes(TsTypeAliasDecl::cast(node.clone())?.type_params())
+        }
+        (TS_TYPE_ALIAS_DECL, "ty") => support::nodes(TsTypeAliasDecl::cast(node.clone())?.ty()),
+        (TS_CONST_ASSERTION, "expr") => {
+            support::nodes(TsConstAssertion::cast(node.clone())?.expr())
+        }
+        (TS_ENUM, "members") => support::nodes(TsEnum::cast(node.clone())?.members()),
+        (TS_ENUM_MEMBER, "value") => support::nodes(TsEnumMember::cast(node.clone())?.value()),
+        (TS_NAMESPACE_DECL, "body") => support::nodes(TsNamespaceDecl::cast(node.clone())?.body()),
+        (TS_MODULE_BLOCK, "items") => support::nodes(TsModuleBlock::cast(node.clone())?.items()),
+        (TS_MODULE_DECL, "body") => support::nodes(TsModuleDecl::cast(node.clone())?.body()),
+        (TS_CONSTRUCTOR_PARAM, "pat") => {
+            support::nodes(TsConstructorParam::cast(node.clone())?.pat())
+        }
+        (TS_CALL_SIGNATURE_DECL, "type_params") => {
+            support::nodes(TsCallSignatureDecl::cast(node.clone())?.type_params())
+        }
+        (TS_CALL_SIGNATURE_DECL, "parameters") => {
+            support::nodes(TsCallSignatureDecl::cast(node.clone())?.parameters())
+        }
+        (TS_CALL_SIGNATURE_DECL, "return_type") => {
+            support::nodes(TsCallSignatureDecl::cast(node.clone())?.return_type())
+        }
+        (TS_CONSTRUCT_SIGNATURE_DECL, "type_params") => {
+            support::nodes(TsConstructSignatureDecl::cast(node.clone())?.type_params())
+        }
+        (TS_CONSTRUCT_SIGNATURE_DECL, "parameters") => {
+            support::nodes(TsConstructSignatureDecl::cast(node.clone())?.parameters())
+        }
+        (TS_CONSTRUCT_SIGNATURE_DECL, "return_type") => {
+            support::nodes(TsConstructSignatureDecl::cast(node.clone())?.return_type())
+        }
+        (TS_INDEX_SIGNATURE, "decorators") => {
+            support::nodes(TsIndexSignature::cast(node.clone())?.decorators())
+        }
+        (TS_INDEX_SIGNATURE, "pat") => support::nodes(TsIndexSignature::cast(node.clone())?.pat()),
+        (TS_INDEX_SIGNATURE, "ty") => support::nodes(TsIndexSignature::cast(node.clone())?.ty()),
+        (TS_METHOD_SIGNATURE, "key") => {
+            support::nodes(TsMethodSignature::cast(node.clone())?.key())
+        }
+        (TS_METHOD_SIGNATURE, "type_params") => {
+            support::nodes(TsMethodSignature::cast(node.clone())?.type_params())
+        }
+        (TS_METHOD_SIGNATURE, "parameters") => {
+            support::nodes(TsMethodSignature::cast(node.clone())?.parameters())
+        }
+        (TS_METHOD_SIGNATURE, "return_type") => {
+            support::nodes(TsMethodSignature::cast(node.clone())?.return_type())
+        }
+        (TS_PROPERTY_SIGNATURE, "prop") => {
+            support::nodes(TsPropertySignature::cast(node.clone())?.prop())
+        }
+        (TS_PROPERTY_SIGNATURE, "ty") => {
+            support::nodes(TsPropertySignature::cast(node.clone())?.ty())
+        }
+        (TS_EXPR_WITH_TYPE_ARGS, "item") => {
+            support::nodes(TsExprWithTypeArgs::cast(node.clone())?.item())
+        }
+        (TS_EXPR_WITH_TYPE_ARGS, "type_params") => {
+            support::nodes(TsExprWithTypeArgs::cast(node.clone())?.type_params())
+        }
+        (TS_INTERFACE_DECL, "type_params") => {
+            support::nodes(TsInterfaceDecl::cast(node.clone())?.type_params())
+        }
+        (TS_INTERFACE_DECL, "extends") => {
+            support::nodes(TsInterfaceDecl::cast(node.clone())?.extends())
+        }
+        (TS_INTERFACE_DECL, "members") => {
+            support::nodes(TsInterfaceDecl::cast(node.clone())?.members())
+        }
+        (TS_OBJECT_TYPE, "members") => support::nodes(TsObjectType::cast(node.clone())?.members()),
+        (TS_IMPORT_EQUALS_DECL, "module") => {
+            support::nodes(TsImportEqualsDecl::cast(node.clone())?.module())
+        }
+        (TS_EXPORT_ASSIGNMENT, "expr") => {
+            support::nodes(TsExportAssignment::cast(node.clone())?.expr())
+        }
+        (TS_DECORATOR, "expr") => support::nodes(TsDecorator::cast(node.clone())?.expr()),
+        (SCRIPT, "items") => support::nodes(Script::cast(node.clone())?.items()),
+        (MODULE, "items") => support::nodes(Module::cast(node.clone())?.items()),
+        (IMPORT_DECL, "imports") => support::nodes(ImportDecl::cast(node.clone())?.imports()),
+        (IMPORT_DECL, "asserted_object") => {
+            support::nodes(ImportDecl::cast(node.clone())?.asserted_object())
+        }
+        (NAMED_IMPORTS, "specifiers") => {
+            support::nodes(NamedImports::cast(node.clone())?.specifiers())
+        }
+        (EXPORT_DECL, "decorators") => support::nodes(ExportDecl::cast(node.clone())?.decorators()),
+        (EXPORT_DECL, "decl") => support::nodes(ExportDecl::cast(node.clone())?.decl()),
+        (EXPORT_NAMED, "specifiers") => {
+            support::nodes(ExportNamed::cast(node.clone())?.specifiers())
+        }
+        (EXPORT_DEFAULT_DECL, "decorators") => {
+            support::nodes(ExportDefaultDecl::cast(node.clone())?.decorators())
+        }
+        (EXPORT_DEFAULT_DECL, "decl") => {
+            support::nodes(ExportDefaultDecl::cast(node.clone())?.decl())
+        }
+        (EXPORT_DEFAULT_EXPR, "expr") => {
+            support::nodes(ExportDefaultExpr::cast(node.clone())?.expr())
+        }
+        (BLOCK_STMT, "stmts") => support::nodes(BlockStmt::cast(node.clone())?.stmts()),
+        (VAR_DECL, "declared") => support::nodes(VarDecl::cast(node.clone())?.declared()),
+        (DECLARATOR, "pattern") => support::nodes(Declarator::cast(node.clone())?.pattern()),
+        (DECLARATOR, "value") => support::nodes(Declarator::cast(node.clone())?.value()),
+        (EXPR_STMT, "expr") => support::nodes(ExprStmt::cast(node.clone())?.expr()),
+        (IF_STMT, "condition") => support::nodes(IfStmt::cast(node.clone())?.condition()),
+        (CONDITION, "condition") => support::nodes(Condition::cast(node.clone())?.condition()),
+        (DO_WHILE_STMT, "cons") => support::nodes(DoWhileStmt::cast(node.clone())?.cons()),
+        (DO_WHILE_STMT, "condition") => {
+            support::nodes(DoWhileStmt::cast(node.clone())?.condition())
+        }
+        (WHILE_STMT, "condition") => support::nodes(WhileStmt::cast(node.clone())?.condition()),
+        (WHILE_STMT, "cons") => support::nodes(WhileStmt::cast(node.clone())?.cons()),
+        (FOR_STMT, "init") => support::nodes(ForStmt::cast(node.clone())?.init()),
+        (FOR_STMT, "test") => support::nodes(ForStmt::cast(node.clone())?.test()),
+        (FOR_STMT, "update") => support::nodes(ForStmt::cast(node.clone())?.update()),
+        (FOR_STMT, "cons") => support::nodes(ForStmt::cast(node.clone())?.cons()),
+        (FOR_STMT_INIT, "inner") => support::nodes(ForStmtInit::cast(node.clone())?.inner()),
+        (FOR_STMT_TEST, "expr") => support::nodes(ForStmtTest::cast(node.clone())?.expr()),
+        (FOR_STMT_UPDATE, "expr") => support::nodes(ForStmtUpdate::cast(node.clone())?.expr()),
+        (FOR_IN_STMT, "left") => support::nodes(ForInStmt::cast(node.clone())?.left()),
+        (FOR_IN_STMT, "right") => support::nodes(ForInStmt::cast(node.clone())?.right()),
+        (FOR_IN_STMT, "cons") => support::nodes(ForInStmt::cast(node.clone())?.cons()),
+        (FOR_OF_STMT, "left") => support::nodes(ForOfStmt::cast(node.clone())?.left()),
+        (FOR_OF_STMT, "right") => support::nodes(ForOfStmt::cast(node.clone())?.right()),
+        (FOR_OF_STMT, "cons") => support::nodes(ForOfStmt::cast(node.clone())?.cons()),
+        (RETURN_STMT, "value") => support::nodes(ReturnStmt::cast(node.clone())?.value()),
+        (WITH_STMT, "condition") => support::nodes(WithStmt::cast(node.clone())?.condition()),
+        (WITH_STMT, "cons") => support::nodes(WithStmt::cast(node.clone())?.cons()),
+        (SWITCH_STMT, "test") => support::nodes(SwitchStmt::cast(node.clone())?.test()),
+        (SWITCH_STMT, "cases") => support::nodes(SwitchStmt::cast(node.clone())?.cases()),
+        (CASE_CLAUSE, "test") => support::nodes(CaseClause::cast(node.clone())?.test()),
+        (CASE_CLAUSE, "cons") => support::nodes(CaseClause::cast(node.clone())?.cons()),
+        (DEFAULT_CLAUSE, "cons") => support::nodes(DefaultClause::cast(node.clone())?.cons()),
+        (LABELLED_STMT, "label") => support::nodes(LabelledStmt::cast(node.clone())?.label()),
+        (LABELLED_STMT, "stmt") => support::nodes(LabelledStmt::cast(node.clone())?.stmt()),
+        (THROW_STMT, "exception") => support::nodes(ThrowStmt::cast(node.clone())?.exception()),
+        (TRY_STMT, "test") => support::nodes(TryStmt::cast(node.clone())?.test()),
+        (TRY_STMT, "handler") => support::nodes(TryStmt::cast(node.clone())?.handler()),
+        (TRY_STMT, "finalizer") => support::nodes(TryStmt::cast(node.clone())?.finalizer()),
+        (CATCH_CLAUSE, "error") => support::nodes(CatchClause::cast(node.clone())?.error()),
+        (CATCH_CLAUSE, "cons") => support::nodes(CatchClause::cast(node.clone())?.cons()),
+        (FINALIZER, "cons") => support::nodes(Finalizer::cast(node.clone())?.cons()),
+        (FN_DECL, "decorators") => support::nodes(FnDecl::cast(node.clone())?.decorators()),
+        (FN_DECL, "name") => support::nodes(FnDecl::cast(node.clone())?.name()),
+        (FN_DECL, "type_parameters") => {
+            support::nodes(FnDecl::cast(node.clone())?.type_parameters())
+        }
+        (FN_DECL, "parameters") => support::nodes(FnDecl::cast(node.clone())?.parameters()),
+        (FN_DECL, "return_type") => support::nodes(FnDecl::cast(node.clone())?.return_type()),
+        (FN_DECL, "body") => support::nodes(FnDecl::cast(node.clone())?.body()),
+        (PARAMETER_LIST, "parameters") => {
+            support::nodes(ParameterList::cast(node.clone())?.parameters())
+        }
+        (ARRAY_EXPR, "elements") => support::nodes(ArrayExpr::cast(node.clone())?.elements()),
+        (OBJECT_EXPR, "props") => support::nodes(ObjectExpr::cast(node.clone())?.props()),
+        (SPREAD_PROP, "value") => support::nodes(SpreadProp::cast(node.clone())?.value()),
+        (INITIALIZED_PROP, "key") => support::nodes(InitializedProp::cast(node.clone())?.key()),
+        (INITIALIZED_PROP, "value") => support::nodes(InitializedProp::cast(node.clone())?.value()),
+        (IDENT_PROP, "name") => support::nodes(IdentProp::cast(node.clone())?.name()),
+        (GETTER, "decorators") => support::nodes(Getter::cast(node.clone())?.decorators()),
+        (GETTER, "key") => support::nodes(Getter::cast(node.clone())?.key()),
+        (GETTER, "parameters") => support::nodes(Getter::cast(node.clone())?.parameters()),
+        (GETTER, "body") => support::nodes(Getter::cast(node.clone())?.body()),
+        (SETTER, "decorators") => support::nodes(Setter::cast(node.clone())?.decorators()),
+        (SETTER, "key") => support::nodes(Setter::cast(node.clone())?.key()),
+        (SETTER, "parameters") => support::nodes(Setter::cast(node.clone())?.parameters()),
+        (SETTER, "body") => support::nodes(Setter::cast(node.clone())?.body()),
+        (GROUPING_EXPR, "inner") => support::nodes(GroupingExpr::cast(node.clone())?.inner()),
+        (DOT_EXPR, "object") => support::nodes(DotExpr::cast(node.clone())?.object()),
+        (DOT_EXPR, "prop") => support::nodes(DotExpr::cast(node.clone())?.prop()),
+        (NEW_EXPR, "type_args") => support::nodes(NewExpr::cast(node.clone())?.type_args()),
+        (NEW_EXPR, "object") => support::nodes(NewExpr::cast(node.clone())?.object()),
+        (NEW_EXPR, "arguments") => support::nodes(NewExpr::cast(node.clone())?.arguments()),
+        (ARG_LIST, "args") => support::nodes(ArgList::cast(node.clone())?.args()),
+        (CALL_EXPR, "callee") => support::nodes(CallExpr::cast(node.clone())?.callee()),
+        (CALL_EXPR, "type_args") => support::nodes(CallExpr::cast(node.clone())?.type_args()),
+        (CALL_EXPR, "arguments") => support::nodes(CallExpr::cast(node.clone())?.arguments()),
+        (SUPER_CALL, "arguments") => support::nodes(SuperCall::cast(node.clone())?.arguments()),
+        (IMPORT_CALL, "argument") => support::nodes(ImportCall::cast(node.clone())?.argument()),
+        (UNARY_EXPR, "expr") => support::nodes(UnaryExpr::cast(node.clone())?.expr()),
+        (SEQUENCE_EXPR, "exprs") => support::nodes(SequenceExpr::cast(node.clone())?.exprs()),
+        (TEMPLATE, "tag") => support::nodes(Template::cast(node.clone())?.tag()),
+        (TEMPLATE, "elements") => support::nodes(Template::cast(node.clone())?.elements()),
+        (TEMPLATE_ELEMENT, "expr") => support::nodes(TemplateElement::cast(node.clone())?.expr()),
+        (SPREAD_ELEMENT, "element") => support::nodes(SpreadElement::cast(node.clone())?.element()),
+        (ARRAY_PATTERN, "decorators") => {
+            support::nodes(ArrayPattern::cast(node.clone())?.decorators())
+        }
+        (ARRAY_PATTERN, "elements") => support::nodes(ArrayPattern::cast(node.clone())?.elements()),
+        (ARRAY_PATTERN, "ty") => support::nodes(ArrayPattern::cast(node.clone())?.ty()),
+        (OBJECT_PATTERN, "decorators") => {
+            support::nodes(ObjectPattern::cast(node.clone())?.decorators())
+        }
+        (OBJECT_PATTERN, "elements") => {
+            support::nodes(ObjectPattern::cast(node.clone())?.elements())
+        }
+        (OBJECT_PATTERN, "ty") => support::nodes(ObjectPattern::cast(node.clone())?.ty()),
+        (REST_PATTERN, "decorators") => {
+            support::nodes(RestPattern::cast(node.clone())?.decorators())
+        }
+        (REST_PATTERN, "pat") => support::nodes(RestPattern::cast(node.clone())?.pat()),
+        (REST_PATTERN, "ty") => support::nodes(RestPattern::cast(node.clone())?.ty()),
+        (ASSIGN_PATTERN, "decorators") => {
+            support::nodes(AssignPattern::cast(node.clone())?.decorators())
+        }
+        (ASSIGN_PATTERN, "key") => support::nodes(AssignPattern::cast(node.clone())?.key()),
+        (ASSIGN_PATTERN, "ty") => support::nodes(AssignPattern::cast(node.clone())?.ty()),
+        (ASSIGN_PATTERN, "value") => support::nodes(AssignPattern::cast(node.clone())?.value()),
+        (KEY_VALUE_PATTERN, "key") => support::nodes(KeyValuePattern::cast(node.clone())?.key()),
+        (COMPUTED_PROPERTY_NAME, "prop") => {
+            support::nodes(ComputedPropertyName::cast(node.clone())?.prop())
+        }
+        (SINGLE_PATTERN, "decorators") => {
+            support::nodes(SinglePattern::cast(node.clone())?.decorators())
+        }
+        (SINGLE_PATTERN, "name") => support::nodes(SinglePattern::cast(node.clone())?.name()),
+        (SINGLE_PATTERN, "ty") => support::nodes(SinglePattern::cast(node.clone())?.ty()),
+        (ARROW_EXPR, "type_params") => support::nodes(ArrowExpr::cast(node.clone())?.type_params()),
+        (ARROW_EXPR, "params") => support::nodes(ArrowExpr::cast(node.clone())?.params()),
+        (ARROW_EXPR, "return_type") => support::nodes(ArrowExpr::cast(node.clone())?.return_type()),
+        (YIELD_EXPR, "value") => support::nodes(YieldExpr::cast(node.clone())?.value()),
+        (FN_EXPR, "name") => support::nodes(FnExpr::cast(node.clone())?.name()),
+        (FN_EXPR, "type_params") => support::nodes(FnExpr::cast(node.clone())?.type_params()),
+        (FN_EXPR, "parameters") => support::nodes(FnExpr::cast(node.clone())?.parameters()),
+        (FN_EXPR, "return_type") => support::nodes(FnExpr::cast(node.clone())?.return_type()),
+        (FN_EXPR, "body") => support::nodes(FnExpr::cast(node.clone())?.body()),
+        (METHOD, "decorators") => support::nodes(Method::cast(node.clone())?.decorators()),
+        (METHOD, "name") => support::nodes(Method::cast(node.clone())?.name()),
+        (METHOD, "type_params") => support::nodes(Method::cast(node.clone())?.type_params()),
+        (METHOD, "parameters") => support::nodes(Method::cast(node.clone())?.parameters()),
+        (METHOD, "return_type") => support::nodes(Method::cast(node.clone())?.return_type()),
+        (METHOD, "body") => support::nodes(Method::cast(node.clone())?.body()),
+        (PRIVATE_PROP, "decorators") => {
+            support::nodes(PrivateProp::cast(node.clone())?.decorators())
+        }
+        (PRIVATE_PROP, "accessibility") => {
+            support::nodes(PrivateProp::cast(node.clone())?.accessibility())
+        }
+        (PRIVATE_PROP, "key") => support::nodes(PrivateProp::cast(node.clone())?.key()),
+        (PRIVATE_PROP, "ty") => support::nodes(PrivateProp::cast(node.clone())?.ty()),
+        (CLASS_PROP, "decorators") => support::nodes(ClassProp::cast(node.clone())?.decorators()),
+        (CLASS_PROP, "accessibility") => {
+            support::nodes(ClassProp::cast(node.clone())?.accessibility())
+        }
+        (CLASS_PROP, "key") => support::nodes(ClassProp::cast(node.clone())?.key()),
+        (CLASS_PROP, "ty") => support::nodes(ClassProp::cast(node.clone())?.ty()),
+        (CONSTRUCTOR, "decorators") => {
+            support::nodes(Constructor::cast(node.clone())?.decorators())
+        }
+        (CONSTRUCTOR, "accessibility") => {
+            support::nodes(Constructor::cast(node.clone())?.accessibility())
+        }
+        (CONSTRUCTOR, "name") => support::nodes(Constructor::cast(node.clone())?.name()),
+        (CONSTRUCTOR, "type_params") => {
+            support::nodes(Constructor::cast(node.clone())?.type_params())
+        }
+        (CONSTRUCTOR, "parameters") => {
+            support::nodes(Constructor::cast(node.clone())?.parameters())
+        }
+        (CONSTRUCTOR, "body") => support::nodes(Constructor::cast(node.clone())?.body()),
+        (CONSTRUCTOR_PARAMETERS, "parameters") => {
+            support::nodes(ConstructorParameters::cast(node.clone())?.parameters())
+        }
+        (CLASS_DECL, "decorators") => support::nodes(ClassDecl::cast(node.clone())?.decorators()),
+        (CLASS_DECL, "name") => support::nodes(ClassDecl::cast(node.clone())?.name()),
+        (CLASS_DECL, "type_params") => support::nodes(ClassDecl::cast(node.clone())?.type_params()),
+        (CLASS_DECL, "parent") => support::nodes(ClassDecl::cast(node.clone())?.parent()),
+        (CLASS_DECL, "parent_type_args") => {
+            support::nodes(ClassDecl::cast(node.clone())?.parent_type_args())
+        }
+        (CLASS_DECL, "implements") => support::nodes(ClassDecl::cast(node.clone())?.implements()),
+        (CLASS_DECL, "body") => support::nodes(ClassDecl::cast(node.clone())?.body()),
+        (CLASS_EXPR, "name") => support::nodes(ClassExpr::cast(node.clone())?.name()),
+        (CLASS_EXPR, "type_params") => support::nodes(ClassExpr::cast(node.clone())?.type_params()),
+        (CLASS_EXPR, "parent") => support::nodes(ClassExpr::cast(node.clone())?.parent()),
+        (CLASS_EXPR, "parent_type_args") => {
+            support::nodes(ClassExpr::cast(node.clone())?.parent_type_args())
+        }
+        (CLASS_EXPR, "implements") => support::nodes(ClassExpr::cast(node.clone())?.implements()),
+        (CLASS_EXPR, "body") => support::nodes(ClassExpr::cast(node.clone())?.body()),
+        (CLASS_BODY, "elements") => support::nodes(ClassBody::cast(node.clone())?.elements()),
+        (AWAIT_EXPR, "expr") => support::nodes(AwaitExpr::cast(node.clone())?.expr()),
+        (PRIVATE_NAME, "name") => support::nodes(PrivateName::cast(node.clone())?.name()),
+        (PRIVATE_PROP_ACCESS, "lhs") => {
+            support::nodes(PrivatePropAccess::cast(node.clone())?.lhs())
+        }
+        (PRIVATE_PROP_ACCESS, "rhs") => {
+            support::nodes(PrivatePropAccess::cast(node.clone())?.rhs())
+        }
+        _ => return None,
+    };
+    Some(nodes)
+}
@@ -73,6 +73,7 @@ mod tests;
 
 #[macro_use]
 pub mod ast;
+pub mod regex;
 pub mod syntax;
 pub mod util;
 
@@ -81,7 +82,7 @@ pub use crate::{
     event::{process, Event},
     lossless_tree_sink::LosslessTreeSink,
     lossy_tree_sink::LossyTreeSink,
-    numbers::{parse_js_num, BigInt, JsNum},
+    numbers::{format_js_num, parse_js_num, BigInt, JsNum},
     parse::*,
     parser::{Checkpoint, CompletedMarker, Marker, Parser},
     state::{ParserState, StrictMode},
@@ -11,7 +11,9 @@ pub enum JsNum {
 }
 
 /// Parse a js number as a string into a number.  
-pub fn parse_js_num(num: String) -> Option<JsNum> {
+pub fn parse_js_num(mut num: String) -> Option<JsNum> {
+    // numeric separators such as `1_000` do not change the value
+    num.retain(|c| c != '_');
     let (radix, mut raw) = match num.get(0..2) {
         Some("0x") | Some("0X") => (16, num.get(2..).unwrap()),
         Some("0b") | Some("0B") => (2, num.get(2..).unwrap()),
@@ -19,8 +21,8 @@ pub fn parse_js_num(num: String) -> Option<JsNum> {
         _ => (10, num.as_str()),
     };
 
-    if radix == 10 && raw.starts_with('0') {
-        // account for legacy octal literals
+    // account for legacy octal literals such as `0777`, but not decimals like `0.5` or `089`
+    if radix == 10 && raw.starts_with('0') && raw.bytes().all(|b| (b'0'..=b'7').contains(&b)) {
         if let Ok(parsed) = parse_radix(raw.as_bytes(), 8) {
             return Some(JsNum::Float(parsed));
         }
@@ -42,8 +44,52 @@ pub fn parse_js_num(num: String) -> Option<JsNum> {
     }
 }
 
+/// Format a number the same way as JavaScript's `Number.prototype.toString`, e.g. `1e21` is formatted
+/// as `1e+21` and `9007199254740993` as `9007199254740992`.
+pub fn format_js_num(num: f64) -> String {
+    if num.is_nan() {
+        return "NaN".to_string();
+    }
+    if num == 0.0 {
+        return "0".to_string();
+    }
+    if num.is_infinite() {
+        return if num > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+    }
+    if num < 0.0 {
+        return format!("-{}", format_js_num(-num));
+    }
+
+    // the shortest digits which round trip to the same number, e.g. `1.2345e3`
+    let sci = format!("{:e}", num);
+    let (mantissa, exponent) = sci.split_at(sci.find('e').unwrap());
+    let digits = mantissa.replace('.', "");
+    let len = digits.len() as i32;
+    // the position of the decimal point relative to the first digit
+    let point = exponent[1..].parse::<i32>().unwrap() + 1;
+
+    if len <= point && point <= 21 {
+        digits + &"0".repeat((point - len) as usize)
+    } else if 0 < point && point <= 21 {
+        let (int, frac) = digits.split_at(point as usize);
+        format!("{}.{}", int, frac)
+    } else if -6 < point && point <= 0 {
+        format!("0.{}{}", "0".repeat(-point as usize), digits)
+    } else {
+        let sign = if point > 0 { '+' } else { '-' };
+        let (first, rest) = digits.split_at(1);
+        let rest = if rest.is_empty() {
+            String::new()
+        } else {
+            format!(".{}", rest)
+        };
+        format!("{}{}e{}{}", first, rest, sign, (point - 1).abs())
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::format_js_num;
     use crate::{
         ast::{Expr, LiteralKind},
         parse_expr,
@@ -79,6 +125,8 @@ mod tests {
         assert_float!("0", 0.0);
         assert_float!("9e999", f64::INFINITY);
         assert_float!("9e-999", 0.0);
+        assert_float!("0.5", 0.5);
+        assert_float!("0.000000000000000000000123", 1.23e-22);
     }
 
     #[test]
@@ -112,6 +160,30 @@ mod tests {
         assert_float!("058", 58.0);
     }
 
+    #[test]
+    fn separators() {
+        assert_float!("1_000_000", 1000000.0);
+        assert_float!("1_0.0_1e1_0", 100100000000.0);
+        assert_float!("0xF_F", 255.0);
+        assert_float!("0b1_1", 3.0);
+        assert_bigint!("1_000n", 1000);
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(format_js_num(0.0), "0");
+        assert_eq!(format_js_num(-1.5), "-1.5");
+        assert_eq!(format_js_num(123.456), "123.456");
+        assert_eq!(format_js_num(0.000001), "0.000001");
+        assert_eq!(format_js_num(1e-7), "1e-7");
+        assert_eq!(format_js_num(1.5e300), "1.5e+300");
+        assert_eq!(format_js_num(1e21), "1e+21");
+        assert_eq!(format_js_num(1e20), "100000000000000000000");
+        assert_eq!(format_js_num(9007199254740993.0), "9007199254740992");
+        assert_eq!(format_js_num(f64::INFINITY), "Infinity");
+        assert_eq!(format_js_num(f64::NAN), "NaN");
+    }
+
     #[test]
     fn base_10_bigint() {
         assert_bigint!("1010n", 1010);
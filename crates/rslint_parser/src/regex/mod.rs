@@ -0,0 +1,309 @@
+//! Parsing and validation of regular expression patterns.
+//!
+//! The lexer only finds the end of a regex literal and checks its flags, this module parses the pattern
+//! of a literal into a small AST and reports the early errors of the pattern, such as duplicate group names,
+//! character class ranges which are out of order, or invalid unicode property escapes.
+//! Both the grammar used with the `u` flag and the legacy grammar of Annex B, which is used without it, are supported.
+//!
+//! Every range in the AST is a byte range relative to the start of the literal, so the opening `/` is at `0`.
+//! The ranges of the errors are offset to be relative to the file instead.
+
+mod parser;
+#[cfg(test)]
+mod tests;
+mod unicode;
+
+use crate::ParserError;
+use std::ops::Range;
+
+/// Parse a regex literal such as `/a+b/g`.
+///
+/// `offset` is the offset of the literal in its file, it is only used for the ranges of the returned errors.
+/// `None` is returned if the literal is malformed, e.g. if it is unterminated, the lexer reports those errors.
+pub fn parse_regex(
+    literal: &str,
+    offset: usize,
+    file_id: usize,
+) -> Option<(Regex, Vec<ParserError>)> {
+    let end = literal.rfind('/').filter(|end| *end > 0)?;
+    if !literal.starts_with('/') || !literal[end + 1..].chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let flags = Flags::parse(&literal[end + 1..]);
+    Some(parser::RegexParser::new(literal, end, flags, offset, file_id).parse())
+}
+
+/// The flags of a regex literal, unknown and duplicate flags are reported by the lexer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Flags {
+    /// `g`
+    pub global: bool,
+    /// `i`
+    pub ignore_case: bool,
+    /// `m`
+    pub multiline: bool,
+    /// `s`
+    pub dot_all: bool,
+    /// `u`
+    pub unicode: bool,
+    /// `y`
+    pub sticky: bool,
+}
+
+impl Flags {
+    pub fn parse(flags: &str) -> Self {
+        let mut ret = Self::default();
+        for flag in flags.chars() {
+            match flag {
+                'g' => ret.global = true,
+                'i' => ret.ignore_case = true,
+                'm' => ret.multiline = true,
+                's' => ret.dot_all = true,
+                'u' => ret.unicode = true,
+                'y' => ret.sticky = true,
+                _ => {}
+            }
+        }
+        ret
+    }
+}
+
+/// A parsed regex literal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regex {
+    pub pattern: Disjunction,
+    pub flags: Flags,
+    pub flags_range: Range<usize>,
+}
+
+/// A list of alternatives separated by `|`, such as `a|b`, this is the pattern itself and the body of groups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disjunction {
+    pub alternatives: Vec<Alternative>,
+    pub range: Range<usize>,
+}
+
+impl Disjunction {
+    /// Call a function for every node inside of this disjunction, including nested nodes, in source order.
+    pub fn walk(&self, func: &mut impl FnMut(&Node)) {
+        for alternative in &self.alternatives {
+            for node in &alternative.nodes {
+                node.walk(func);
+            }
+        }
+    }
+}
+
+/// A sequence of nodes which all have to match one after another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alternative {
+    pub nodes: Vec<Node>,
+    pub range: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Char(Char),
+    /// `.`
+    Dot(Range<usize>),
+    Assertion(Assertion),
+    Lookaround(Lookaround),
+    ClassEscape(ClassEscape),
+    CharacterClass(CharacterClass),
+    Group(Group),
+    Backreference(Backreference),
+    Quantified(Quantified),
+}
+
+impl Node {
+    pub fn range(&self) -> Range<usize> {
+        match self {
+            Node::Char(node) => node.range.clone(),
+            Node::Dot(range) => range.clone(),
+            Node::Assertion(node) => node.range.clone(),
+            Node::Lookaround(node) => node.range.clone(),
+            Node::ClassEscape(node) => node.range.clone(),
+            Node::CharacterClass(node) => node.range.clone(),
+            Node::Group(node) => node.range.clone(),
+            Node::Backreference(node) => node.range.clone(),
+            Node::Quantified(node) => node.range.clone(),
+        }
+    }
+
+    /// Call a function for this node and every node nested inside of it.
+    pub fn walk(&self, func: &mut impl FnMut(&Node)) {
+        func(self);
+        match self {
+            Node::Lookaround(node) => node.body.walk(func),
+            Node::Group(node) => node.body.walk(func),
+            Node::Quantified(node) => node.node.walk(func),
+            _ => {}
+        }
+    }
+}
+
+/// A single character, either written literally or as an escape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Char {
+    /// The code point of the character. This is not a `char` since lone surrogates such as `\uD800` are allowed.
+    /// Without the `u` flag characters outside of the BMP are still a single [`Char`], even though they
+    /// match as two separate code units.
+    pub value: u32,
+    pub kind: CharKind,
+    pub range: Range<usize>,
+}
+
+impl Char {
+    pub fn as_char(&self) -> Option<char> {
+        std::char::from_u32(self.value)
+    }
+}
+
+/// How a character was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharKind {
+    /// A character written as itself, such as `a`.
+    Literal,
+    /// A single character escape such as `\n` or `\t`, or `\b` inside of a class.
+    Escape,
+    /// A control escape such as `\cJ`.
+    Control,
+    /// A hex escape such as `\x0A`.
+    Hex,
+    /// A unicode escape such as `\u000A` or `\u{A}`, a surrogate pair of escapes is a single character with the `u` flag.
+    Unicode,
+    /// A legacy octal escape such as `\12`, only allowed without the `u` flag.
+    Octal,
+    /// `\0`
+    Null,
+    /// An escaped character which represents itself, such as `\.` or `\/`.
+    Identity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assertion {
+    pub kind: AssertionKind,
+    pub range: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssertionKind {
+    /// `^`
+    Start,
+    /// `$`
+    End,
+    /// `\b`
+    WordBoundary,
+    /// `\B`
+    NotWordBoundary,
+}
+
+/// `(?=a)`, `(?!a)`, `(?<=a)`, or `(?<!a)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lookaround {
+    pub kind: LookaroundKind,
+    pub negated: bool,
+    pub body: Disjunction,
+    pub range: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LookaroundKind {
+    Lookahead,
+    Lookbehind,
+}
+
+/// An escape which matches a set of characters, such as `\d` or `\p{Letter}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassEscape {
+    pub kind: ClassEscapeKind,
+    /// Whether the escape is the uppercase version such as `\D` or `\P{Letter}`.
+    pub negated: bool,
+    pub range: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClassEscapeKind {
+    /// `\d`
+    Digit,
+    /// `\w`
+    Word,
+    /// `\s`
+    Space,
+    /// `\p{name}` or `\p{name=value}`, only allowed with the `u` flag.
+    Property { name: String, value: Option<String> },
+}
+
+/// `[abc]` or `[^a-z]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharacterClass {
+    pub negated: bool,
+    pub items: Vec<ClassItem>,
+    pub range: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClassItem {
+    Char(Char),
+    Range(ClassRange),
+    Escape(ClassEscape),
+}
+
+impl ClassItem {
+    pub fn range(&self) -> Range<usize> {
+        match self {
+            ClassItem::Char(item) => item.range.clone(),
+            ClassItem::Range(item) => item.range.clone(),
+            ClassItem::Escape(item) => item.range.clone(),
+        }
+    }
+}
+
+/// A range of characters in a class such as `a-z`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassRange {
+    pub min: Char,
+    pub max: Char,
+    pub range: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    pub kind: GroupKind,
+    pub body: Disjunction,
+    pub range: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupKind {
+    /// `(a)` or `(?<name>a)`, the index of the first group is `1`.
+    Capturing { index: u32, name: Option<String> },
+    /// `(?:a)`
+    NonCapturing,
+}
+
+/// `\1` or `\k<name>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backreference {
+    pub target: BackreferenceTarget,
+    pub range: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackreferenceTarget {
+    Index(u32),
+    Name(String),
+}
+
+/// A node followed by a quantifier such as `a*`, `a+?`, or `a{1,3}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quantified {
+    pub node: Box<Node>,
+    pub min: u32,
+    /// The maximum amount of repetitions, this is `None` if there is no maximum.
+    pub max: Option<u32>,
+    pub greedy: bool,
+    /// The range of the quantifier itself, including the lazy `?`.
+    pub quantifier_range: Range<usize>,
+    pub range: Range<usize>,
+}
@@ -0,0 +1,912 @@
+use super::*;
+use rslint_errors::Diagnostic;
+use rslint_lexer::{is_id_continue, is_id_start};
+
+/// Characters which have to be escaped to match themselves.
+const SYNTAX_CHARS: &str = "^$\\.*+?()[]{}|";
+
+pub(super) struct RegexParser<'a> {
+    src: &'a str,
+    /// The offset of the closing `/` of the literal.
+    end: usize,
+    pos: usize,
+    flags: Flags,
+    offset: usize,
+    file_id: usize,
+    /// How many groups the current position is nested in.
+    depth: u32,
+    /// The total amount of capturing groups in the pattern.
+    group_count: u32,
+    /// Whether the pattern has any named groups, without the `u` flag this makes `\k` a named backreference.
+    has_named_groups: bool,
+    next_group_index: u32,
+    group_names: Vec<(String, Range<usize>)>,
+    named_references: Vec<(String, Range<usize>)>,
+    errors: Vec<ParserError>,
+}
+
+impl<'a> RegexParser<'a> {
+    pub fn new(src: &'a str, end: usize, flags: Flags, offset: usize, file_id: usize) -> Self {
+        let mut parser = Self {
+            src,
+            end,
+            pos: 1,
+            flags,
+            offset,
+            file_id,
+            depth: 0,
+            group_count: 0,
+            has_named_groups: false,
+            next_group_index: 1,
+            group_names: vec![],
+            named_references: vec![],
+            errors: vec![],
+        };
+        parser.count_groups();
+        parser
+    }
+
+    pub fn parse(mut self) -> (Regex, Vec<ParserError>) {
+        let mut pattern = self.disjunction();
+        pattern.range = 1..self.end;
+
+        for (name, range) in std::mem::take(&mut self.named_references) {
+            if !self.group_names.iter().any(|(group, _)| *group == name) {
+                self.error(
+                    range,
+                    &format!("reference to an undefined capture group `{}`", name),
+                    format!("there is no group named `{}`", name),
+                );
+            }
+        }
+
+        let regex = Regex {
+            pattern,
+            flags: self.flags,
+            flags_range: self.end + 1..self.src.len(),
+        };
+        (regex, self.errors)
+    }
+
+    /// Find out how many capturing groups there are before parsing, backreferences may come before the group
+    /// they reference, and without the `u` flag the meaning of `\1` and `\k` depends on the groups.
+    fn count_groups(&mut self) {
+        let bytes = self.src.as_bytes();
+        let mut in_class = false;
+        let mut idx = 1;
+        while idx < self.end {
+            match bytes[idx] {
+                b'\\' => idx += 1,
+                b'[' => in_class = true,
+                b']' => in_class = false,
+                b'(' if !in_class => {
+                    if bytes.get(idx + 1) != Some(&b'?') {
+                        self.group_count += 1;
+                    } else if bytes.get(idx + 2) == Some(&b'<')
+                        && !matches!(bytes.get(idx + 3), Some(b'=') | Some(b'!'))
+                    {
+                        self.group_count += 1;
+                        self.has_named_groups = true;
+                    }
+                }
+                _ => {}
+            }
+            idx += 1;
+        }
+    }
+
+    fn error(&mut self, range: Range<usize>, message: &str, label: impl Into<String>) {
+        let range = self.offset + range.start..self.offset + range.end;
+        let err = Diagnostic::error(self.file_id, "SyntaxError", message).primary(range, label);
+        self.errors.push(err);
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..self.end].chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.src[self.pos..self.end].chars().nth(n)
+    }
+
+    fn at_str(&self, text: &str) -> bool {
+        self.src[self.pos..self.end].starts_with(text)
+    }
+
+    fn bump(&mut self) -> char {
+        let c = self.peek().expect("bumped past the end of a regex");
+        self.pos += c.len_utf8();
+        c
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn char(&self, value: char, kind: CharKind, start: usize) -> Char {
+        Char {
+            value: value as u32,
+            kind,
+            range: start..self.pos,
+        }
+    }
+
+    fn disjunction(&mut self) -> Disjunction {
+        let start = self.pos;
+        let mut alternatives = vec![self.alternative()];
+        while self.eat('|') {
+            alternatives.push(self.alternative());
+        }
+        Disjunction {
+            alternatives,
+            range: start..self.pos,
+        }
+    }
+
+    fn alternative(&mut self) -> Alternative {
+        let start = self.pos;
+        let mut nodes = vec![];
+        while let Some(c) = self.peek() {
+            match c {
+                '|' => break,
+                ')' if self.depth > 0 => break,
+                ')' => {
+                    let start = self.pos;
+                    self.bump();
+                    self.error(
+                        start..self.pos,
+                        "unmatched parenthesis in regex",
+                        "this parenthesis is never opened",
+                    );
+                }
+                _ => {
+                    if let Some(node) = self.term() {
+                        nodes.push(node);
+                    }
+                }
+            }
+        }
+        Alternative {
+            nodes,
+            range: start..self.pos,
+        }
+    }
+
+    fn term(&mut self) -> Option<Node> {
+        let start = self.pos;
+        let node = match self.peek()? {
+            '^' | '$' => {
+                let kind = if self.bump() == '^' {
+                    AssertionKind::Start
+                } else {
+                    AssertionKind::End
+                };
+                Node::Assertion(Assertion {
+                    kind,
+                    range: start..self.pos,
+                })
+            }
+            '\\' if matches!(self.peek_nth(1), Some('b') | Some('B')) => {
+                self.bump();
+                let kind = if self.bump() == 'b' {
+                    AssertionKind::WordBoundary
+                } else {
+                    AssertionKind::NotWordBoundary
+                };
+                Node::Assertion(Assertion {
+                    kind,
+                    range: start..self.pos,
+                })
+            }
+            '(' if ["(?=", "(?!", "(?<=", "(?<!"]
+                .iter()
+                .any(|prefix| self.at_str(prefix)) =>
+            {
+                self.lookaround()
+            }
+            '*' | '+' | '?' => {
+                self.bump();
+                self.eat('?');
+                self.error(
+                    start..self.pos,
+                    "nothing to repeat in regex",
+                    "this quantifier has nothing to repeat",
+                );
+                return None;
+            }
+            '{' => {
+                if self.braced_quantifier().is_some() {
+                    self.eat('?');
+                    self.error(
+                        start..self.pos,
+                        "nothing to repeat in regex",
+                        "this quantifier has nothing to repeat",
+                    );
+                    return None;
+                }
+                self.bump();
+                if self.flags.unicode {
+                    self.error(
+                        start..self.pos,
+                        "lone quantifier bracket in regex",
+                        "this must be escaped with the `u` flag",
+                    );
+                    return None;
+                }
+                // Annex B: a `{` which does not start a quantifier is a normal character
+                Node::Char(self.char('{', CharKind::Literal, start))
+            }
+            _ => self.atom()?,
+        };
+        Some(self.quantified(node))
+    }
+
+    fn quantified(&mut self, node: Node) -> Node {
+        let start = self.pos;
+        let (min, max) = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => match self.braced_quantifier() {
+                Some(bounds) => bounds,
+                None => return node,
+            },
+            _ => return node,
+        };
+        if self.pos == start {
+            self.bump();
+        }
+        let greedy = !self.eat('?');
+        let quantifier_range = start..self.pos;
+
+        let repeatable = match &node {
+            Node::Assertion(_) => false,
+            // Annex B: lookaheads can be quantified without the `u` flag
+            Node::Lookaround(lookaround) => {
+                lookaround.kind == LookaroundKind::Lookahead && !self.flags.unicode
+            }
+            _ => true,
+        };
+        if !repeatable {
+            self.error(
+                quantifier_range,
+                "nothing to repeat in regex",
+                "assertions cannot be repeated",
+            );
+            return node;
+        }
+        if let Some(max) = max.filter(|max| *max < min) {
+            self.error(
+                quantifier_range.clone(),
+                "numbers out of order in regex quantifier",
+                format!(
+                    "the minimum of {} is larger than the maximum of {}",
+                    min, max
+                ),
+            );
+        }
+
+        Node::Quantified(Quantified {
+            range: node.range().start..self.pos,
+            node: Box::new(node),
+            min,
+            max,
+            greedy,
+            quantifier_range,
+        })
+    }
+
+    /// Parse `{n}`, `{n,}`, or `{n,m}`, nothing is consumed if the quantifier is not valid.
+    fn braced_quantifier(&mut self) -> Option<(u32, Option<u32>)> {
+        let start = self.pos;
+        self.bump();
+        let bounds = self.digits().and_then(|min| {
+            if !self.eat(',') {
+                Some((min, Some(min)))
+            } else if self.peek() == Some('}') {
+                Some((min, None))
+            } else {
+                self.digits().map(|max| (min, Some(max)))
+            }
+        });
+        if bounds.is_some() && self.eat('}') {
+            bounds
+        } else {
+            self.pos = start;
+            None
+        }
+    }
+
+    fn digits(&mut self) -> Option<u32> {
+        let start = self.pos;
+        let mut value: u32 = 0;
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+            self.bump();
+            value = value.saturating_mul(10).saturating_add(digit);
+        }
+        if self.pos == start {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    fn hex_digits(&mut self, count: usize) -> Option<u32> {
+        let start = self.pos;
+        let mut value = 0;
+        for _ in 0..count {
+            match self.peek().and_then(|c| c.to_digit(16)) {
+                Some(digit) => {
+                    self.bump();
+                    value = value * 16 + digit;
+                }
+                None => {
+                    self.pos = start;
+                    return None;
+                }
+            }
+        }
+        Some(value)
+    }
+
+    fn atom(&mut self) -> Option<Node> {
+        let start = self.pos;
+        match self.peek()? {
+            '.' => {
+                self.bump();
+                Some(Node::Dot(start..self.pos))
+            }
+            '(' => Some(self.group()),
+            '[' => Some(self.class()),
+            '\\' => self.atom_escape(),
+            ']' | '}' if self.flags.unicode => {
+                self.bump();
+                self.error(
+                    start..self.pos,
+                    "lone quantifier bracket in regex",
+                    "this must be escaped with the `u` flag",
+                );
+                None
+            }
+            c => {
+                self.bump();
+                Some(Node::Char(self.char(c, CharKind::Literal, start)))
+            }
+        }
+    }
+
+    fn group(&mut self) -> Node {
+        let start = self.pos;
+        self.bump();
+        let kind = if self.at_str("?:") {
+            self.pos += 2;
+            GroupKind::NonCapturing
+        } else if self.at_str("?<") {
+            self.pos += 2;
+            let name = self.group_name(start);
+            if let Some(name) = &name {
+                self.declare_group_name(name.to_owned(), start..self.pos);
+            }
+            let index = self.next_group_index;
+            self.next_group_index += 1;
+            GroupKind::Capturing { index, name }
+        } else if self.peek() == Some('?') {
+            self.bump();
+            self.error(
+                start..self.pos,
+                "invalid regex group",
+                "expected `:`, `=`, `!`, `<=`, `<!`, or a group name after this",
+            );
+            GroupKind::NonCapturing
+        } else {
+            let index = self.next_group_index;
+            self.next_group_index += 1;
+            GroupKind::Capturing { index, name: None }
+        };
+
+        let body = self.group_body(start);
+        Node::Group(Group {
+            kind,
+            body,
+            range: start..self.pos,
+        })
+    }
+
+    fn lookaround(&mut self) -> Node {
+        let start = self.pos;
+        self.pos += 2;
+        let kind = if self.eat('<') {
+            LookaroundKind::Lookbehind
+        } else {
+            LookaroundKind::Lookahead
+        };
+        let negated = self.bump() == '!';
+        let body = self.group_body(start);
+        Node::Lookaround(Lookaround {
+            kind,
+            negated,
+            body,
+            range: start..self.pos,
+        })
+    }
+
+    fn group_body(&mut self, start: usize) -> Disjunction {
+        self.depth += 1;
+        let body = self.disjunction();
+        self.depth -= 1;
+        if !self.eat(')') {
+            self.error(
+                start..start + 1,
+                "unterminated group in regex",
+                "this group is never closed",
+            );
+        }
+        body
+    }
+
+    /// Parse `name>` of a named group or named backreference.
+    fn group_name(&mut self, start: usize) -> Option<String> {
+        let mut name = String::new();
+        let mut valid = true;
+        loop {
+            let c = match self.peek() {
+                Some('\\') if self.peek_nth(1) == Some('u') => {
+                    self.pos += 2;
+                    match self.unicode_escape(true).and_then(std::char::from_u32) {
+                        Some(c) => c,
+                        None => {
+                            valid = false;
+                            continue;
+                        }
+                    }
+                }
+                Some(c) if c == '$' || c == '_' || is_id_continue(c) => {
+                    self.bump();
+                    c
+                }
+                _ => break,
+            };
+            valid &= if name.is_empty() {
+                c == '$' || c == '_' || is_id_start(c)
+            } else {
+                c == '$' || c == '\u{200C}' || c == '\u{200D}' || is_id_continue(c)
+            };
+            name.push(c);
+        }
+
+        if valid && !name.is_empty() && self.eat('>') {
+            Some(name)
+        } else {
+            self.eat('>');
+            self.error(
+                start..self.pos,
+                "invalid capture group name in regex",
+                "expected an identifier followed by `>`",
+            );
+            None
+        }
+    }
+
+    fn declare_group_name(&mut self, name: String, range: Range<usize>) {
+        if let Some((_, first)) = self.group_names.iter().find(|(group, _)| *group == name) {
+            let first = self.offset + first.start..self.offset + first.end;
+            let err = Diagnostic::error(
+                self.file_id,
+                "SyntaxError",
+                format!("duplicate capture group name `{}` in regex", name),
+            )
+            .secondary(first, format!("`{}` is first declared here", name))
+            .primary(
+                self.offset + range.start..self.offset + range.end,
+                format!("`{}` is then declared again here", name),
+            );
+            self.errors.push(err);
+        } else {
+            self.group_names.push((name, range));
+        }
+    }
+
+    fn atom_escape(&mut self) -> Option<Node> {
+        let start = self.pos;
+        self.bump();
+        match self.peek()? {
+            'd' | 'D' | 'w' | 'W' | 's' | 'S' => Some(Node::ClassEscape(self.class_escape(start))),
+            'p' | 'P' if self.flags.unicode => Some(Node::ClassEscape(self.property_escape(start))),
+            '1'..='9' => self.decimal_escape(start),
+            'k' if self.flags.unicode || self.has_named_groups => Some(self.named_reference(start)),
+            _ => self.character_escape(start, false).map(Node::Char),
+        }
+    }
+
+    fn decimal_escape(&mut self, start: usize) -> Option<Node> {
+        let digits_start = self.pos;
+        let index = self.digits()?;
+        if index <= self.group_count {
+            return Some(Node::Backreference(Backreference {
+                target: BackreferenceTarget::Index(index),
+                range: start..self.pos,
+            }));
+        }
+        if self.flags.unicode {
+            self.error(
+                start..self.pos,
+                "invalid escape in regex",
+                format!("there is no capture group with the index {}", index),
+            );
+            return None;
+        }
+        self.pos = digits_start;
+        Some(Node::Char(self.legacy_octal_escape(start)))
+    }
+
+    fn named_reference(&mut self, start: usize) -> Node {
+        self.bump();
+        let name = if self.eat('<') {
+            self.group_name(start)
+        } else {
+            self.error(
+                start..self.pos,
+                "invalid named reference in regex",
+                "expected a group name in angle brackets after this",
+            );
+            None
+        };
+        if let Some(name) = &name {
+            self.named_references
+                .push((name.to_owned(), start..self.pos));
+        }
+        Node::Backreference(Backreference {
+            target: BackreferenceTarget::Name(name.unwrap_or_default()),
+            range: start..self.pos,
+        })
+    }
+
+    /// Annex B: a legacy octal escape such as `\12`, or an identity escape of `8` or `9`.
+    fn legacy_octal_escape(&mut self, start: usize) -> Char {
+        let first = self.bump();
+        if first == '8' || first == '9' {
+            return self.char(first, CharKind::Identity, start);
+        }
+        // the value can be at most `\377`
+        let max_len = if first <= '3' { 3 } else { 2 };
+        let mut value = first.to_digit(8).unwrap();
+        for _ in 1..max_len {
+            match self.peek().and_then(|c| c.to_digit(8)) {
+                Some(digit) => {
+                    self.bump();
+                    value = value * 8 + digit;
+                }
+                None => break,
+            }
+        }
+        Char {
+            value,
+            kind: CharKind::Octal,
+            range: start..self.pos,
+        }
+    }
+
+    /// Parse an escape which represents a single character, the backslash has already been consumed.
+    fn character_escape(&mut self, start: usize, in_class: bool) -> Option<Char> {
+        let unicode = self.flags.unicode;
+        let c = self.bump();
+        let (value, kind) = match c {
+            't' => (0x09, CharKind::Escape),
+            'n' => (0x0A, CharKind::Escape),
+            'v' => (0x0B, CharKind::Escape),
+            'f' => (0x0C, CharKind::Escape),
+            'r' => (0x0D, CharKind::Escape),
+            'b' if in_class => (0x08, CharKind::Escape),
+            'c' => match self.peek() {
+                // Annex B: digits and `_` are also allowed inside of classes
+                Some(letter)
+                    if letter.is_ascii_alphabetic()
+                        || (in_class && !unicode && (letter.is_ascii_digit() || letter == '_')) =>
+                {
+                    self.bump();
+                    (letter as u32 % 32, CharKind::Control)
+                }
+                _ if unicode => {
+                    self.error(
+                        start..self.pos,
+                        "invalid escape in regex",
+                        "`\\c` must be followed by an ASCII letter",
+                    );
+                    return None;
+                }
+                _ => {
+                    // Annex B: the backslash is a normal character and the `c` is parsed on its own
+                    self.pos = start + 1;
+                    return Some(self.char('\\', CharKind::Literal, start));
+                }
+            },
+            '0' if !self.peek().map_or(false, |c| c.is_ascii_digit()) => (0, CharKind::Null),
+            '0'..='9' => {
+                if unicode {
+                    self.error(
+                        start..self.pos,
+                        "invalid escape in regex",
+                        "octal escapes are not allowed with the `u` flag",
+                    );
+                    return None;
+                }
+                self.pos = start + 1;
+                return Some(self.legacy_octal_escape(start));
+            }
+            'x' => match self.hex_digits(2) {
+                Some(value) => (value, CharKind::Hex),
+                None if unicode => {
+                    self.error(
+                        start..self.pos,
+                        "invalid escape in regex",
+                        "expected two hexadecimal digits after this",
+                    );
+                    return None;
+                }
+                None => ('x' as u32, CharKind::Identity),
+            },
+            'u' => match self.unicode_escape(unicode) {
+                Some(value) => (value, CharKind::Unicode),
+                None if unicode => {
+                    if self.eat('{') {
+                        while self.peek().map_or(false, |c| c != '}') {
+                            self.bump();
+                        }
+                        self.eat('}');
+                    }
+                    self.error(
+                        start..self.pos,
+                        "invalid unicode escape in regex",
+                        "expected four hexadecimal digits or a code point in braces after this",
+                    );
+                    return None;
+                }
+                None => ('u' as u32, CharKind::Identity),
+            },
+            c if unicode && !(SYNTAX_CHARS.contains(c) || c == '/' || (in_class && c == '-')) => {
+                self.error(
+                    start..self.pos,
+                    "invalid escape in regex",
+                    "this escape is not allowed with the `u` flag",
+                );
+                return None;
+            }
+            c => (c as u32, CharKind::Identity),
+        };
+        Some(Char {
+            value,
+            kind,
+            range: start..self.pos,
+        })
+    }
+
+    /// Parse the rest of a `\u` escape, with the `u` flag this includes `\u{...}` and surrogate pairs.
+    fn unicode_escape(&mut self, unicode: bool) -> Option<u32> {
+        let start = self.pos;
+        if unicode && self.eat('{') {
+            let digits_start = self.pos;
+            let mut value: u32 = 0;
+            while let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) {
+                self.bump();
+                value = value.saturating_mul(16).saturating_add(digit);
+            }
+            if self.pos > digits_start && value <= 0x10FFFF && self.eat('}') {
+                return Some(value);
+            }
+            self.pos = start;
+            return None;
+        }
+
+        let lead = self.hex_digits(4)?;
+        if unicode && (0xD800..=0xDBFF).contains(&lead) && self.at_str("\\u") {
+            let before_trail = self.pos;
+            self.pos += 2;
+            match self.hex_digits(4) {
+                Some(trail) if (0xDC00..=0xDFFF).contains(&trail) => {
+                    return Some(0x10000 + ((lead - 0xD800) << 10) + (trail - 0xDC00));
+                }
+                _ => self.pos = before_trail,
+            }
+        }
+        Some(lead)
+    }
+
+    fn class_escape(&mut self, start: usize) -> ClassEscape {
+        let c = self.bump();
+        let kind = match c.to_ascii_lowercase() {
+            'd' => ClassEscapeKind::Digit,
+            'w' => ClassEscapeKind::Word,
+            _ => ClassEscapeKind::Space,
+        };
+        ClassEscape {
+            kind,
+            negated: c.is_ascii_uppercase(),
+            range: start..self.pos,
+        }
+    }
+
+    /// Parse `\p{...}` or `\P{...}`, the backslash has already been consumed.
+    fn property_escape(&mut self, start: usize) -> ClassEscape {
+        let negated = self.bump() == 'P';
+        let braces_start = self.pos;
+        let mut kind = ClassEscapeKind::Property {
+            name: String::new(),
+            value: None,
+        };
+
+        if self.eat('{') {
+            let body_start = self.pos;
+            while self
+                .peek()
+                .map_or(false, |c| c.is_ascii_alphanumeric() || c == '_' || c == '=')
+            {
+                self.bump();
+            }
+            let body = &self.src[body_start..self.pos];
+            if self.eat('}') {
+                let mut parts = body.splitn(2, '=');
+                let name = parts.next().unwrap_or_default();
+                let value = parts.next();
+                if !unicode::is_valid_property(name, value) {
+                    self.error(
+                        braces_start..self.pos,
+                        "invalid unicode property name in regex",
+                        format!("`{}` is not a valid unicode property", body),
+                    );
+                }
+                kind = ClassEscapeKind::Property {
+                    name: name.to_string(),
+                    value: value.map(|value| value.to_string()),
+                };
+            } else {
+                self.error(
+                    start..self.pos,
+                    "invalid unicode property name in regex",
+                    "expected a property name followed by `}`",
+                );
+            }
+        } else {
+            self.error(
+                start..self.pos,
+                "invalid unicode property name in regex",
+                "expected a property name in braces after this",
+            );
+        }
+        ClassEscape {
+            kind,
+            negated,
+            range: start..self.pos,
+        }
+    }
+
+    fn class(&mut self) -> Node {
+        let start = self.pos;
+        self.bump();
+        let negated = self.eat('^');
+        let mut items = vec![];
+        loop {
+            match self.peek() {
+                Some(']') => {
+                    self.bump();
+                    break;
+                }
+                None => {
+                    self.error(
+                        start..start + 1,
+                        "unterminated character class in regex",
+                        "this class is never closed",
+                    );
+                    break;
+                }
+                _ => {}
+            }
+
+            let first = match self.class_atom() {
+                Some(atom) => atom,
+                None => continue,
+            };
+            if self.peek() != Some('-') || matches!(self.peek_nth(1), Some(']') | None) {
+                items.push(first);
+                continue;
+            }
+            let dash_start = self.pos;
+            self.bump();
+            let second = match self.class_atom() {
+                Some(atom) => atom,
+                None => {
+                    items.push(first);
+                    continue;
+                }
+            };
+
+            match (first, second) {
+                (ClassItem::Char(min), ClassItem::Char(max)) => {
+                    let range = min.range.start..max.range.end;
+                    // without the `u` flag characters outside of the BMP are two code units, and only
+                    // the code units next to the dash are the ends of the range, e.g. `[💩-💫]`
+                    let (low, high) = if self.flags.unicode {
+                        (min.value, max.value)
+                    } else {
+                        (code_units(min.value).1, code_units(max.value).0)
+                    };
+                    if low > high {
+                        let label = if (low, high) == (min.value, max.value) {
+                            format!(
+                                "`{}` comes after `{}`",
+                                &self.src[min.range.clone()],
+                                &self.src[max.range.clone()]
+                            )
+                        } else {
+                            format!(
+                                "without the `u` flag this range is from the code unit `\\u{:X}` to `\\u{:X}`",
+                                low, high
+                            )
+                        };
+                        self.error(
+                            range.clone(),
+                            "range out of order in regex character class",
+                            label,
+                        );
+                    }
+                    items.push(ClassItem::Range(ClassRange { min, max, range }));
+                }
+                (first, second) => {
+                    if self.flags.unicode {
+                        self.error(
+                            first.range().start..second.range().end,
+                            "invalid character class range in regex",
+                            "a class escape cannot be used in a range",
+                        );
+                    }
+                    // Annex B: the dash is a normal character
+                    items.push(first);
+                    items.push(ClassItem::Char(Char {
+                        value: '-' as u32,
+                        kind: CharKind::Literal,
+                        range: dash_start..dash_start + 1,
+                    }));
+                    items.push(second);
+                }
+            }
+        }
+        Node::CharacterClass(CharacterClass {
+            negated,
+            items,
+            range: start..self.pos,
+        })
+    }
+
+    fn class_atom(&mut self) -> Option<ClassItem> {
+        let start = self.pos;
+        if self.peek()? != '\\' {
+            let c = self.bump();
+            return Some(ClassItem::Char(self.char(c, CharKind::Literal, start)));
+        }
+        self.bump();
+        match self.peek() {
+            Some('d') | Some('D') | Some('w') | Some('W') | Some('s') | Some('S') => {
+                Some(ClassItem::Escape(self.class_escape(start)))
+            }
+            Some('p') | Some('P') if self.flags.unicode => {
+                Some(ClassItem::Escape(self.property_escape(start)))
+            }
+            Some(_) => self.character_escape(start, true).map(ClassItem::Char),
+            None => None,
+        }
+    }
+}
+
+/// The first and last UTF-16 code unit of a character, these are different for characters outside of the BMP.
+fn code_units(value: u32) -> (u32, u32) {
+    if value > 0xFFFF {
+        let offset = value - 0x10000;
+        (0xD800 + (offset >> 10), 0xDC00 + (offset & 0x3FF))
+    } else {
+        (value, value)
+    }
+}
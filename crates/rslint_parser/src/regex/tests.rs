@@ -0,0 +1,342 @@
+use super::*;
+
+fn parse(literal: &str) -> Regex {
+    let (regex, errors) = parse_regex(literal, 0, 0).expect("malformed regex literal");
+    assert!(
+        errors.is_empty(),
+        "unexpected errors in {}: {:#?}",
+        literal,
+        errors
+    );
+    regex
+}
+
+/// Parse a literal and get the title and primary range of every error.
+fn errors(literal: &str) -> Vec<(String, Range<usize>)> {
+    let (_, errors) = parse_regex(literal, 0, 0).expect("malformed regex literal");
+    errors
+        .into_iter()
+        .map(|err| (err.title, err.primary.unwrap().span.range))
+        .collect()
+}
+
+fn assert_err(literal: &str, title: &str, range: Range<usize>) {
+    assert_eq!(
+        errors(literal),
+        vec![(title.to_string(), range)],
+        "{}",
+        literal
+    );
+}
+
+fn nodes(regex: &Regex) -> &[Node] {
+    &regex.pattern.alternatives[0].nodes
+}
+
+#[test]
+fn malformed_literals() {
+    assert!(parse_regex("/", 0, 0).is_none());
+    assert!(parse_regex("/a/-", 0, 0).is_none());
+    assert!(parse_regex("a", 0, 0).is_none());
+}
+
+#[test]
+fn flags() {
+    let regex = parse("/a/gimsuy");
+    assert_eq!(
+        regex.flags,
+        Flags {
+            global: true,
+            ignore_case: true,
+            multiline: true,
+            dot_all: true,
+            unicode: true,
+            sticky: true,
+        }
+    );
+    assert_eq!(regex.flags_range, 3..9);
+    assert_eq!(regex.pattern.range, 1..2);
+}
+
+#[test]
+fn alternatives() {
+    let regex = parse("/ab|c|/");
+    let alternatives = &regex.pattern.alternatives;
+    assert_eq!(alternatives.len(), 3);
+    assert_eq!(alternatives[0].range, 1..3);
+    assert_eq!(alternatives[1].range, 4..5);
+    assert_eq!(alternatives[2].range, 6..6);
+    assert!(alternatives[2].nodes.is_empty());
+}
+
+#[test]
+fn chars() {
+    let regex = parse(r"/a\n\cJ\x41\u0042\0\101\.é/");
+    let chars = nodes(&regex)
+        .iter()
+        .map(|node| match node {
+            Node::Char(c) => (c.value, c.kind, c.range.clone()),
+            _ => panic!("expected a char, got {:?}", node),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        chars,
+        vec![
+            ('a' as u32, CharKind::Literal, 1..2),
+            (0x0A, CharKind::Escape, 2..4),
+            (0x0A, CharKind::Control, 4..7),
+            (0x41, CharKind::Hex, 7..11),
+            (0x42, CharKind::Unicode, 11..17),
+            (0, CharKind::Null, 17..19),
+            (0o101, CharKind::Octal, 19..23),
+            ('.' as u32, CharKind::Identity, 23..25),
+            ('é' as u32, CharKind::Literal, 25..27),
+        ]
+    );
+}
+
+#[test]
+fn unicode_escapes() {
+    let regex = parse(r"/\u{1F600}😀/u");
+    assert_eq!(nodes(&regex).len(), 2);
+    for node in nodes(&regex) {
+        match node {
+            Node::Char(c) => assert_eq!(c.value, 0x1F600),
+            _ => panic!("expected a char, got {:?}", node),
+        }
+    }
+
+    // without the `u` flag this is `u{1F600}` followed by the emoji
+    let regex = parse(r"/\u{1F600}😀/");
+    assert_eq!(nodes(&regex).len(), 9);
+}
+
+#[test]
+fn quantifiers() {
+    let regex = parse("/a*b+?c{2}d{1,}e{1,3}?/");
+    let quantifiers = nodes(&regex)
+        .iter()
+        .map(|node| match node {
+            Node::Quantified(q) => (q.min, q.max, q.greedy, q.quantifier_range.clone()),
+            _ => panic!("expected a quantifier, got {:?}", node),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        quantifiers,
+        vec![
+            (0, None, true, 2..3),
+            (1, None, false, 4..6),
+            (2, Some(2), true, 7..10),
+            (1, None, true, 11..15),
+            (1, Some(3), false, 16..22),
+        ]
+    );
+}
+
+#[test]
+fn annex_b_braces() {
+    let regex = parse("/a{/");
+    assert_eq!(nodes(&regex).len(), 2);
+    let regex = parse("/a{1,x}/");
+    assert_eq!(nodes(&regex).len(), 6);
+    let regex = parse("/]/");
+    assert_eq!(nodes(&regex).len(), 1);
+}
+
+#[test]
+fn groups() {
+    let regex = parse("/(a)(?:b)(?<name>c)(?=d)(?<!e)/");
+    let kinds = nodes(&regex)
+        .iter()
+        .map(|node| match node {
+            Node::Group(group) => format!("{:?}", group.kind),
+            Node::Lookaround(lookaround) => {
+                format!("{:?} {}", lookaround.kind, lookaround.negated)
+            }
+            _ => panic!("expected a group, got {:?}", node),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            "Capturing { index: 1, name: None }",
+            "NonCapturing",
+            "Capturing { index: 2, name: Some(\"name\") }",
+            "Lookahead false",
+            "Lookbehind true",
+        ]
+    );
+    assert_eq!(nodes(&regex)[2].range(), 9..19);
+}
+
+#[test]
+fn backreferences() {
+    let regex = parse(r"/\1(a)\k<b>(?<b>c)/");
+    let targets = nodes(&regex)
+        .iter()
+        .filter_map(|node| match node {
+            Node::Backreference(backreference) => Some(backreference.target.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        targets,
+        vec![
+            BackreferenceTarget::Index(1),
+            BackreferenceTarget::Name("b".to_string())
+        ]
+    );
+
+    // Annex B: without groups these are an octal escape and an identity escape
+    let regex = parse(r"/\1\k/");
+    assert!(matches!(
+        nodes(&regex),
+        [
+            Node::Char(Char {
+                kind: CharKind::Octal,
+                ..
+            }),
+            Node::Char(Char {
+                kind: CharKind::Identity,
+                ..
+            })
+        ]
+    ));
+}
+
+#[test]
+fn character_classes() {
+    let regex = parse(r"/[^a-z\d\-]/");
+    let class = match &nodes(&regex)[0] {
+        Node::CharacterClass(class) => class,
+        node => panic!("expected a class, got {:?}", node),
+    };
+    assert!(class.negated);
+    assert_eq!(class.range, 1..11);
+    assert_eq!(class.items.len(), 3);
+    assert!(matches!(&class.items[0], ClassItem::Range(range) if range.range == (3..6)));
+    assert!(matches!(
+        &class.items[1],
+        ClassItem::Escape(ClassEscape {
+            kind: ClassEscapeKind::Digit,
+            negated: false,
+            ..
+        })
+    ));
+    assert!(matches!(&class.items[2], ClassItem::Char(c) if c.value == '-' as u32));
+
+    // a trailing dash is a literal dash, `\b` is a backspace
+    let regex = parse(r"/[a-][\b]/");
+    assert!(
+        matches!(&nodes(&regex)[1], Node::CharacterClass(class) if class.items == vec![ClassItem::Char(Char { value: 8, kind: CharKind::Escape, range: 6..8 })])
+    );
+}
+
+#[test]
+fn property_escapes() {
+    let regex = parse(r"/\p{L}\P{Script=Greek}\p{ASCII_Hex_Digit}/u");
+    assert!(matches!(
+        &nodes(&regex)[1],
+        Node::ClassEscape(ClassEscape {
+            kind: ClassEscapeKind::Property { name, value: Some(value) },
+            negated: true,
+            ..
+        }) if name == "Script" && value == "Greek"
+    ));
+
+    // without the `u` flag this is just `p{L}`
+    let regex = parse(r"/\p{L}/");
+    assert_eq!(nodes(&regex).len(), 4);
+}
+
+#[test]
+fn walk() {
+    let regex = parse("/(a(?:b)+)|c/");
+    let mut ranges = vec![];
+    regex.pattern.walk(&mut |node| ranges.push(node.range()));
+    assert_eq!(ranges, vec![1..10, 2..3, 3..9, 3..8, 6..7, 11..12]);
+}
+
+#[test]
+fn duplicate_group_names() {
+    let (_, errors) = parse_regex("/(?<a>x)(?<a>y)/", 10, 0).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].title, "duplicate capture group name `a` in regex");
+    assert_eq!(errors[0].primary.as_ref().unwrap().span.range, 18..23);
+    assert_eq!(errors[0].children[0].span.range, 11..16);
+}
+
+#[test]
+fn group_errors() {
+    assert_err("/(?<1a>x)/", "invalid capture group name in regex", 1..7);
+    assert_err("/(?x)/", "invalid regex group", 1..3);
+    assert_err("/(a/", "unterminated group in regex", 1..2);
+    assert_err("/a)/", "unmatched parenthesis in regex", 2..3);
+    assert_err(
+        r"/\k<b>(?<a>x)/",
+        "reference to an undefined capture group `b`",
+        1..6,
+    );
+}
+
+#[test]
+fn quantifier_errors() {
+    assert_err("/*a/", "nothing to repeat in regex", 1..2);
+    assert_err("/a|{1}/", "nothing to repeat in regex", 3..6);
+    assert_err("/^*/", "nothing to repeat in regex", 2..3);
+    assert_err("/(?<=a)?/", "nothing to repeat in regex", 7..8);
+    assert_err("/(?=a)?/u", "nothing to repeat in regex", 6..7);
+    assert_err("/a{2,1}/", "numbers out of order in regex quantifier", 2..7);
+    assert_err("/a{/u", "lone quantifier bracket in regex", 2..3);
+    assert_err("/]/u", "lone quantifier bracket in regex", 1..2);
+    assert!(errors("/(?=a)?/").is_empty());
+}
+
+#[test]
+fn class_errors() {
+    assert_err(
+        "/[z-a]/",
+        "range out of order in regex character class",
+        2..5,
+    );
+    assert_err(r"/[\d-z]/u", "invalid character class range in regex", 2..6);
+    assert_err("/[a/", "unterminated character class in regex", 1..2);
+    assert!(errors(r"/[\d-z]/").is_empty());
+}
+
+#[test]
+fn class_ranges_compare_code_units_without_unicode() {
+    assert_err(
+        "/[💩-💫]/",
+        "range out of order in regex character class",
+        2..11,
+    );
+    assert_err(
+        "/[💩-a]/",
+        "range out of order in regex character class",
+        2..8,
+    );
+    assert!(errors("/[💩-💫]/u").is_empty());
+    assert!(errors("/[a-💩]/").is_empty());
+}
+
+#[test]
+fn escape_errors() {
+    assert_err(
+        r"/\p{Foo}/u",
+        "invalid unicode property name in regex",
+        3..8,
+    );
+    assert_err(
+        r"/\p{General_Category=Greek}/u",
+        "invalid unicode property name in regex",
+        3..27,
+    );
+    assert_err(r"/\p/u", "invalid unicode property name in regex", 1..3);
+    assert_err(r"/\a/u", "invalid escape in regex", 1..3);
+    assert_err(r"/\1/u", "invalid escape in regex", 1..3);
+    assert_err(r"/\c1/u", "invalid escape in regex", 1..3);
+    assert_err(r"/\x4/u", "invalid escape in regex", 1..3);
+    assert_err(r"/\u{110000}/u", "invalid unicode escape in regex", 1..11);
+    assert!(errors(r"/\a\c1\x4\u{110000}/").is_empty());
+}
@@ -49,6 +49,9 @@ pub struct ParserState {
     pub in_binding_list_for_signature: bool,
     pub decorators_were_valid: bool,
     pub in_default: bool,
+    /// Whether the parser is in the pattern of a destructuring assignment,
+    /// where member expressions are valid targets
+    pub in_assign_pattern: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -83,6 +86,7 @@ impl Default for ParserState {
             in_binding_list_for_signature: false,
             decorators_were_valid: false,
             in_default: false,
+            in_assign_pattern: false,
         }
     }
 }
@@ -77,11 +77,31 @@ pub fn literal(p: &mut Parser) -> Option<CompletedMarker> {
     if !p.at_ts(LITERAL) {
         return None;
     }
+    if p.at(REGEX) {
+        regex_early_errors(p);
+    }
     let m = p.start();
     p.bump_any();
     Some(m.complete(p, SyntaxKind::LITERAL))
 }
 
+// test_err regex_early_errors
+// /(?<a>x)(?<a>y)/;
+// /[z-a]/;
+// /\p{Foo}/u;
+// /a{2,1}/;
+// /(a/;
+// /\k<b>(?<a>x)/;
+// /[💩-💫]/;
+fn regex_early_errors(p: &mut Parser) {
+    let start = p.cur_tok().range.start;
+    if let Some((_, errors)) = crate::regex::parse_regex(p.cur_src(), start, p.file_id) {
+        for err in errors {
+            p.error(err);
+        }
+    }
+}
+
 /// An assignment expression such as `foo += bar` or `foo = 5`.
 pub fn assign_expr(p: &mut Parser) -> Option<CompletedMarker> {
     if p.at(T![<])
@@ -167,7 +187,7 @@ pub(crate) fn is_valid_target(p: &mut Parser, marker: &CompletedMarker) -> bool
     }
 }
 
-fn check_assign_target_from_marker(p: &mut Parser, marker: &CompletedMarker) {
+pub(crate) fn check_assign_target_from_marker(p: &mut Parser, marker: &CompletedMarker) {
     if !is_valid_target(p, &marker) {
         let err = p
             .err_builder(&format!(
@@ -195,7 +215,11 @@ fn assign_expr_recursive(
         if p.at(T![=]) {
             if !is_valid_target(p, &target) && target.kind() != TEMPLATE {
                 p.rewind(checkpoint);
-                target = pattern(p, false)?;
+                let mut guard = p.with_state(ParserState {
+                    in_assign_pattern: true,
+                    ..p.state.clone()
+                });
+                target = pattern(&mut *guard, false)?;
             }
         } else {
             if !is_valid_target(p, &target) {
@@ -482,6 +506,7 @@ pub fn subscripts(p: &mut Parser, lhs: CompletedMarker, no_call: bool) -> Comple
             }
             T!['['] => lhs = bracket_expr(p, lhs, false),
             T![.] => lhs = dot_expr(p, lhs, false),
+            T![?.] => lhs = optional_chain(p, lhs),
             T![!] if !p.has_linebreak_before_n(0) => {
                 lhs = {
                     // FIXME(RDambrosio016): we need to tell the lexer that an expression is not
@@ -524,11 +549,14 @@ pub fn subscripts(p: &mut Parser, lhs: CompletedMarker, no_call: bool) -> Comple
     lhs
 }
 
-// An optional chain such as `foo?.bar?.(baz)?.[foo]`
+/// An optional chain such as `foo?.bar?.(baz)?.[foo]`
 // test optional_chain
 // foo?.bar?.(baz)?.[foo]
 // foo.bar?.(f).baz
 // foo[bar]?.baz
+// foo.bar?.baz
+// foo()?.bar.baz?.()
+// x = (foo.bar?.())
 pub fn optional_chain(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
     let mut lhs = lhs;
     while !p.at(EOF) {
@@ -1,8 +1,16 @@
-use super::expr::{assign_expr, identifier_name, identifier_reference, object_prop_name};
+use super::expr::{
+    assign_expr, check_assign_target_from_marker, identifier_name, identifier_reference, lhs_expr,
+    object_prop_name,
+};
 use crate::{SyntaxKind::*, *};
 
 pub fn pattern(p: &mut Parser, parameters: bool) -> Option<CompletedMarker> {
     Some(match p.cur() {
+        T![ident] | T![yield] | T![await] | T![this]
+            if p.state.in_assign_pattern && matches!(p.nth(1), T![.] | T!['[']) =>
+        {
+            member_target(p)?
+        }
         T![ident] | T![yield] | T![await] => {
             let m = p.start();
             if p.state.should_record_names {
@@ -61,6 +69,19 @@ pub fn pattern(p: &mut Parser, parameters: bool) -> Option<CompletedMarker> {
     })
 }
 
+// test assign_pattern_member
+// [foo.bar, baz[0] = 1] = qux;
+// ({ a: this.b, c: d[e], ...f.g } = h);
+fn member_target(p: &mut Parser) -> Option<CompletedMarker> {
+    let mut guard = p.with_state(ParserState {
+        in_assign_pattern: false,
+        ..p.state.clone()
+    });
+    let target = lhs_expr(&mut *guard)?;
+    check_assign_target_from_marker(&mut *guard, &target);
+    Some(target)
+}
+
 pub fn opt_binding_identifier(p: &mut Parser) -> Option<CompletedMarker> {
     const BINDING_IDENTS: TokenSet = token_set![T![ident], T![yield], T![await]];
 
@@ -117,8 +138,12 @@ pub fn binding_element(p: &mut Parser, parameters: bool) -> Option<CompletedMark
         let m = left.map(|m| m.precede(p)).unwrap_or_else(|| p.start());
         p.bump_any();
 
-        assign_expr(p);
-        return Some(m.complete(p, ASSIGN_PATTERN));
+        let mut guard = p.with_state(ParserState {
+            in_assign_pattern: false,
+            ..p.state.clone()
+        });
+        assign_expr(&mut *guard);
+        return Some(m.complete(&mut *guard, ASSIGN_PATTERN));
     }
 
     left
@@ -392,6 +392,7 @@ pub fn contains_js_linebreak(string: impl AsRef<str>) -> bool {
 pub fn contains_js_whitespace(string: impl AsRef<str>) -> bool {
     let text = string.as_ref();
     text.contains(' ')
+        || text.contains('\t')
         || text.contains('\u{000B}')
         || text.contains('\u{000C}')
         || text.contains('\u{0020}')
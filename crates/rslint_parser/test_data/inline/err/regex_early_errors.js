@@ -0,0 +1,7 @@
+/(?<a>x)(?<a>y)/;
+/[z-a]/;
+/\p{Foo}/u;
+/a{2,1}/;
+/(a/;
+/\k<b>(?<a>x)/;
+/[💩-💫]/;
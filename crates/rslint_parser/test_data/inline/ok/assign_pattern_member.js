@@ -0,0 +1,2 @@
+[foo.bar, baz[0] = 1] = qux;
+({ a: this.b, c: d[e], ...f.g } = h);
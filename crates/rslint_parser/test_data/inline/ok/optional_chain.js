@@ -0,0 +1,6 @@
+foo?.bar?.(baz)?.[foo]
+foo.bar?.(f).baz
+foo[bar]?.baz
+foo.bar?.baz
+foo()?.bar.baz?.()
+x = (foo.bar?.())
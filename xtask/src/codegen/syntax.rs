@@ -176,6 +176,23 @@ fn generate_nodes(grammar: AstSrc<'_>) -> Result<String> {
         })
         .unzip();
 
+    let field_arms = grammar.nodes.iter().flat_map(|node| {
+        let name = format_ident!("{}", node.name);
+        let kind = format_ident!("{}", to_upper_snake_case(node.name));
+
+        node.fields
+            .iter()
+            .filter(|field| field.token_kind().is_none())
+            .map(move |field| {
+                let method_name = field.method_name();
+                let field_name = method_name.to_string();
+
+                quote! {
+                    (#kind, #field_name) => support::nodes(#name::cast(node.clone())?.#method_name())
+                }
+            })
+    });
+
     let enum_names = grammar.enums.iter().map(|it| it.name);
     let node_names = grammar.nodes.iter().map(|it| it.name);
 
@@ -204,6 +221,17 @@ fn generate_nodes(grammar: AstSrc<'_>) -> Result<String> {
         #(#node_boilerplate_impls)*
         #(#enum_boilerplate_impls)*
         #(#display_impls)*
+
+        /// Get the nodes in a field of a node using the field's generated accessor, e.g. `callee` for a `CALL_EXPR`.
+        /// Returns `None` if the kind of node has no generated accessor with that name, fields which are tokens
+        /// are not included.
+        pub(crate) fn generated_field(node: &SyntaxNode, field: &str) -> Option<Vec<SyntaxNode>> {
+            let nodes = match (node.kind(), field) {
+                #(#field_arms,)*
+                _ => return None,
+            };
+            Some(nodes)
+        }
     };
 
     let ast = ast